@@ -17,7 +17,7 @@ mod reader;
 mod writer;
 
 pub use error::Error;
-pub use reader::{DumpReader, UpdateFile};
+pub use reader::{DumpProgress, DumpReader, UpdateFile};
 pub use writer::DumpWriter;
 
 const CURRENT_DUMP_VERSION: Version = Version::V6;
@@ -89,6 +89,14 @@ pub struct TaskDump {
         default
     )]
     pub finished_at: Option<OffsetDateTime>,
+
+    // Older dumps don't contain any priority, their tasks get the default one.
+    #[serde(default, skip_serializing_if = "is_default_priority")]
+    pub priority: i8,
+}
+
+fn is_default_priority(priority: &i8) -> bool {
+    *priority == 0
 }
 
 // A `Kind` specific version made for the dump. If modified you may break the dump.
@@ -100,6 +108,8 @@ pub enum KindDump {
         method: IndexDocumentsMethod,
         documents_count: u64,
         allow_index_creation: bool,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        skip_malformed_documents: bool,
     },
     DocumentDeletion {
         documents_ids: Vec<String>,
@@ -125,9 +135,15 @@ pub enum KindDump {
     IndexUpdate {
         primary_key: Option<String>,
     },
+    IndexCompaction,
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
+    MultiIndexDocumentImport {
+        additions: Vec<IndexDocumentImport>,
+        method: IndexDocumentsMethod,
+        allow_index_creation: bool,
+    },
     TaskCancelation {
         query: String,
         tasks: RoaringBitmap,
@@ -143,6 +159,16 @@ pub enum KindDump {
     SnapshotCreation,
 }
 
+/// The documents imported in one of the indexes of a `MultiIndexDocumentImport`.
+/// The documents of every index are concatenated, in order, in the content file of the task.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexDocumentImport {
+    pub index_uid: String,
+    pub primary_key: Option<String>,
+    pub documents_count: u64,
+}
+
 impl From<Task> for TaskDump {
     fn from(task: Task) -> Self {
         TaskDump {
@@ -157,6 +183,7 @@ impl From<Task> for TaskDump {
             enqueued_at: task.enqueued_at,
             started_at: task.started_at,
             finished_at: task.finished_at,
+            priority: task.priority,
         }
     }
 }
@@ -169,12 +196,14 @@ impl From<KindWithContent> for KindDump {
                 method,
                 documents_count,
                 allow_index_creation,
+                skip_malformed_documents,
                 ..
             } => KindDump::DocumentImport {
                 primary_key,
                 method,
                 documents_count,
                 allow_index_creation,
+                skip_malformed_documents,
             },
             KindWithContent::DocumentDeletion { documents_ids, .. } => {
                 KindDump::DocumentDeletion { documents_ids }
@@ -199,7 +228,24 @@ impl From<KindWithContent> for KindDump {
             KindWithContent::IndexUpdate { primary_key, .. } => {
                 KindDump::IndexUpdate { primary_key }
             }
+            KindWithContent::IndexCompaction { .. } => KindDump::IndexCompaction,
             KindWithContent::IndexSwap { swaps } => KindDump::IndexSwap { swaps },
+            KindWithContent::MultiIndexDocumentAddition {
+                additions,
+                method,
+                allow_index_creation,
+            } => KindDump::MultiIndexDocumentImport {
+                additions: additions
+                    .into_iter()
+                    .map(|addition| IndexDocumentImport {
+                        index_uid: addition.index_uid,
+                        primary_key: addition.primary_key,
+                        documents_count: addition.documents_count,
+                    })
+                    .collect(),
+                method,
+                allow_index_creation,
+            },
             KindWithContent::TaskCancelation { query, tasks } => {
                 KindDump::TaskCancelation { query, tasks }
             }
@@ -216,6 +262,7 @@ impl From<KindWithContent> for KindDump {
 
 #[cfg(test)]
 pub(crate) mod test {
+    use std::collections::BTreeMap;
     use std::fs::File;
     use std::io::Seek;
     use std::str::FromStr;
@@ -294,6 +341,8 @@ pub(crate) mod test {
             localized_attributes: Setting::NotSet,
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
+            prefix_disabled_attributes: Setting::NotSet,
+            document_ttl: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
@@ -312,16 +361,20 @@ pub(crate) mod test {
                         allow_index_creation: true,
                         primary_key: Some(S("bone")),
                         documents_count: 12,
+                        skip_malformed_documents: false,
                     },
                     canceled_by: None,
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 12,
                         indexed_documents: Some(10),
+                        skipped_documents: Vec::new(),
+                        skipped_documents_count: 0,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
                     started_at: Some(datetime!(2022-11-20 0:00 UTC)),
                     finished_at: Some(datetime!(2022-11-21 0:00 UTC)),
+                    priority: 0,
                 },
                 None,
             ),
@@ -336,16 +389,20 @@ pub(crate) mod test {
                         allow_index_creation: true,
                         primary_key: None,
                         documents_count: 2,
+                        skip_malformed_documents: false,
                     },
                     canceled_by: None,
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 2,
                         indexed_documents: None,
+                        skipped_documents: Vec::new(),
+                        skipped_documents_count: 0,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
                     started_at: None,
                     finished_at: None,
+                    priority: 0,
                 },
                 Some(vec![
                     json!({ "id": 4, "race": "leonberg" }).as_object().unwrap().clone(),
@@ -365,6 +422,7 @@ pub(crate) mod test {
                     enqueued_at: datetime!(2022-11-15 0:00 UTC),
                     started_at: None,
                     finished_at: None,
+                    priority: 1,
                 },
                 None,
             ),
@@ -449,6 +507,9 @@ pub(crate) mod test {
 
         dump.create_experimental_features(features).unwrap();
 
+        // ========== aliases
+        dump.create_aliases(&create_test_aliases()).unwrap();
+
         // create the dump
         let mut file = tempfile::tempfile().unwrap();
         dump.persist_to(&mut file).unwrap();
@@ -461,6 +522,10 @@ pub(crate) mod test {
         RuntimeTogglableFeatures { vector_store: true, ..Default::default() }
     }
 
+    fn create_test_aliases() -> BTreeMap<String, String> {
+        btreemap! { S("dogs") => S("doggos") }
+    }
+
     #[test]
     fn test_creating_and_read_dump() {
         let mut file = create_test_dump();
@@ -509,5 +574,8 @@ pub(crate) mod test {
         // ==== checking the features
         let expected = create_test_features();
         assert_eq!(dump.features().unwrap().unwrap(), expected);
+
+        // ==== checking the aliases
+        assert_eq!(dump.aliases(), create_test_aliases());
     }
 }
@@ -104,6 +104,7 @@ impl CompatV5ToV6 {
                                 }
                             },
                             allow_index_creation,
+                            skip_malformed_documents: false,
                         },
                         v5::tasks::TaskContent::DocumentDeletion { deletion, .. } => match deletion
                         {
@@ -133,6 +134,8 @@ impl CompatV5ToV6 {
                             v6::Details::DocumentAdditionOrUpdate {
                                 received_documents: received_documents as u64,
                                 indexed_documents,
+                                skipped_documents: Vec::new(),
+                                skipped_documents_count: 0,
                             }
                         }
                         v5::Details::Settings { settings } => {
@@ -159,6 +162,7 @@ impl CompatV5ToV6 {
                     enqueued_at: task_view.enqueued_at,
                     started_at: task_view.started_at,
                     finished_at: task_view.finished_at,
+                    priority: 0,
                 };
 
                 (task, content_file)
@@ -384,6 +388,8 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
             search_cutoff_ms: v6::Setting::NotSet,
             facet_search: v6::Setting::NotSet,
             prefix_search: v6::Setting::NotSet,
+            prefix_disabled_attributes: v6::Setting::NotSet,
+            document_ttl: v6::Setting::NotSet,
             _kind: std::marker::PhantomData,
         }
     }
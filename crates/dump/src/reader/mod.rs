@@ -1,5 +1,8 @@
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use std::num::NonZeroU64;
+use std::sync::Arc;
 
 use flate2::bufread::GzDecoder;
 use serde::Deserialize;
@@ -7,11 +10,13 @@ use tempfile::TempDir;
 
 use self::compat::v4_to_v5::CompatV4ToV5;
 use self::compat::v5_to_v6::{CompatIndexV5ToV6, CompatV5ToV6};
+use self::progress::{ProgressHook, ProgressIter};
 use self::v5::V5Reader;
 use self::v6::{V6IndexReader, V6Reader};
 use crate::{Result, Version};
 
 mod compat;
+mod progress;
 
 mod v1;
 mod v2;
@@ -20,10 +25,17 @@ mod v4;
 mod v5;
 mod v6;
 
+pub use self::progress::DumpProgress;
+
 pub type Document = serde_json::Map<String, serde_json::Value>;
 pub type UpdateFile = dyn Iterator<Item = Result<Document>>;
 
-pub enum DumpReader {
+pub struct DumpReader {
+    reader: VersionedDumpReader,
+    progress: Option<ProgressHook>,
+}
+
+enum VersionedDumpReader {
     Current(V6Reader),
     Compat(CompatV5ToV6),
 }
@@ -57,140 +69,208 @@ impl DumpReader {
     }
 
     pub fn version(&self) -> crate::Version {
-        match self {
-            DumpReader::Current(current) => current.version(),
-            DumpReader::Compat(compat) => compat.version(),
+        match &self.reader {
+            VersionedDumpReader::Current(current) => current.version(),
+            VersionedDumpReader::Compat(compat) => compat.version(),
         }
     }
 
     pub fn date(&self) -> Option<time::OffsetDateTime> {
-        match self {
-            DumpReader::Current(current) => current.date(),
-            DumpReader::Compat(compat) => compat.date(),
+        match &self.reader {
+            VersionedDumpReader::Current(current) => current.date(),
+            VersionedDumpReader::Compat(compat) => compat.date(),
         }
     }
 
     pub fn instance_uid(&self) -> Result<Option<uuid::Uuid>> {
-        match self {
-            DumpReader::Current(current) => current.instance_uid(),
-            DumpReader::Compat(compat) => compat.instance_uid(),
+        match &self.reader {
+            VersionedDumpReader::Current(current) => current.instance_uid(),
+            VersionedDumpReader::Compat(compat) => compat.instance_uid(),
         }
     }
 
+    /// Registers a hook that is called when starting to read an index and
+    /// every `interval` documents or tasks read, as the dump is being read.
+    ///
+    /// The hook is called from the iterators returned by the reader, so that
+    /// nothing has to be buffered to report the progress.
+    pub fn on_progress(
+        &mut self,
+        interval: NonZeroU64,
+        hook: impl Fn(DumpProgress) + Send + Sync + 'static,
+    ) {
+        self.progress = Some(ProgressHook { interval, hook: Arc::new(hook) });
+    }
+
     pub fn indexes(&self) -> Result<Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>> {
-        match self {
-            DumpReader::Current(current) => {
-                let indexes = Box::new(current.indexes()?.map(|res| res.map(DumpIndexReader::from)))
-                    as Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>;
-                Ok(indexes)
+        let indexes = match &self.reader {
+            VersionedDumpReader::Current(current) => {
+                Box::new(current.indexes()?.map(|res| res.map(DumpIndexReader::from)))
+                    as Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>
             }
-            DumpReader::Compat(compat) => {
-                let indexes = Box::new(compat.indexes()?.map(|res| res.map(DumpIndexReader::from)))
-                    as Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>;
-                Ok(indexes)
+            VersionedDumpReader::Compat(compat) => {
+                Box::new(compat.indexes()?.map(|res| res.map(DumpIndexReader::from)))
+                    as Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>
             }
-        }
+        };
+
+        let progress = self.progress.as_ref();
+        Ok(Box::new(indexes.map(move |index| {
+            index.map(|mut index| {
+                if let Some(progress) = progress {
+                    let uid = index.metadata().uid.clone();
+                    (progress.hook)(DumpProgress::Index { uid });
+                    index.progress = Some(progress.clone());
+                }
+                index
+            })
+        })))
     }
 
     pub fn tasks(
         &mut self,
     ) -> Result<Box<dyn Iterator<Item = Result<(v6::Task, Option<Box<UpdateFile>>)>> + '_>> {
-        match self {
-            DumpReader::Current(current) => Ok(current.tasks()),
-            DumpReader::Compat(compat) => compat.tasks(),
+        let tasks = match &mut self.reader {
+            VersionedDumpReader::Current(current) => current.tasks(),
+            VersionedDumpReader::Compat(compat) => compat.tasks()?,
+        };
+
+        match &self.progress {
+            Some(progress) => {
+                let hook = progress.hook.clone();
+                let report = move |count| hook(DumpProgress::Tasks { count });
+                Ok(Box::new(ProgressIter::new(tasks, progress.interval, report)))
+            }
+            None => Ok(tasks),
         }
     }
 
     pub fn keys(&mut self) -> Result<Box<dyn Iterator<Item = Result<v6::Key>> + '_>> {
-        match self {
-            DumpReader::Current(current) => Ok(current.keys()),
-            DumpReader::Compat(compat) => compat.keys(),
+        match &mut self.reader {
+            VersionedDumpReader::Current(current) => Ok(current.keys()),
+            VersionedDumpReader::Compat(compat) => compat.keys(),
         }
     }
 
     pub fn features(&self) -> Result<Option<v6::RuntimeTogglableFeatures>> {
-        match self {
-            DumpReader::Current(current) => Ok(current.features()),
-            DumpReader::Compat(compat) => compat.features(),
+        match &self.reader {
+            VersionedDumpReader::Current(current) => Ok(current.features()),
+            VersionedDumpReader::Compat(compat) => compat.features(),
+        }
+    }
+
+    /// Returns the aliases mapped to the uid of the index they point to,
+    /// the dumps of the previous versions don't have any.
+    pub fn aliases(&self) -> BTreeMap<String, String> {
+        match &self.reader {
+            VersionedDumpReader::Current(current) => current.aliases().clone(),
+            VersionedDumpReader::Compat(_) => BTreeMap::new(),
         }
     }
 }
 
+impl From<VersionedDumpReader> for DumpReader {
+    fn from(reader: VersionedDumpReader) -> Self {
+        DumpReader { reader, progress: None }
+    }
+}
+
 impl From<V6Reader> for DumpReader {
     fn from(value: V6Reader) -> Self {
-        DumpReader::Current(value)
+        VersionedDumpReader::Current(value).into()
     }
 }
 
 impl From<CompatV5ToV6> for DumpReader {
     fn from(value: CompatV5ToV6) -> Self {
-        DumpReader::Compat(value)
+        VersionedDumpReader::Compat(value).into()
     }
 }
 
 impl From<V5Reader> for DumpReader {
     fn from(value: V5Reader) -> Self {
-        DumpReader::Compat(value.to_v6())
+        VersionedDumpReader::Compat(value.to_v6()).into()
     }
 }
 
 impl From<CompatV4ToV5> for DumpReader {
     fn from(value: CompatV4ToV5) -> Self {
-        DumpReader::Compat(value.to_v6())
+        VersionedDumpReader::Compat(value.to_v6()).into()
     }
 }
 
-pub enum DumpIndexReader {
+pub struct DumpIndexReader {
+    reader: VersionedDumpIndexReader,
+    progress: Option<ProgressHook>,
+}
+
+enum VersionedDumpIndexReader {
     Current(v6::V6IndexReader),
     Compat(Box<CompatIndexV5ToV6>),
 }
 
 impl DumpIndexReader {
     pub fn new_v6(v6: v6::V6IndexReader) -> DumpIndexReader {
-        DumpIndexReader::Current(v6)
+        DumpIndexReader::from(v6)
     }
 
     pub fn metadata(&self) -> &crate::IndexMetadata {
-        match self {
-            DumpIndexReader::Current(v6) => v6.metadata(),
-            DumpIndexReader::Compat(compat) => compat.metadata(),
+        match &self.reader {
+            VersionedDumpIndexReader::Current(v6) => v6.metadata(),
+            VersionedDumpIndexReader::Compat(compat) => compat.metadata(),
         }
     }
 
     pub fn documents(&mut self) -> Result<Box<dyn Iterator<Item = Result<Document>> + '_>> {
-        match self {
-            DumpIndexReader::Current(v6) => v6
+        let index_uid = self.metadata().uid.clone();
+        let documents = match &mut self.reader {
+            VersionedDumpIndexReader::Current(v6) => v6
                 .documents()
-                .map(|iter| Box::new(iter) as Box<dyn Iterator<Item = Result<Document>> + '_>),
-            DumpIndexReader::Compat(compat) => compat
+                .map(|iter| Box::new(iter) as Box<dyn Iterator<Item = Result<Document>> + '_>)?,
+            VersionedDumpIndexReader::Compat(compat) => compat
                 .documents()
-                .map(|iter| Box::new(iter) as Box<dyn Iterator<Item = Result<Document>> + '_>),
+                .map(|iter| Box::new(iter) as Box<dyn Iterator<Item = Result<Document>> + '_>)?,
+        };
+
+        match &self.progress {
+            Some(progress) => {
+                let hook = progress.hook.clone();
+                let report = move |count| {
+                    hook(DumpProgress::Documents { index_uid: index_uid.clone(), count })
+                };
+                Ok(Box::new(ProgressIter::new(documents, progress.interval, report)))
+            }
+            None => Ok(documents),
         }
     }
 
     pub fn settings(&mut self) -> Result<v6::Settings<v6::Checked>> {
-        match self {
-            DumpIndexReader::Current(v6) => v6.settings(),
-            DumpIndexReader::Compat(compat) => compat.settings(),
+        match &mut self.reader {
+            VersionedDumpIndexReader::Current(v6) => v6.settings(),
+            VersionedDumpIndexReader::Compat(compat) => compat.settings(),
         }
     }
 }
 
 impl From<V6IndexReader> for DumpIndexReader {
     fn from(value: V6IndexReader) -> Self {
-        DumpIndexReader::Current(value)
+        DumpIndexReader { reader: VersionedDumpIndexReader::Current(value), progress: None }
     }
 }
 
 impl From<CompatIndexV5ToV6> for DumpIndexReader {
     fn from(value: CompatIndexV5ToV6) -> Self {
-        DumpIndexReader::Compat(Box::new(value))
+        DumpIndexReader {
+            reader: VersionedDumpIndexReader::Compat(Box::new(value)),
+            progress: None,
+        }
     }
 }
 
 #[cfg(test)]
 pub(crate) mod test {
     use std::fs::File;
+    use std::sync::Mutex;
 
     use meili_snap::insta;
 
@@ -722,6 +802,51 @@ pub(crate) mod test {
         meili_snap::snapshot_hash!(format!("{:#?}", documents), @"235016433dd04262c7f2da01d1e808ce");
     }
 
+    #[test]
+    fn import_dump_v2_with_progress() {
+        let dump = File::open("tests/assets/v2.dump").unwrap();
+        let mut dump = DumpReader::open(dump).unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        dump.on_progress(NonZeroU64::new(50).unwrap(), move |progress| {
+            sink.lock().unwrap().push(progress)
+        });
+
+        assert_eq!(dump.tasks().unwrap().count(), 9);
+
+        let indexes = dump.indexes().unwrap().collect::<Result<Vec<_>>>().unwrap();
+        let mut movies =
+            indexes.into_iter().find(|index| index.metadata().uid == "movies").unwrap();
+        assert_eq!(movies.documents().unwrap().count(), 110);
+
+        let events = events.lock().unwrap();
+        // the indexes are not read in any particular order
+        let mut index_uids: Vec<_> = events
+            .iter()
+            .filter_map(|progress| match progress {
+                DumpProgress::Index { uid } => Some(uid.as_str()),
+                _ => None,
+            })
+            .collect();
+        index_uids.sort_unstable();
+        assert_eq!(index_uids, ["dnd_spells", "movies", "movies_2", "products"]);
+
+        let counts: Vec<_> = events
+            .iter()
+            .filter(|progress| !matches!(progress, DumpProgress::Index { .. }))
+            .collect();
+        assert_eq!(
+            counts,
+            [
+                &DumpProgress::Tasks { count: 9 },
+                &DumpProgress::Documents { index_uid: "movies".to_string(), count: 50 },
+                &DumpProgress::Documents { index_uid: "movies".to_string(), count: 100 },
+                &DumpProgress::Documents { index_uid: "movies".to_string(), count: 110 },
+            ]
+        );
+    }
+
     #[test]
     fn import_dump_v2_from_meilisearch_v0_22_0_issue_3435() {
         let dump = File::open("tests/assets/v2-v0.22.0.dump").unwrap();
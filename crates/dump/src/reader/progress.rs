@@ -0,0 +1,58 @@
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+/// The progress of the reading of a dump, see [`DumpReader::on_progress`](super::DumpReader::on_progress).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DumpProgress {
+    /// Started reading the index with this uid.
+    Index { uid: String },
+    /// Read `count` documents of the index so far.
+    Documents { index_uid: String, count: u64 },
+    /// Read `count` tasks so far.
+    Tasks { count: u64 },
+}
+
+#[derive(Clone)]
+pub(super) struct ProgressHook {
+    pub interval: NonZeroU64,
+    pub hook: Arc<dyn Fn(DumpProgress) + Send + Sync>,
+}
+
+/// Counts the items of an iterator and reports the count every `interval` items,
+/// and one last time once the iterator is exhausted.
+pub(super) struct ProgressIter<I, F> {
+    iter: I,
+    count: u64,
+    interval: NonZeroU64,
+    finished: bool,
+    report: F,
+}
+
+impl<I, F> ProgressIter<I, F> {
+    pub fn new(iter: I, interval: NonZeroU64, report: F) -> Self {
+        ProgressIter { iter, count: 0, interval, finished: false, report }
+    }
+}
+
+impl<I: Iterator, F: Fn(u64)> Iterator for ProgressIter<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.count += 1;
+                if self.count % self.interval == 0 {
+                    (self.report)(self.count);
+                }
+                Some(item)
+            }
+            None => {
+                if !self.finished && self.count % self.interval != 0 {
+                    (self.report)(self.count);
+                }
+                self.finished = true;
+                None
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader, ErrorKind};
 use std::path::Path;
@@ -50,6 +51,7 @@ pub struct V6Reader {
     tasks: BufReader<File>,
     keys: BufReader<File>,
     features: Option<RuntimeTogglableFeatures>,
+    aliases: BTreeMap<String, String>,
 }
 
 impl V6Reader {
@@ -78,12 +80,20 @@ impl V6Reader {
             None
         };
 
+        let aliases = match fs::read(dump.path().join("aliases.json")) {
+            Ok(aliases_file) => serde_json::from_reader(&*aliases_file)?,
+            // The dumps created before the aliases don't have any.
+            Err(error) if error.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(error) => return Err(error.into()),
+        };
+
         Ok(V6Reader {
             metadata: serde_json::from_reader(&*meta_file)?,
             instance_uid,
             tasks: BufReader::new(File::open(dump.path().join("tasks").join("queue.jsonl"))?),
             keys: BufReader::new(File::open(dump.path().join("keys.jsonl"))?),
             features,
+            aliases,
             dump,
         })
     }
@@ -154,6 +164,10 @@ impl V6Reader {
     pub fn features(&self) -> Option<RuntimeTogglableFeatures> {
         self.features
     }
+
+    pub fn aliases(&self) -> &BTreeMap<String, String> {
+        &self.aliases
+    }
 }
 
 pub struct UpdateFile {
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::io::{BufWriter, Write};
 use std::path::PathBuf;
@@ -61,6 +62,11 @@ impl DumpWriter {
         )?)
     }
 
+    /// Writes the aliases, mapped to the uid of the index they point to.
+    pub fn create_aliases(&self, aliases: &BTreeMap<String, String>) -> Result<()> {
+        Ok(std::fs::write(self.dir.path().join("aliases.json"), serde_json::to_string(aliases)?)?)
+    }
+
     pub fn persist_to(self, mut writer: impl Write) -> Result<()> {
         let gz_encoder = GzEncoder::new(&mut writer, Compression::default());
         let mut tar_encoder = tar::Builder::new(gz_encoder);
@@ -292,6 +298,7 @@ pub(crate) mod test {
         │    ├---- update_files/
         │    │    └---- 1.jsonl
         │    └---- queue.jsonl
+        ├---- aliases.json
         ├---- experimental-features.json
         ├---- instance_uid.uuid
         ├---- keys.jsonl
@@ -94,6 +94,27 @@ pub fn parse_not_exists(input: Span) -> IResult<FilterCondition> {
     Ok((input, FilterCondition::Not(Box::new(FilterCondition::Condition { fid: key, op: Exists }))))
 }
 
+/// not_empty      = value "NOT" WS+ "EMPTY"
+///
+/// Matches the documents having a value for the field that is neither
+/// `null`, nor an empty string, array or object.
+pub fn parse_not_empty(input: Span) -> IResult<FilterCondition> {
+    let (input, key) = parse_value(input)?;
+
+    let (input, _) = tuple((tag("NOT"), multispace1, tag("EMPTY")))(input)?;
+    Ok((
+        input,
+        FilterCondition::And(vec![
+            FilterCondition::Condition { fid: key.clone(), op: Exists },
+            FilterCondition::Not(Box::new(FilterCondition::Condition {
+                fid: key.clone(),
+                op: Empty,
+            })),
+            FilterCondition::Not(Box::new(FilterCondition::Condition { fid: key, op: Null })),
+        ]),
+    ))
+}
+
 /// contains        = value "CONTAINS" value
 pub fn parse_contains(input: Span) -> IResult<FilterCondition> {
     let (input, (fid, contains, value)) =
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use dump::{KindDump, TaskDump, UpdateFile};
 use meilisearch_types::heed::RwTxn;
 use meilisearch_types::milli::documents::DocumentsBatchBuilder;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{IndexDocumentAddition, Kind, KindWithContent, Status, Task};
 use roaring::RoaringBitmap;
 use uuid::Uuid;
 
@@ -39,22 +39,36 @@ impl<'a> Dump<'a> {
         task: TaskDump,
         content_file: Option<Box<UpdateFile>>,
     ) -> Result<Task> {
-        let content_uuid = match content_file {
-            Some(content_file) if task.status == Status::Enqueued => {
-                let (uuid, mut file) = self.index_scheduler.queue.create_update_file(false)?;
-                let mut builder = DocumentsBatchBuilder::new(&mut file);
-                for doc in content_file {
-                    builder.append_json_object(&doc?)?;
+        // The documents of a multi-index import are concatenated in a single content file,
+        // we must split them back into one update file per index.
+        let documents_counts: Vec<_> = match &task.kind {
+            KindDump::MultiIndexDocumentImport { additions, .. } => {
+                additions.iter().map(|addition| Some(addition.documents_count)).collect()
+            }
+            _ => vec![None],
+        };
+
+        let mut content_uuids = match content_file {
+            Some(mut content_file) if task.status == Status::Enqueued => {
+                let mut uuids = Vec::with_capacity(documents_counts.len());
+                for documents_count in documents_counts {
+                    let (uuid, mut file) = self.index_scheduler.queue.create_update_file(false)?;
+                    let mut builder = DocumentsBatchBuilder::new(&mut file);
+                    let documents_count = documents_count.map_or(usize::MAX, |c| c as usize);
+                    for doc in content_file.by_ref().take(documents_count) {
+                        builder.append_json_object(&doc?)?;
+                    }
+                    builder.into_inner()?;
+                    file.persist()?;
+                    uuids.push(uuid);
                 }
-                builder.into_inner()?;
-                file.persist()?;
 
-                Some(uuid)
+                uuids
             }
             // If the task isn't `Enqueued` then just generate a recognisable `Uuid`
             // in case we try to open it later.
-            _ if task.status != Status::Enqueued => Some(Uuid::nil()),
-            _ => None,
+            _ if task.status != Status::Enqueued => vec![Uuid::nil(); documents_counts.len()],
+            _ => Vec::new(),
         };
 
         let task = Task {
@@ -67,19 +81,22 @@ impl<'a> Dump<'a> {
             canceled_by: task.canceled_by,
             details: task.details,
             status: task.status,
+            priority: task.priority,
             kind: match task.kind {
                 KindDump::DocumentImport {
                     primary_key,
                     method,
                     documents_count,
                     allow_index_creation,
+                    skip_malformed_documents,
                 } => KindWithContent::DocumentAdditionOrUpdate {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                     primary_key,
                     method,
-                    content_file: content_uuid.ok_or(Error::CorruptedDump)?,
+                    content_file: content_uuids.pop().ok_or(Error::CorruptedDump)?,
                     documents_count,
                     allow_index_creation,
+                    skip_malformed_documents,
                 },
                 KindDump::DocumentDeletion { documents_ids } => KindWithContent::DocumentDeletion {
                     documents_ids,
@@ -121,7 +138,29 @@ impl<'a> Dump<'a> {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                     primary_key,
                 },
+                KindDump::IndexCompaction => KindWithContent::IndexCompaction {
+                    index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
+                },
                 KindDump::IndexSwap { swaps } => KindWithContent::IndexSwap { swaps },
+                KindDump::MultiIndexDocumentImport { additions, method, allow_index_creation } => {
+                    if additions.len() != content_uuids.len() {
+                        return Err(Error::CorruptedDump);
+                    }
+                    KindWithContent::MultiIndexDocumentAddition {
+                        additions: additions
+                            .into_iter()
+                            .zip(content_uuids)
+                            .map(|(addition, content_file)| IndexDocumentAddition {
+                                index_uid: addition.index_uid,
+                                primary_key: addition.primary_key,
+                                content_file,
+                                documents_count: addition.documents_count,
+                            })
+                            .collect(),
+                        method,
+                        allow_index_creation,
+                    }
+                }
                 KindDump::TaskCancelation { query, tasks } => {
                     KindWithContent::TaskCancelation { query, tasks }
                 }
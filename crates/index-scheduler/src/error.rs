@@ -55,6 +55,10 @@ pub enum Error {
     IndexNotFound(String),
     #[error("Index `{0}` already exists.")]
     IndexAlreadyExists(String),
+    #[error("`{0}` cannot be used both as an index uid and as an alias.")]
+    IndexAliasConflict(String),
+    #[error("Alias `{0}` not found.")]
+    AliasNotFound(String),
     #[error(
         "Indexes must be declared only once during a swap. `{0}` was specified several times."
     )]
@@ -111,6 +115,16 @@ pub enum Error {
     TaskNotFound(TaskId),
     #[error("Batch `{0}` not found.")]
     BatchNotFound(BatchId),
+    #[error("Task `{0}` cannot be prioritized because it is not enqueued.")]
+    TaskNotEnqueued(TaskId),
+    #[error(
+        "Task `{task_uid}` cannot be prioritized because index `{index_uid}` is being processed."
+    )]
+    PrioritizedTaskIndexProcessing { task_uid: TaskId, index_uid: String },
+    #[error("Task `{0}` cannot be requeued because it has neither failed nor been canceled.")]
+    TaskNotRequeuable(TaskId),
+    #[error("Task `{0}` cannot be requeued because its content file has been deleted.")]
+    RequeuedTaskContentFileMissing(TaskId),
     #[error("Query parameters to filter the tasks to delete are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskDeletionWithEmptyQuery,
     #[error("Query parameters to filter the tasks to cancel are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
@@ -173,6 +187,8 @@ impl Error {
             | Error::WithCustomErrorCode(_, _)
             | Error::BadTaskId { .. }
             | Error::IndexAlreadyExists(_)
+            | Error::IndexAliasConflict(_)
+            | Error::AliasNotFound(_)
             | Error::SwapDuplicateIndexFound(_)
             | Error::SwapDuplicateIndexesFound(_)
             | Error::SwapIndexNotFound(_)
@@ -188,6 +204,10 @@ impl Error {
             | Error::InvalidIndexUid { .. }
             | Error::TaskNotFound(_)
             | Error::BatchNotFound(_)
+            | Error::TaskNotEnqueued(_)
+            | Error::PrioritizedTaskIndexProcessing { .. }
+            | Error::TaskNotRequeuable(_)
+            | Error::RequeuedTaskContentFileMissing(_)
             | Error::TaskDeletionWithEmptyQuery
             | Error::TaskCancelationWithEmptyQuery
             | Error::AbortedTask
@@ -235,6 +255,8 @@ impl ErrorCode for Error {
             Error::BadTaskId { .. } => Code::BadRequest,
             Error::IndexNotFound(_) => Code::IndexNotFound,
             Error::IndexAlreadyExists(_) => Code::IndexAlreadyExists,
+            Error::IndexAliasConflict(_) => Code::IndexAliasConflict,
+            Error::AliasNotFound(_) => Code::AliasNotFound,
             Error::SwapDuplicateIndexesFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapDuplicateIndexFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapIndexNotFound(_) => Code::IndexNotFound,
@@ -248,6 +270,10 @@ impl ErrorCode for Error {
             Error::InvalidIndexUid { .. } => Code::InvalidIndexUid,
             Error::TaskNotFound(_) => Code::TaskNotFound,
             Error::BatchNotFound(_) => Code::BatchNotFound,
+            Error::TaskNotEnqueued(_) => Code::BadRequest,
+            Error::PrioritizedTaskIndexProcessing { .. } => Code::BadRequest,
+            Error::TaskNotRequeuable(_) => Code::BadRequest,
+            Error::RequeuedTaskContentFileMissing(_) => Code::BadRequest,
             Error::TaskDeletionWithEmptyQuery => Code::MissingTaskFilters,
             Error::TaskCancelationWithEmptyQuery => Code::MissingTaskFilters,
             // TODO: not sure of the Code to use
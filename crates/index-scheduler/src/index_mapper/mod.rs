@@ -1,13 +1,16 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{fs, thread};
 
 use meilisearch_types::heed::types::{SerdeJson, Str};
-use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn};
+use meilisearch_types::heed::{CompactionOption, Database, Env, RoTxn, RwTxn};
 use meilisearch_types::milli;
+use meilisearch_types::milli::index::DocumentTtl;
 use meilisearch_types::milli::update::IndexerConfig;
 use meilisearch_types::milli::{FieldDistribution, Index};
+use meilisearch_types::tasks::KindWithContent;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tracing::error;
@@ -22,6 +25,7 @@ mod index_map;
 
 const INDEX_MAPPING: &str = "index-mapping";
 const INDEX_STATS: &str = "index-stats";
+const INDEX_ALIASES: &str = "index-aliases";
 
 /// Structure managing meilisearch's indexes.
 ///
@@ -30,6 +34,7 @@ const INDEX_STATS: &str = "index-stats";
 /// 2. Opening indexes and storing references to these opened indexes
 /// 3. Accessing indexes through their uuid
 /// 4. Mapping a user-defined name to each index uuid.
+/// 5. Mapping aliases to the name of the index they point to.
 ///
 /// # Implementation notes
 ///
@@ -60,6 +65,11 @@ pub struct IndexMapper {
     /// Using an UUID forces to use the index_mapping table to recover the index behind a name, ensuring
     /// consistency wrt index swapping.
     pub(crate) index_stats: Database<UuidCodec, SerdeJson<IndexStats>>,
+    /// Map an alias with the name of the index it points to.
+    ///
+    /// An alias and an index never share a name and an alias always points to an index, not to
+    /// another alias. Using the name of the index makes an alias follow its name when swapping.
+    pub(crate) index_aliases: Database<Str, Str>,
 
     /// Path to the folder where the LMDB environments of each index are.
     base_path: PathBuf,
@@ -110,6 +120,12 @@ pub struct IndexStats {
     pub primary_key: Option<String>,
     /// Association of every field name with the number of times it occurs in the documents.
     pub field_distribution: FieldDistribution,
+    /// Association of every faceted field containing numbers with its smallest and biggest value.
+    #[serde(default)]
+    pub numeric_field_bounds: BTreeMap<String, (f64, f64)>,
+    /// Time-to-live of the documents, used to sweep the expired documents without opening every index.
+    #[serde(default)]
+    pub document_ttl: Option<DocumentTtl>,
     /// Creation date of the index.
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
@@ -131,6 +147,8 @@ impl IndexStats {
             used_database_size: index.used_size()?,
             primary_key: index.primary_key(rtxn)?.map(|s| s.to_string()),
             field_distribution: index.field_distribution(rtxn)?,
+            numeric_field_bounds: index.numeric_fields_bounds(rtxn)?,
+            document_ttl: index.document_ttl(rtxn)?,
             created_at: index.created_at(rtxn)?,
             updated_at: index.updated_at(rtxn)?,
         })
@@ -148,6 +166,7 @@ impl IndexMapper {
             index_map: Arc::new(RwLock::new(IndexMap::new(budget.index_count))),
             index_mapping: env.create_database(wtxn, Some(INDEX_MAPPING))?,
             index_stats: env.create_database(wtxn, Some(INDEX_STATS))?,
+            index_aliases: env.create_database(wtxn, Some(INDEX_ALIASES))?,
             base_path: options.indexes_path.clone(),
             index_base_map_size: budget.map_size,
             index_growth_amount: options.index_growth_amount,
@@ -164,6 +183,10 @@ impl IndexMapper {
         name: &str,
         date: Option<(OffsetDateTime, OffsetDateTime)>,
     ) -> Result<Index> {
+        if self.index_aliases.get(&wtxn, name)?.is_some() {
+            return Err(Error::IndexAliasConflict(name.to_string()));
+        }
+
         match self.index(&wtxn, name) {
             Ok(index) => {
                 wtxn.commit()?;
@@ -214,6 +237,13 @@ impl IndexMapper {
         // Once we retrieved the UUID of the index we remove it from the mapping table.
         assert!(self.index_mapping.delete(&mut wtxn, name)?);
 
+        // And we remove the aliases that were pointing to it.
+        for (alias, index_uid) in self.aliases(&wtxn)? {
+            if index_uid == name {
+                self.index_aliases.delete(&mut wtxn, &alias)?;
+            }
+        }
+
         wtxn.commit()?;
 
         let mut tries = 0;
@@ -309,6 +339,78 @@ impl IndexMapper {
         Ok(())
     }
 
+    /// Rewrites the LMDB environment of the specified index without its free pages.
+    ///
+    /// A compacted copy of the environment is written next to it, then the index is closed
+    /// and its data file is replaced by the copy while the index map is locked, so that nobody
+    /// can access the index in the meantime. The index is lazily reopened by the next `index` call.
+    ///
+    /// Returns the size of the data file before and after the compaction, in bytes.
+    pub fn compact_index(&self, rtxn: &RoTxn, name: &str) -> Result<(u64, u64)> {
+        let uuid = self
+            .index_mapping
+            .get(rtxn, name)?
+            .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
+        let index_path = self.base_path.join(uuid.to_string());
+        let data_path = index_path.join("data.mdb");
+        let compacted_path = index_path.join("data.mdb.compacted");
+
+        let index = self.index(rtxn, name)?;
+        let size_before =
+            index.on_disk_size().map_err(|e| Error::from_milli(e, Some(name.to_string())))?;
+        index
+            .copy_to_file(&compacted_path, CompactionOption::Enabled)
+            .map_err(|e| Error::from_milli(e, Some(name.to_string())))?;
+        drop(index);
+
+        let mut tries = 0;
+        // We close the index and wait for all its users to release it before swapping the files.
+        // Between the moment the index is closed and the moment we take the lock, a reader could
+        // reopen it, in which case we must close it again.
+        loop {
+            tries += 1;
+            if tries > 100 {
+                panic!("Too many spurious wake ups while trying to compact the index {name}");
+            }
+
+            // we get the index here to drop the lock before waiting
+            let status = self.index_map.read().unwrap().get(&uuid);
+            if let Closing(closing) = status {
+                // Avoiding deadlocks: no lock taken while doing this operation.
+                if closing.wait_timeout(Duration::from_secs(6)).is_none() {
+                    continue;
+                }
+            }
+
+            let mut index_map = self.index_map.write().unwrap();
+            match index_map.get(&uuid) {
+                Available(_) => {
+                    index_map.close_for_resize(&uuid, self.enable_mdb_writemap, 0);
+                }
+                Closing(closing) => {
+                    if let Some(reopen) = closing.wait_timeout(Duration::ZERO) {
+                        fs::rename(&compacted_path, &data_path)?;
+                        reopen
+                            .reopen(&mut index_map, &index_path)
+                            .map_err(|e| Error::from_milli(e, Some(uuid.to_string())))?;
+                        break;
+                    }
+                }
+                Missing => {
+                    fs::rename(&compacted_path, &data_path)?;
+                    break;
+                }
+                BeingDeleted => {
+                    let _ = fs::remove_file(&compacted_path);
+                    return Err(Error::IndexNotFound(name.to_string()));
+                }
+            }
+        }
+
+        let size_after = fs::metadata(&data_path)?.len();
+        Ok((size_before, size_after))
+    }
+
     /// Return an index, may open it if it wasn't already opened.
     pub fn index(&self, rtxn: &RoTxn, name: &str) -> Result<Index> {
         if let Some((current_name, current_index)) =
@@ -431,6 +533,19 @@ impl IndexMapper {
             .collect()
     }
 
+    /// Return the name of the indexes with a document time-to-live.
+    ///
+    /// The time-to-live is read from the cached stats, only the indexes without stats are opened.
+    pub fn indexes_with_document_ttl(&self, rtxn: &RoTxn) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+        for name in self.index_names(rtxn)? {
+            if self.stats_of(rtxn, &name)?.document_ttl.is_some() {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
     /// Swap two index names.
     pub fn swap(&self, wtxn: &mut RwTxn, lhs: &str, rhs: &str) -> Result<()> {
         let lhs_uuid = self
@@ -492,6 +607,82 @@ impl IndexMapper {
         Ok(self.index_mapping.get(rtxn, name)?.is_some())
     }
 
+    /// Returns the name of the index the alias points to, or the name itself if it isn't an alias.
+    pub fn resolve_alias(&self, rtxn: &RoTxn, name: &str) -> Result<String> {
+        Ok(self.index_aliases.get(rtxn, name)?.unwrap_or(name).to_string())
+    }
+
+    /// Makes the alias point to the index, whether the alias already exists or not.
+    pub fn put_alias(&self, wtxn: &mut RwTxn, alias: &str, index_uid: &str) -> Result<()> {
+        if self.index_mapping.get(wtxn, alias)?.is_some() {
+            return Err(Error::IndexAliasConflict(alias.to_string()));
+        }
+        if self.index_mapping.get(wtxn, index_uid)?.is_none() {
+            return Err(Error::IndexNotFound(index_uid.to_string()));
+        }
+
+        self.index_aliases.put(wtxn, alias, index_uid)?;
+        Ok(())
+    }
+
+    pub fn delete_alias(&self, wtxn: &mut RwTxn, alias: &str) -> Result<()> {
+        if self.index_aliases.delete(wtxn, alias)? {
+            Ok(())
+        } else {
+            Err(Error::AliasNotFound(alias.to_string()))
+        }
+    }
+
+    /// Return every alias along with the name of the index it points to.
+    pub fn aliases(&self, rtxn: &RoTxn) -> Result<Vec<(String, String)>> {
+        self.index_aliases
+            .iter(rtxn)?
+            .map(|res| {
+                res.map_err(Error::from)
+                    .map(|(alias, index_uid)| (alias.to_string(), index_uid.to_string()))
+            })
+            .collect()
+    }
+
+    /// Makes the operations sent to an alias target the index it points to.
+    ///
+    /// The creation of an index that has the name of an alias is rejected, and the swaps
+    /// keep the names they were given.
+    pub fn resolve_task_aliases(&self, rtxn: &RoTxn, kind: &mut KindWithContent) -> Result<()> {
+        use KindWithContent::*;
+
+        match kind {
+            DocumentAdditionOrUpdate { index_uid, .. }
+            | DocumentEdition { index_uid, .. }
+            | DocumentDeletion { index_uid, .. }
+            | DocumentDeletionByFilter { index_uid, .. }
+            | DocumentClear { index_uid }
+            | SettingsUpdate { index_uid, .. }
+            | IndexDeletion { index_uid }
+            | IndexUpdate { index_uid, .. }
+            | IndexCompaction { index_uid } => {
+                *index_uid = self.resolve_alias(rtxn, index_uid)?;
+            }
+            MultiIndexDocumentAddition { additions, .. } => {
+                for addition in additions {
+                    addition.index_uid = self.resolve_alias(rtxn, &addition.index_uid)?;
+                }
+            }
+            IndexCreation { index_uid, .. } => {
+                if self.index_aliases.get(rtxn, index_uid)?.is_some() {
+                    return Err(Error::IndexAliasConflict(index_uid.to_string()));
+                }
+            }
+            IndexSwap { .. }
+            | TaskCancelation { .. }
+            | TaskDeletion { .. }
+            | DumpCreation { .. }
+            | SnapshotCreation => (),
+        }
+
+        Ok(())
+    }
+
     pub fn indexer_config(&self) -> &IndexerConfig {
         &self.indexer_config
     }
@@ -202,6 +202,7 @@ pub fn snapshot_task(task: &Task) -> String {
         details,
         status,
         kind,
+        priority,
     } = task;
     snap.push('{');
     snap.push_str(&format!("uid: {uid}, "));
@@ -209,6 +210,9 @@ pub fn snapshot_task(task: &Task) -> String {
         snap.push_str(&format!("batch_uid: {batch_uid}, "));
     }
     snap.push_str(&format!("status: {status}, "));
+    if *priority != 0 {
+        snap.push_str(&format!("priority: {priority}, "));
+    }
     if let Some(canceled_by) = canceled_by {
         snap.push_str(&format!("canceled_by: {canceled_by}, "));
     }
@@ -229,9 +233,19 @@ fn snapshot_details(d: &Details) -> String {
         Details::DocumentAdditionOrUpdate {
             received_documents,
             indexed_documents,
+            skipped_documents_count: 0,
+            ..
         } => {
             format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?} }}")
         }
+        Details::DocumentAdditionOrUpdate {
+            received_documents,
+            indexed_documents,
+            skipped_documents,
+            skipped_documents_count,
+        } => {
+            format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?}, skipped_documents: {skipped_documents:?}, skipped_documents_count: {skipped_documents_count} }}")
+        }
         Details::DocumentEdition {
             deleted_documents,
             edited_documents,
@@ -279,6 +293,12 @@ fn snapshot_details(d: &Details) -> String {
         Details::IndexSwap { swaps } => {
             format!("{{ swaps: {swaps:?} }}")
         }
+        Details::IndexCompaction { reclaimed_bytes } => {
+            format!("{{ reclaimed_bytes: {reclaimed_bytes:?} }}")
+        }
+        Details::MultiIndexDocumentAddition { indexes } => {
+            format!("{{ indexes: {indexes:?} }}")
+        }
     }
 }
 
@@ -48,17 +48,17 @@ pub use error::Error;
 pub use features::RoFeatures;
 use flate2::bufread::GzEncoder;
 use flate2::Compression;
-use meilisearch_types::batches::Batch;
+use meilisearch_types::batches::{Batch, BatchId};
 use meilisearch_types::features::{InstanceTogglableFeatures, RuntimeTogglableFeatures};
 use meilisearch_types::heed::byteorder::BE;
 use meilisearch_types::heed::types::I128;
-use meilisearch_types::heed::{self, Env, RoTxn};
+use meilisearch_types::heed::{self, Env, RoTxn, RwTxn};
 use meilisearch_types::milli::index::IndexEmbeddingConfig;
 use meilisearch_types::milli::update::IndexerConfig;
 use meilisearch_types::milli::vector::{Embedder, EmbedderOptions, EmbeddingConfigs};
 use meilisearch_types::milli::{self, Index};
 use meilisearch_types::task_view::TaskView;
-use meilisearch_types::tasks::{KindWithContent, Task};
+use meilisearch_types::tasks::{KindWithContent, Status, Task};
 use processing::ProcessingTasks;
 pub use queue::Query;
 use queue::Queue;
@@ -120,6 +120,9 @@ pub struct IndexSchedulerOptions {
     pub batched_tasks_size_limit: u64,
     /// The experimental features enabled for this instance.
     pub instance_features: InstanceTogglableFeatures,
+    /// The interval at which the documents past the time-to-live of their index are deleted.
+    /// `None` disables the periodic sweep of the expired documents.
+    pub document_ttl_sweep_interval: Option<Duration>,
 }
 
 /// Structure which holds meilisearch's indexes and schedules the tasks
@@ -229,7 +232,7 @@ impl IndexScheduler {
 
         let env = unsafe {
             heed::EnvOpenOptions::new()
-                .max_dbs(19)
+                .max_dbs(22)
                 .map_size(budget.task_db_size)
                 .open(&options.tasks_path)
         }?;
@@ -365,7 +368,15 @@ impl IndexScheduler {
                     let ret = catch_unwind(AssertUnwindSafe(|| run.tick()));
                     match ret {
                         Ok(Ok(TickOutcome::TickAgain(_))) => (),
-                        Ok(Ok(TickOutcome::WaitForSignal)) => run.scheduler.wake_up.wait(),
+                        Ok(Ok(TickOutcome::WaitForSignal)) => {
+                            match run.document_ttl_sweep_interval() {
+                                // wake up regularly to sweep the expired documents
+                                Some(interval) => {
+                                    run.scheduler.wake_up.wait_timeout(interval);
+                                }
+                                None => run.scheduler.wake_up.wait(),
+                            }
+                        }
                         Ok(Err(e)) => {
                             tracing::error!("{e}");
                             // Wait one second when an irrecoverable error occurs.
@@ -397,7 +408,7 @@ impl IndexScheduler {
         Ok(self.env.non_free_pages_size()?)
     }
 
-    /// Return the index corresponding to the name.
+    /// Return the index corresponding to the name, or to the alias.
     ///
     /// * If the index wasn't opened before, the index will be opened.
     /// * If the index doesn't exist on disk, the `IndexNotFoundError` is thrown.
@@ -411,12 +422,16 @@ impl IndexScheduler {
     /// If you need to fetch information from or perform an action on all indexes,
     /// see the `try_for_each_index` function.
     pub fn index(&self, name: &str) -> Result<Index> {
-        self.index_mapper.index(&self.env.read_txn()?, name)
+        let rtxn = self.env.read_txn()?;
+        let name = self.index_mapper.resolve_alias(&rtxn, name)?;
+        self.index_mapper.index(&rtxn, &name)
     }
 
-    /// Return the boolean referring if index exists.
+    /// Return the boolean referring if index, or the index the alias points to, exists.
     pub fn index_exists(&self, name: &str) -> Result<bool> {
-        self.index_mapper.index_exists(&self.env.read_txn()?, name)
+        let rtxn = self.env.read_txn()?;
+        let name = self.index_mapper.resolve_alias(&rtxn, name)?;
+        self.index_mapper.index_exists(&rtxn, &name)
     }
 
     /// Return the name of all indexes without opening them.
@@ -425,6 +440,104 @@ impl IndexScheduler {
         self.index_mapper.index_names(&rtxn)
     }
 
+    /// Return the name of the index the alias points to, or the name itself if it isn't an alias.
+    ///
+    /// The API keys and the tenant tokens must be checked against the resolved name, as it is
+    /// the index that is read or modified.
+    pub fn resolve_alias(&self, name: &str) -> Result<String> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.resolve_alias(&rtxn, name)
+    }
+
+    /// Return every alias along with the name of the index it points to.
+    pub fn aliases(&self) -> Result<Vec<(String, String)>> {
+        let rtxn = self.env.read_txn()?;
+        self.index_mapper.aliases(&rtxn)
+    }
+
+    /// Makes the alias point to the index, creating the alias if it doesn't exist.
+    ///
+    /// The index, documents, settings and search routes sent to an alias are applied to the index
+    /// it points to. An alias cannot have the name of an index and must point to an existing index.
+    pub fn put_alias(&self, alias: &str, index_uid: &str) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.put_alias(&mut wtxn, alias, index_uid)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_alias(&self, alias: &str) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.index_mapper.delete_alias(&mut wtxn, alias)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Makes the enqueued task the next one to be processed, the override is kept until the task
+    /// is deleted. When several tasks are prioritized, the last prioritized one is processed first.
+    ///
+    /// The tasks enqueued before it on the same index are still processed first, or in the same
+    /// batch, as it may depend on them. A task cannot be prioritized while its index is being
+    /// processed.
+    pub fn prioritize_task(&self, task_id: TaskId) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        let task =
+            self.queue.tasks.get_task(&wtxn, task_id)?.ok_or(Error::TaskNotFound(task_id))?;
+        let processing = self.processing_tasks.read().unwrap().processing.clone();
+        if task.status != Status::Enqueued || processing.contains(task_id) {
+            return Err(Error::TaskNotEnqueued(task_id));
+        }
+
+        for index_uid in task.indexes() {
+            let index_tasks = self.queue.tasks.index_tasks(&wtxn, index_uid)?;
+            if !processing.is_disjoint(&index_tasks) {
+                return Err(Error::PrioritizedTaskIndexProcessing {
+                    task_uid: task_id,
+                    index_uid: index_uid.to_string(),
+                });
+            }
+        }
+
+        let prioritized_at = OffsetDateTime::now_utc().unix_timestamp_nanos();
+        self.queue.tasks.prioritized.put(&mut wtxn, &task_id, &prioritized_at)?;
+        wtxn.commit()?;
+
+        // notify the scheduler loop to create a batch with the prioritized task
+        self.scheduler.wake_up.signal();
+        Ok(())
+    }
+
+    /// Enqueue a copy of each given failed or canceled task under a new task uid.
+    ///
+    /// The copies reuse the content files of the original tasks where they are still present, and
+    /// fail otherwise. Every task is checked before any copy is registered, so either all the
+    /// tasks are requeued or none of them. The copies are only visible once `wtxn` is committed.
+    pub fn requeue_tasks(&self, wtxn: &mut RwTxn, ids: RoaringBitmap) -> Result<Vec<Task>> {
+        let mut tasks = Vec::with_capacity(ids.len() as usize);
+        for task_id in &ids {
+            let task =
+                self.queue.tasks.get_task(wtxn, task_id)?.ok_or(Error::TaskNotFound(task_id))?;
+            if !matches!(task.status, Status::Failed | Status::Canceled) {
+                return Err(Error::TaskNotRequeuable(task_id));
+            }
+            let file_store = &self.queue.file_store;
+            let content_files = task.content_uuids();
+            if content_files.into_iter().any(|uuid| !file_store.get_update_path(uuid).exists()) {
+                return Err(Error::RequeuedTaskContentFileMissing(task_id));
+            }
+            tasks.push(task);
+        }
+
+        let tasks = tasks
+            .into_iter()
+            .map(|task| self.queue.register(wtxn, &task.kind, None, task.priority, false))
+            .collect::<Result<_>>()?;
+
+        // notify the scheduler loop to execute a new tick
+        self.scheduler.wake_up.signal();
+        Ok(tasks)
+    }
+
     /// Attempts `f` for each index that exists known to the index scheduler.
     ///
     /// It is preferable to use this function rather than a loop that opens all indexes, as a way to avoid having all indexes opened,
@@ -509,6 +622,18 @@ impl IndexScheduler {
         Ok(nbr_index_processing_tasks > 0)
     }
 
+    /// Makes the index uids of the query that are aliases match the tasks of the index
+    /// they point to.
+    fn resolve_query_aliases(&self, rtxn: &RoTxn, query: &Query) -> Result<Query> {
+        let mut query = query.clone();
+        if let Some(index_uids) = query.index_uids.as_mut() {
+            for index_uid in index_uids {
+                *index_uid = self.index_mapper.resolve_alias(rtxn, index_uid)?;
+            }
+        }
+        Ok(query)
+    }
+
     /// Return the tasks matching the query from the user's point of view along
     /// with the total number of tasks matching the query, ignoring from and limit.
     ///
@@ -524,8 +649,9 @@ impl IndexScheduler {
         filters: &meilisearch_auth::AuthFilter,
     ) -> Result<(Vec<Task>, u64)> {
         let rtxn = self.read_txn()?;
+        let query = self.resolve_query_aliases(&rtxn, query)?;
         let processing = self.processing_tasks.read().unwrap();
-        self.queue.get_tasks_from_authorized_indexes(&rtxn, query, filters, &processing)
+        self.queue.get_tasks_from_authorized_indexes(&rtxn, &query, filters, &processing)
     }
 
     /// Return the task ids matching the query along with the total number of tasks
@@ -543,8 +669,9 @@ impl IndexScheduler {
         filters: &meilisearch_auth::AuthFilter,
     ) -> Result<(RoaringBitmap, u64)> {
         let rtxn = self.read_txn()?;
+        let query = self.resolve_query_aliases(&rtxn, query)?;
         let processing = self.processing_tasks.read().unwrap();
-        self.queue.get_task_ids_from_authorized_indexes(&rtxn, query, filters, &processing)
+        self.queue.get_task_ids_from_authorized_indexes(&rtxn, &query, filters, &processing)
     }
 
     /// Return the batches matching the query from the user's point of view along
@@ -562,8 +689,9 @@ impl IndexScheduler {
         filters: &meilisearch_auth::AuthFilter,
     ) -> Result<(Vec<Batch>, u64)> {
         let rtxn = self.read_txn()?;
+        let query = self.resolve_query_aliases(&rtxn, query)?;
         let processing = self.processing_tasks.read().unwrap();
-        self.queue.get_batches_from_authorized_indexes(&rtxn, query, filters, &processing)
+        self.queue.get_batches_from_authorized_indexes(&rtxn, &query, filters, &processing)
     }
 
     /// Return the batch ids matching the query along with the total number of batches
@@ -581,8 +709,32 @@ impl IndexScheduler {
         filters: &meilisearch_auth::AuthFilter,
     ) -> Result<(RoaringBitmap, u64)> {
         let rtxn = self.read_txn()?;
+        let query = self.resolve_query_aliases(&rtxn, query)?;
         let processing = self.processing_tasks.read().unwrap();
-        self.queue.get_batch_ids_from_authorized_indexes(&rtxn, query, filters, &processing)
+        self.queue.get_batch_ids_from_authorized_indexes(&rtxn, &query, filters, &processing)
+    }
+
+    /// Return the tasks of the batch `batch_uid` that the user is allowed to see, in
+    /// ascending order of task id, along with their total number, ignoring `from` and `limit`.
+    ///
+    /// The tasks are read from the mapping of the batches to their tasks, or from the processing
+    /// tasks if the batch is currently being processed. Pass the uid of the last task returned
+    /// plus one as `from` to fetch the next page.
+    pub fn tasks_in_batch(
+        &self,
+        batch_uid: BatchId,
+        from: Option<TaskId>,
+        limit: u32,
+        filters: &meilisearch_auth::AuthFilter,
+    ) -> Result<(Vec<Task>, u64)> {
+        let query = Query {
+            limit: Some(limit),
+            from,
+            reverse: Some(true),
+            batch_uids: Some(vec![batch_uid]),
+            ..Query::default()
+        };
+        self.get_tasks_from_authorized_indexes(&query, filters)
     }
 
     /// Register a new task in the scheduler.
@@ -593,6 +745,20 @@ impl IndexScheduler {
         kind: KindWithContent,
         task_id: Option<TaskId>,
         dry_run: bool,
+    ) -> Result<Task> {
+        self.register_with_priority(kind, task_id, 0, dry_run)
+    }
+
+    /// Register a new task in the scheduler with the given priority.
+    ///
+    /// The enqueued tasks with the highest priority are batched first, tasks sharing
+    /// the same priority are processed in the order they were enqueued.
+    pub fn register_with_priority(
+        &self,
+        mut kind: KindWithContent,
+        task_id: Option<TaskId>,
+        priority: i8,
+        dry_run: bool,
     ) -> Result<Task> {
         // if the task doesn't delete anything and 50% of the task queue is full, we must refuse to enqueue the incomming task
         if !matches!(&kind, KindWithContent::TaskDeletion { tasks, .. } if !tasks.is_empty())
@@ -602,7 +768,8 @@ impl IndexScheduler {
         }
 
         let mut wtxn = self.env.write_txn()?;
-        let task = self.queue.register(&mut wtxn, &kind, task_id, dry_run)?;
+        self.index_mapper.resolve_task_aliases(&wtxn, &mut kind)?;
+        let task = self.queue.register(&mut wtxn, &kind, task_id, priority, dry_run)?;
 
         // If the registered task is a task cancelation
         // we inform the processing tasks to stop (if necessary).
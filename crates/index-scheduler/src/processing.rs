@@ -122,6 +122,21 @@ make_enum_progress! {
     }
 }
 
+make_enum_progress! {
+    pub enum CompactIndexProgress {
+        CompactingTheIndex,
+    }
+}
+
+make_enum_progress! {
+    pub enum MultiIndexDocumentAdditionProgress {
+        RetrievingTheIndexes,
+        IndexingTheDocuments,
+        CommittingTheIndexes,
+        RollingBackTheCreatedIndexes,
+    }
+}
+
 make_enum_progress! {
     pub enum SwappingTheIndexes {
         EnsuringCorrectnessOfTheSwap,
@@ -214,8 +214,10 @@ impl BatchQueue {
             .into_iter()
             .map(|batch_id| {
                 if Some(batch_id) == processing.batch.as_ref().map(|batch| batch.uid) {
-                    let mut batch = processing.batch.as_ref().unwrap().to_batch();
+                    let processing_batch = processing.batch.as_ref().unwrap();
+                    let mut batch = processing_batch.to_batch();
                     batch.progress = processing.get_progress_view();
+                    batch.stats.progress_percent = processing_batch.progress_percent();
                     Ok(batch)
                 } else {
                     self.get_batch(rtxn, batch_id)
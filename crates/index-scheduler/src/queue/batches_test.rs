@@ -1,3 +1,5 @@
+use std::sync::atomic::Ordering;
+
 use meili_snap::snapshot;
 use meilisearch_auth::AuthFilter;
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
@@ -5,8 +7,10 @@ use meilisearch_types::tasks::{IndexSwap, KindWithContent, Status};
 use time::{Duration, OffsetDateTime};
 
 use crate::insta_snapshot::{snapshot_bitmap, snapshot_index_scheduler};
+use crate::processing::AtomicDocumentStep;
 use crate::test_utils::Breakpoint::*;
 use crate::test_utils::{index_creation_task, FailureLocation};
+use crate::utils::ProcessingBatch;
 use crate::{IndexScheduler, Query};
 
 #[test]
@@ -120,13 +124,35 @@ fn query_batches_simple() {
             },
             "indexUids": {
               "catto": 1
-            }
+            },
+            "progressPercent": 0
           },
           "startedAt": "1970-01-01T00:00:00Z",
           "finishedAt": null
         }
         "#);
 
+    // the percentage only counts the tasks that reached a terminal status,
+    // the progress of the steps of the batch doesn't move it
+    let progress = index_scheduler.processing_tasks.read().unwrap().progress.clone().unwrap();
+    let (atomic, step) = AtomicDocumentStep::new(4);
+    atomic.store(2, Ordering::Relaxed);
+    progress.update_progress(step);
+    let (batches, _) = index_scheduler
+        .get_batches_from_authorized_indexes(&query, &AuthFilter::default())
+        .unwrap();
+    snapshot!(format!("{:?}", batches[0].stats.progress_percent), @"Some(0)");
+
+    let mut batch = ProcessingBatch::new(42);
+    snapshot!(format!("{:?}", batch.progress_percent()), @"None");
+    batch.stats.total_nb_tasks = 4;
+    batch.stats.status.extend([
+        (Status::Succeeded, 1),
+        (Status::Failed, 1),
+        (Status::Processing, 2),
+    ]);
+    snapshot!(format!("{:?}", batch.progress_percent()), @"Some(50)");
+
     let query = Query { statuses: Some(vec![Status::Enqueued]), ..Default::default() };
     let (batches, _) = index_scheduler
         .get_batch_ids_from_authorized_indexes(&query, &AuthFilter::default())
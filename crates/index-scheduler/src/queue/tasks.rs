@@ -22,6 +22,8 @@ mod db_name {
     pub const ENQUEUED_AT: &str = "enqueued-at";
     pub const STARTED_AT: &str = "started-at";
     pub const FINISHED_AT: &str = "finished-at";
+    pub const PRIORITIZED: &str = "prioritized";
+    pub const PRIORITY: &str = "priority";
 }
 
 pub struct TaskQueue {
@@ -43,6 +45,10 @@ pub struct TaskQueue {
     pub(crate) started_at: Database<BEI128, CboRoaringBitmapCodec>,
     /// Store the task ids of tasks which finished at a specific date
     pub(crate) finished_at: Database<BEI128, CboRoaringBitmapCodec>,
+    /// Store the date at which a task was manually prioritized
+    pub(crate) prioritized: Database<BEU32, BEI128>,
+    /// All the tasks ids grouped by their priority, tasks with the default priority are not stored
+    pub(crate) priority: Database<SerdeBincode<i8>, RoaringBitmapCodec>,
 }
 
 impl TaskQueue {
@@ -56,6 +62,8 @@ impl TaskQueue {
             enqueued_at: self.enqueued_at,
             started_at: self.started_at,
             finished_at: self.finished_at,
+            prioritized: self.prioritized,
+            priority: self.priority,
         }
     }
 
@@ -69,6 +77,8 @@ impl TaskQueue {
             enqueued_at: env.create_database(wtxn, Some(db_name::ENQUEUED_AT))?,
             started_at: env.create_database(wtxn, Some(db_name::STARTED_AT))?,
             finished_at: env.create_database(wtxn, Some(db_name::FINISHED_AT))?,
+            prioritized: env.create_database(wtxn, Some(db_name::PRIORITIZED))?,
+            priority: env.create_database(wtxn, Some(db_name::PRIORITY))?,
         })
     }
 
@@ -84,6 +94,47 @@ impl TaskQueue {
         enum_iterator::all().map(|s| self.get_status(rtxn, s)).union()
     }
 
+    /// Returns the enqueued task that was prioritized the last, if any.
+    pub(crate) fn prioritized_task(
+        &self,
+        rtxn: &RoTxn,
+        enqueued: &RoaringBitmap,
+    ) -> Result<Option<TaskId>> {
+        let mut prioritized: Option<(TaskId, i128)> = None;
+        for result in self.prioritized.iter(rtxn)? {
+            let (task_id, prioritized_at) = result?;
+            if enqueued.contains(task_id)
+                && prioritized.map_or(true, |(_, at)| prioritized_at >= at)
+            {
+                prioritized = Some((task_id, prioritized_at));
+            }
+        }
+        Ok(prioritized.map(|(task_id, _)| task_id))
+    }
+
+    /// Returns the enqueued tasks sharing the highest priority.
+    pub(crate) fn highest_priority_tasks(
+        &self,
+        rtxn: &RoTxn,
+        enqueued: &RoaringBitmap,
+    ) -> Result<RoaringBitmap> {
+        let mut default_priority = enqueued.clone();
+        let mut highest: Option<(i8, RoaringBitmap)> = None;
+        for result in self.priority.iter(rtxn)? {
+            let (priority, tasks) = result?;
+            default_priority -= &tasks;
+            let tasks = tasks & enqueued;
+            if !tasks.is_empty() && highest.as_ref().map_or(true, |(p, _)| priority > *p) {
+                highest = Some((priority, tasks));
+            }
+        }
+
+        Ok(match highest {
+            Some((priority, tasks)) if priority > 0 || default_priority.is_empty() => tasks,
+            _ => default_priority,
+        })
+    }
+
     pub(crate) fn get_task(&self, rtxn: &RoTxn, task_id: TaskId) -> Result<Option<Task>> {
         Ok(self.all_tasks.get(rtxn, &task_id)?)
     }
@@ -246,6 +297,12 @@ impl TaskQueue {
 
         utils::insert_task_datetime(wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
 
+        if task.priority != 0 {
+            let mut tasks = self.priority.get(wtxn, &task.priority)?.unwrap_or_default();
+            tasks.insert(task.uid);
+            self.priority.put(wtxn, &task.priority, &tasks)?;
+        }
+
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use std::ops::Bound;
+
 use meili_snap::snapshot;
 use meilisearch_auth::AuthFilter;
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
@@ -7,6 +9,7 @@ use time::{Duration, OffsetDateTime};
 use crate::insta_snapshot::{snapshot_bitmap, snapshot_index_scheduler};
 use crate::test_utils::Breakpoint::*;
 use crate::test_utils::{index_creation_task, FailureLocation};
+use crate::utils::{keep_ids_within_datetime_bounds, keep_ids_within_datetimes};
 use crate::{IndexScheduler, Query};
 
 #[test]
@@ -439,3 +442,79 @@ fn query_tasks_canceled_by() {
     // Return only 1 because the user is not authorized to see task 2
     snapshot!(snapshot_bitmap(&tasks), @"[1,]");
 }
+
+#[test]
+fn query_tasks_in_batch() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let kind = index_creation_task("doggo", "bone");
+    let _ = index_scheduler.register(kind, None, false).unwrap();
+    handle.advance_one_successful_batch();
+
+    for id in ["1", "2", "3"] {
+        let kind = KindWithContent::DocumentDeletion {
+            index_uid: "doggo".to_owned(),
+            documents_ids: vec![id.to_owned()],
+        };
+        let _ = index_scheduler.register(kind, None, false).unwrap();
+    }
+    // the three deletions are batched together
+    handle.advance_one_successful_batch();
+
+    let kind = index_creation_task("catto", "mouse");
+    let _ = index_scheduler.register(kind, None, false).unwrap();
+    handle.advance_one_successful_batch();
+
+    let filters = AuthFilter::default();
+    let (tasks, total) = index_scheduler.tasks_in_batch(1, None, 2, &filters).unwrap();
+    let uids: Vec<_> = tasks.iter().map(|task| task.uid).collect();
+    snapshot!(format!("{uids:?} {total}"), @"[1, 2] 3");
+
+    let (tasks, total) = index_scheduler.tasks_in_batch(1, Some(3), 2, &filters).unwrap();
+    let uids: Vec<_> = tasks.iter().map(|task| task.uid).collect();
+    snapshot!(format!("{uids:?} {total}"), @"[3] 3");
+
+    let (tasks, total) = index_scheduler.tasks_in_batch(2, None, 20, &filters).unwrap();
+    let uids: Vec<_> = tasks.iter().map(|task| task.uid).collect();
+    snapshot!(format!("{uids:?} {total}"), @"[4] 1");
+
+    let (tasks, total) = index_scheduler.tasks_in_batch(42, None, 20, &filters).unwrap();
+    assert!(tasks.is_empty());
+    assert_eq!(total, 0);
+
+    // the user is not allowed to see the tasks of the doggo index
+    let filters = AuthFilter::with_allowed_indexes(
+        vec![IndexUidPattern::new_unchecked("catto")].into_iter().collect(),
+    );
+    let (tasks, _) = index_scheduler.tasks_in_batch(1, None, 20, &filters).unwrap();
+    assert!(tasks.is_empty());
+}
+
+#[test]
+fn query_tasks_within_inclusive_datetime_bounds() {
+    let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+    let kind = index_creation_task("doggo", "bone");
+    let task = index_scheduler.register(kind, None, false).unwrap();
+    let enqueued_at = task.enqueued_at;
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let database = index_scheduler.queue.tasks.enqueued_at;
+    let all_tasks = index_scheduler.queue.tasks.all_task_ids(&rtxn).unwrap();
+
+    // a task enqueued exactly at the boundary is excluded by default...
+    let mut tasks = all_tasks.clone();
+    keep_ids_within_datetimes(&rtxn, &mut tasks, database, Some(enqueued_at), None).unwrap();
+    snapshot!(snapshot_bitmap(&tasks), @"[]");
+
+    // ...but kept when the bounds are inclusive.
+    let (after, before) = (Bound::Included(enqueued_at), Bound::Included(enqueued_at));
+    let mut tasks = all_tasks.clone();
+    keep_ids_within_datetime_bounds(&rtxn, &mut tasks, database, after, before).unwrap();
+    snapshot!(snapshot_bitmap(&tasks), @"[0,]");
+
+    let (after, before) = (Bound::Included(enqueued_at), Bound::Excluded(enqueued_at));
+    let mut tasks = all_tasks;
+    keep_ids_within_datetime_bounds(&rtxn, &mut tasks, database, after, before).unwrap();
+    snapshot!(snapshot_bitmap(&tasks), @"[]");
+}
@@ -35,7 +35,9 @@ enum AutobatchKind {
     IndexCreation,
     IndexDeletion,
     IndexUpdate,
+    IndexCompaction,
     IndexSwap,
+    MultiIndexDocumentAddition,
 }
 
 impl AutobatchKind {
@@ -81,7 +83,11 @@ impl From<KindWithContent> for AutobatchKind {
             KindWithContent::IndexDeletion { .. } => AutobatchKind::IndexDeletion,
             KindWithContent::IndexCreation { .. } => AutobatchKind::IndexCreation,
             KindWithContent::IndexUpdate { .. } => AutobatchKind::IndexUpdate,
+            KindWithContent::IndexCompaction { .. } => AutobatchKind::IndexCompaction,
             KindWithContent::IndexSwap { .. } => AutobatchKind::IndexSwap,
+            KindWithContent::MultiIndexDocumentAddition { .. } => {
+                AutobatchKind::MultiIndexDocumentAddition
+            }
             KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
@@ -128,9 +134,15 @@ pub enum BatchKind {
     IndexUpdate {
         id: TaskId,
     },
+    IndexCompaction {
+        id: TaskId,
+    },
     IndexSwap {
         id: TaskId,
     },
+    MultiIndexDocumentAddition {
+        id: TaskId,
+    },
 }
 
 impl BatchKind {
@@ -169,7 +181,11 @@ impl BatchKind {
             K::IndexCreation => (Break(BatchKind::IndexCreation { id: task_id }), true),
             K::IndexDeletion => (Break(BatchKind::IndexDeletion { ids: vec![task_id] }), false),
             K::IndexUpdate => (Break(BatchKind::IndexUpdate { id: task_id }), false),
+            K::IndexCompaction => (Break(BatchKind::IndexCompaction { id: task_id }), false),
             K::IndexSwap => (Break(BatchKind::IndexSwap { id: task_id }), false),
+            K::MultiIndexDocumentAddition => {
+                (Break(BatchKind::MultiIndexDocumentAddition { id: task_id }), false)
+            }
             K::DocumentClear => (Continue(BatchKind::DocumentClear { ids: vec![task_id] }), false),
             K::DocumentImport { method, allow_index_creation, primary_key: pk }
                 if primary_key.is_none() || pk.is_none() || primary_key == pk.as_deref() =>
@@ -219,7 +235,7 @@ impl BatchKind {
 
         match (self, kind) {
             // We don't batch any of these operations
-            (this, K::IndexCreation | K::IndexUpdate | K::IndexSwap | K::DocumentEdition) => Break(this),
+            (this, K::IndexCreation | K::IndexUpdate | K::IndexCompaction | K::IndexSwap | K::MultiIndexDocumentAddition | K::DocumentEdition) => Break(this),
             // We must not batch tasks that don't have the same index creation rights if the index doesn't already exists.
             (this, kind) if !index_already_exists && this.allow_index_creation() == Some(false) && kind.allow_index_creation() == Some(true) => {
                 Break(this)
@@ -464,7 +480,9 @@ impl BatchKind {
                 BatchKind::IndexCreation { .. }
                 | BatchKind::IndexDeletion { .. }
                 | BatchKind::IndexUpdate { .. }
+                | BatchKind::IndexCompaction { .. }
                 | BatchKind::IndexSwap { .. }
+                | BatchKind::MultiIndexDocumentAddition { .. }
                 | BatchKind::DocumentEdition { .. },
                 _,
             ) => {
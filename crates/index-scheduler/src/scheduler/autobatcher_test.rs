@@ -40,6 +40,7 @@ fn doc_imp(
         content_file: Uuid::new_v4(),
         documents_count: 0,
         allow_index_creation,
+        skip_malformed_documents: false,
     }
 }
 
@@ -44,14 +44,21 @@ pub(crate) enum Batch {
         tasks: Vec<Task>,
         index_has_been_created: bool,
     },
+    IndexCompaction {
+        index_uid: String,
+        task: Task,
+    },
     IndexSwap {
         task: Task,
     },
+    MultiIndexDocumentAddition {
+        task: Task,
+    },
 }
 
 #[derive(Debug)]
 pub(crate) enum DocumentOperation {
-    Add(Uuid),
+    Add { content_file: Uuid, skip_malformed_documents: bool },
     Delete(Vec<String>),
 }
 
@@ -100,7 +107,8 @@ impl Batch {
             Batch::TaskCancelation { task, .. }
             | Batch::Dump(task)
             | Batch::IndexCreation { task, .. }
-            | Batch::IndexUpdate { task, .. } => {
+            | Batch::IndexUpdate { task, .. }
+            | Batch::IndexCompaction { task, .. } => {
                 RoaringBitmap::from_sorted_iter(std::iter::once(task.uid)).unwrap()
             }
             Batch::SnapshotCreation(tasks)
@@ -124,7 +132,7 @@ impl Batch {
                     ..
                 } => RoaringBitmap::from_iter(tasks.iter().chain(other).map(|task| task.uid)),
             },
-            Batch::IndexSwap { task } => {
+            Batch::IndexSwap { task } | Batch::MultiIndexDocumentAddition { task } => {
                 RoaringBitmap::from_sorted_iter(std::iter::once(task.uid)).unwrap()
             }
         }
@@ -138,11 +146,13 @@ impl Batch {
             | TaskDeletions(_)
             | SnapshotCreation(_)
             | Dump(_)
-            | IndexSwap { .. } => None,
+            | IndexSwap { .. }
+            | MultiIndexDocumentAddition { .. } => None,
             IndexOperation { op, .. } => Some(op.index_uid()),
             IndexCreation { index_uid, .. }
             | IndexUpdate { index_uid, .. }
-            | IndexDeletion { index_uid, .. } => Some(index_uid),
+            | IndexDeletion { index_uid, .. }
+            | IndexCompaction { index_uid, .. } => Some(index_uid),
         }
     }
 }
@@ -161,7 +171,11 @@ impl fmt::Display for Batch {
             Batch::IndexCreation { .. } => f.write_str("IndexCreation")?,
             Batch::IndexUpdate { .. } => f.write_str("IndexUpdate")?,
             Batch::IndexDeletion { .. } => f.write_str("IndexDeletion")?,
+            Batch::IndexCompaction { .. } => f.write_str("IndexCompaction")?,
             Batch::IndexSwap { .. } => f.write_str("IndexSwap")?,
+            Batch::MultiIndexDocumentAddition { .. } => {
+                f.write_str("MultiIndexDocumentAddition")?
+            }
         };
         match index_uid {
             Some(name) => f.write_fmt(format_args!(" on {name:?} from tasks: {tasks:?}")),
@@ -270,8 +284,15 @@ impl IndexScheduler {
 
                 for task in tasks.iter() {
                     match task.kind {
-                        KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => {
-                            operations.push(DocumentOperation::Add(content_file));
+                        KindWithContent::DocumentAdditionOrUpdate {
+                            content_file,
+                            skip_malformed_documents,
+                            ..
+                        } => {
+                            operations.push(DocumentOperation::Add {
+                                content_file,
+                                skip_malformed_documents,
+                            });
                         }
                         KindWithContent::DocumentDeletion { ref documents_ids, .. } => {
                             operations.push(DocumentOperation::Delete(documents_ids.clone()));
@@ -400,12 +421,24 @@ impl IndexScheduler {
                     ids,
                 )?,
             })),
+            BatchKind::IndexCompaction { id } => {
+                let mut task =
+                    self.queue.tasks.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                current_batch.processing(Some(&mut task));
+                Ok(Some(Batch::IndexCompaction { index_uid, task }))
+            }
             BatchKind::IndexSwap { id } => {
                 let mut task =
                     self.queue.tasks.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
                 current_batch.processing(Some(&mut task));
                 Ok(Some(Batch::IndexSwap { task }))
             }
+            BatchKind::MultiIndexDocumentAddition { id } => {
+                let mut task =
+                    self.queue.tasks.get_task(rtxn, id)?.ok_or(Error::CorruptedTaskQueue)?;
+                current_batch.processing(Some(&mut task));
+                Ok(Some(Batch::MultiIndexDocumentAddition { task }))
+            }
         }
     }
 
@@ -414,7 +447,8 @@ impl IndexScheduler {
     /// 2. We get the *next* task to delete.
     /// 3. We get the *next* snapshot to process.
     /// 4. We get the *next* dump to process.
-    /// 5. We get the *next* tasks to process for a specific index.
+    /// 5. We get the *next* tasks to process for a specific index, starting with the index of
+    ///    the last prioritized task or else of the oldest task with the highest priority.
     #[tracing::instrument(level = "trace", skip(self, rtxn), target = "indexing::scheduler")]
     pub(crate) fn create_next_batch(
         &self,
@@ -462,8 +496,16 @@ impl IndexScheduler {
             return Ok(Some((Batch::Dump(task), current_batch)));
         }
 
-        // 5. We make a batch from the unprioritised tasks. Start by taking the next enqueued task.
-        let task_id = if let Some(task_id) = enqueued.min() { task_id } else { return Ok(None) };
+        // 5. We make a batch from the unprioritised tasks. Start by taking the last manually
+        //    prioritized task or, if there is none, the oldest enqueued task of the highest
+        //    priority.
+        let task_id = match self.queue.tasks.prioritized_task(rtxn, enqueued)? {
+            Some(task_id) => task_id,
+            None => match self.queue.tasks.highest_priority_tasks(rtxn, enqueued)?.min() {
+                Some(task_id) => task_id,
+                None => return Ok(None),
+            },
+        };
         let mut task =
             self.queue.tasks.get_task(rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
 
@@ -506,7 +548,7 @@ impl IndexScheduler {
                 .get_task(rtxn, task_id)
                 .and_then(|task| task.ok_or(Error::CorruptedTaskQueue))?;
 
-            if let Some(uuid) = task.content_uuid() {
+            for uuid in task.content_uuids() {
                 let content_size = self.queue.file_store.compute_size(uuid)?;
                 total_size = total_size.saturating_add(content_size);
             }
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use meilisearch_types::milli;
+use meilisearch_types::tasks::{KindWithContent, Status};
+use time::OffsetDateTime;
+
+use crate::{Error, IndexScheduler, Result};
+
+impl IndexScheduler {
+    /// Returns the interval at which the run loop must wake up to sweep the expired documents,
+    /// `None` if the sweep is disabled or if no index has a time-to-live.
+    pub(crate) fn document_ttl_sweep_interval(&self) -> Option<Duration> {
+        let interval = self.scheduler.document_ttl_sweep_interval?;
+        let indexes = self
+            .env
+            .read_txn()
+            .map_err(Error::from)
+            .and_then(|rtxn| self.index_mapper.indexes_with_document_ttl(&rtxn));
+        match indexes {
+            Ok(indexes) => (!indexes.is_empty()).then_some(interval),
+            // keep sweeping, the error is going to be logged by the sweep
+            Err(_) => Some(interval),
+        }
+    }
+
+    /// Enqueue a document deletion for the documents of every index that are past
+    /// the time-to-live configured in its settings.
+    ///
+    /// Only the indexes with a time-to-live are opened. An index that still has
+    /// enqueued or processing tasks is skipped, its expired documents are going to
+    /// be deleted by the first sweep following the processing of its tasks.
+    pub(crate) fn enqueue_expired_documents_deletions(&self) -> Result<()> {
+        let now = OffsetDateTime::now_utc().unix_timestamp() as f64;
+
+        let rtxn = self.env.read_txn()?;
+        let busy = self.queue.tasks.get_status(&rtxn, Status::Enqueued)?
+            | &*self.processing_tasks.read().unwrap().processing;
+
+        let mut deletions = Vec::new();
+        for index_uid in self.index_mapper.indexes_with_document_ttl(&rtxn)? {
+            let index_tasks = self.queue.tasks.index_tasks(&rtxn, &index_uid)?;
+            if !index_tasks.is_disjoint(&busy) {
+                continue;
+            }
+
+            let index = self.index_mapper.index(&rtxn, &index_uid)?;
+            let index_rtxn = index.read_txn()?;
+            let expired = index
+                .expired_documents(&index_rtxn, now)
+                .map_err(|e| Error::from_milli(e, Some(index_uid.clone())))?;
+            if expired.is_empty() {
+                continue;
+            }
+
+            let documents_ids = index
+                .external_id_of(&index_rtxn, expired)
+                .and_then(|ids| ids.into_iter().collect::<milli::Result<Vec<_>>>())
+                .map_err(|e| Error::from_milli(e, Some(index_uid.clone())))?;
+            deletions.push(KindWithContent::DocumentDeletion { index_uid, documents_ids });
+        }
+        drop(rtxn);
+
+        if deletions.is_empty() {
+            return Ok(());
+        }
+
+        let mut wtxn = self.env.write_txn()?;
+        for deletion in &deletions {
+            self.queue.register(&mut wtxn, deletion, None, 0, false)?;
+        }
+        wtxn.commit()?;
+
+        Ok(())
+    }
+}
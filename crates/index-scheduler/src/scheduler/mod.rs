@@ -2,9 +2,11 @@ mod autobatcher;
 #[cfg(test)]
 mod autobatcher_test;
 mod create_batch;
+mod enqueue_expired_documents;
 mod process_batch;
 mod process_dump_creation;
 mod process_index_operation;
+mod process_multi_index_document_addition;
 mod process_snapshot_creation;
 #[cfg(test)]
 mod test;
@@ -17,7 +19,8 @@ mod test_failure;
 
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::milli;
@@ -74,6 +77,12 @@ pub struct Scheduler {
 
     /// The path to the version file of Meilisearch.
     pub(crate) version_file_path: PathBuf,
+
+    /// The interval at which the expired documents are deleted, `None` if they are never deleted.
+    pub(crate) document_ttl_sweep_interval: Option<Duration>,
+
+    /// The last time the expired documents were looked for.
+    pub(crate) last_document_ttl_sweep: Arc<Mutex<Instant>>,
 }
 
 impl Scheduler {
@@ -88,6 +97,8 @@ impl Scheduler {
             snapshots_path: self.snapshots_path.clone(),
             auth_path: self.auth_path.clone(),
             version_file_path: self.version_file_path.clone(),
+            document_ttl_sweep_interval: self.document_ttl_sweep_interval,
+            last_document_ttl_sweep: self.last_document_ttl_sweep.clone(),
         }
     }
 
@@ -103,14 +114,27 @@ impl Scheduler {
             snapshots_path: options.snapshots_path.clone(),
             auth_path: options.auth_path.clone(),
             version_file_path: options.version_file_path.clone(),
+            document_ttl_sweep_interval: options.document_ttl_sweep_interval,
+            last_document_ttl_sweep: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Returns `true` if the expired documents must be looked for, and resets the sweep timer.
+    pub(crate) fn document_ttl_sweep_is_due(&self) -> bool {
+        let Some(interval) = self.document_ttl_sweep_interval else { return false };
+        let mut last_sweep = self.last_document_ttl_sweep.lock().unwrap();
+        if last_sweep.elapsed() < interval {
+            return false;
         }
+        *last_sweep = Instant::now();
+        true
     }
 }
 
 impl IndexScheduler {
     /// Perform one iteration of the run loop.
     ///
-    /// 1. See if we need to cleanup the task queue
+    /// 1. See if we need to cleanup the task queue and to delete the expired documents
     /// 2. Find the next batch of tasks to be processed.
     /// 3. Update the information of these tasks following the start of their processing.
     /// 4. Update the in-memory list of processed tasks accordingly.
@@ -134,6 +158,13 @@ impl IndexScheduler {
             wtxn.commit()?;
         }
 
+        if self.scheduler.document_ttl_sweep_is_due() {
+            // the expired documents are swept again later, it must not prevent processing the tasks
+            if let Err(e) = self.enqueue_expired_documents_deletions() {
+                tracing::error!("Could not enqueue the deletion of the expired documents: {e}");
+            }
+        }
+
         let rtxn = self.env.read_txn().map_err(Error::HeedTransaction)?;
         let (batch, mut processing_batch) =
             match self.create_next_batch(&rtxn).map_err(|e| Error::CreateBatch(Box::new(e)))? {
@@ -1,5 +1,6 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 use meilisearch_types::batches::BatchId;
 use meilisearch_types::heed::{RoTxn, RwTxn};
@@ -8,12 +9,13 @@ use meilisearch_types::milli::{self};
 use meilisearch_types::tasks::{Details, IndexSwap, KindWithContent, Status, Task};
 use milli::update::Settings as MilliSettings;
 use roaring::RoaringBitmap;
+use time::OffsetDateTime;
 
 use super::create_batch::Batch;
 use crate::processing::{
-    AtomicBatchStep, AtomicTaskStep, CreateIndexProgress, DeleteIndexProgress,
-    InnerSwappingTwoIndexes, SwappingTheIndexes, TaskCancelationProgress, TaskDeletionProgress,
-    UpdateIndexProgress, VariableNameStep,
+    AtomicBatchStep, AtomicTaskStep, CompactIndexProgress, CreateIndexProgress,
+    DeleteIndexProgress, InnerSwappingTwoIndexes, SwappingTheIndexes, TaskCancelationProgress,
+    TaskDeletionProgress, UpdateIndexProgress, VariableNameStep,
 };
 use crate::utils::{self, swap_index_uid_in_task, ProcessingBatch};
 use crate::{Error, IndexScheduler, Result, TaskId};
@@ -266,6 +268,41 @@ impl IndexScheduler {
 
                 Ok(tasks)
             }
+            Batch::IndexCompaction { index_uid, mut task } => {
+                progress.update_progress(CompactIndexProgress::CompactingTheIndex);
+                let rtxn = self.env.read_txn()?;
+                let (size_before, size_after) =
+                    self.index_mapper.compact_index(&rtxn, &index_uid)?;
+                let index = self.index_mapper.index(&rtxn, &index_uid)?;
+                rtxn.commit()?;
+
+                task.status = Status::Succeeded;
+                task.details = Some(Details::IndexCompaction {
+                    reclaimed_bytes: Some(size_before.saturating_sub(size_after)),
+                });
+
+                // The size of the index changed, we must update its stats. As the compaction
+                // already succeeded, failing to store them must not fail the entire batch.
+                let res = || -> Result<()> {
+                    let mut wtxn = self.env.write_txn()?;
+                    let index_rtxn = index.read_txn()?;
+                    let stats = crate::index_mapper::IndexStats::new(&index, &index_rtxn)
+                        .map_err(|e| Error::from_milli(e, Some(index_uid.clone())))?;
+                    self.index_mapper.store_stats_of(&mut wtxn, &index_uid, &stats)?;
+                    wtxn.commit()?;
+                    Ok(())
+                }();
+
+                match res {
+                    Ok(_) => (),
+                    Err(e) => tracing::error!(
+                        error = &e as &dyn std::error::Error,
+                        "Could not write the stats of the index"
+                    ),
+                }
+
+                Ok(vec![task])
+            }
             Batch::IndexSwap { mut task } => {
                 progress.update_progress(SwappingTheIndexes::EnsuringCorrectnessOfTheSwap);
 
@@ -314,6 +351,9 @@ impl IndexScheduler {
                 task.status = Status::Succeeded;
                 Ok(vec![task])
             }
+            Batch::MultiIndexDocumentAddition { task } => {
+                self.process_multi_index_document_addition(task, progress)
+            }
         }
     }
 
@@ -401,6 +441,7 @@ impl IndexScheduler {
         let mut affected_statuses = HashSet::new();
         let mut affected_kinds = HashSet::new();
         let mut affected_canceled_by = RoaringBitmap::new();
+        let mut affected_priorities = HashSet::new();
         // The tasks that have been removed *per batches*.
         let mut affected_batches: HashMap<BatchId, RoaringBitmap> = HashMap::new();
 
@@ -442,6 +483,9 @@ impl IndexScheduler {
             if let Some(canceled_by) = task.canceled_by {
                 affected_canceled_by.insert(canceled_by);
             }
+            if task.priority != 0 {
+                affected_priorities.insert(task.priority);
+            }
             if let Some(batch_uid) = task.batch_uid {
                 affected_batches.entry(batch_uid).or_default().insert(task_id);
             }
@@ -473,6 +517,7 @@ impl IndexScheduler {
         progress.update_progress(task_progress);
         for task in to_delete_tasks.iter() {
             self.queue.tasks.all_tasks.delete(wtxn, &task)?;
+            self.queue.tasks.prioritized.delete(wtxn, &task)?;
             atomic_progress.fetch_add(1, Ordering::Relaxed);
         }
         for canceled_by in affected_canceled_by {
@@ -485,6 +530,16 @@ impl IndexScheduler {
                 }
             }
         }
+        for priority in affected_priorities {
+            if let Some(mut tasks) = self.queue.tasks.priority.get(wtxn, &priority)? {
+                tasks -= &to_delete_tasks;
+                if tasks.is_empty() {
+                    self.queue.tasks.priority.delete(wtxn, &priority)?;
+                } else {
+                    self.queue.tasks.priority.put(wtxn, &priority, &tasks)?;
+                }
+            }
+        }
         progress.update_progress(TaskDeletionProgress::DeletingBatches);
         let (atomic_progress, batch_progress) = AtomicBatchStep::new(affected_batches.len() as u32);
         progress.update_progress(batch_progress);
@@ -535,6 +590,32 @@ impl IndexScheduler {
         Ok(to_delete_tasks)
     }
 
+    /// Delete the succeeded, failed and canceled tasks that finished more than `age` ago.
+    ///
+    /// The enqueued and processing tasks are never deleted, it is therefore safe to call this
+    /// method while a batch is being processed. Returns the ids of the deleted tasks.
+    pub fn delete_finished_tasks_older_than(
+        &self,
+        wtxn: &mut RwTxn,
+        age: Duration,
+    ) -> Result<RoaringBitmap> {
+        let mut finished_tasks = RoaringBitmap::new();
+        for status in [Status::Succeeded, Status::Failed, Status::Canceled] {
+            finished_tasks |= self.queue.tasks.get_status(wtxn, status)?;
+        }
+
+        let before = OffsetDateTime::now_utc() - age;
+        utils::keep_ids_within_datetimes(
+            wtxn,
+            &mut finished_tasks,
+            self.queue.tasks.finished_at,
+            None,
+            Some(before),
+        )?;
+
+        self.delete_matched_tasks(wtxn, &finished_tasks, &Progress::default())
+    }
+
     /// Cancel each given task from all the databases (if it is cancelable).
     ///
     /// Returns the list of tasks that matched the filter and must be written in the database.
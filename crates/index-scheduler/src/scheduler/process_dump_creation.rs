@@ -58,7 +58,7 @@ impl IndexScheduler {
 
             let (_, mut t) = ret?;
             let status = t.status;
-            let content_file = t.content_uuid();
+            let content_files = t.content_uuids();
 
             // In the case we're dumping ourselves we want to be marked as finished
             // to not loop over ourselves indefinitely.
@@ -74,8 +74,10 @@ impl IndexScheduler {
             }
             let mut dump_content_file = dump_tasks.push_task(&t.into())?;
 
-            // 2.1. Dump the `content_file` associated with the task if there is one and the task is not finished yet.
-            if let Some(content_file) = content_file {
+            // 2.1. Dump the `content_file`s associated with the task if there are some and the task is not finished yet.
+            //      The content files of a multi-index addition are concatenated in the same dump content file.
+            let has_content_files = !content_files.is_empty();
+            for content_file in content_files {
                 if self.scheduler.must_stop_processing.get() {
                     return Err(Error::AbortedTask);
                 }
@@ -95,9 +97,11 @@ impl IndexScheduler {
                                 .map_err(|e| Error::from_milli(e, None))?,
                         )?;
                     }
-                    dump_content_file.flush()?;
                 }
             }
+            if has_content_files && status == Status::Enqueued {
+                dump_content_file.flush()?;
+            }
             atomic.fetch_add(1, Ordering::Relaxed);
         }
         dump_tasks.flush()?;
@@ -216,6 +220,10 @@ impl IndexScheduler {
         let features = self.features().runtime_features();
         dump.create_experimental_features(features)?;
 
+        // 5. Dump the aliases
+        let aliases = self.index_mapper.aliases(&rtxn)?.into_iter().collect();
+        dump.create_aliases(&aliases)?;
+
         let dump_uid = started_at.format(format_description!(
                     "[year repr:full][month repr:numerical][day padding:zero]-[hour padding:zero][minute padding:zero][second padding:zero][subsecond digits:3]"
                 )).unwrap();
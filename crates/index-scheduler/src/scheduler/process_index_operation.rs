@@ -7,7 +7,9 @@ use meilisearch_types::milli::update::new::indexer::{self, UpdateByFunction};
 use meilisearch_types::milli::update::DocumentAdditionResult;
 use meilisearch_types::milli::{self, Filter, ThreadPoolNoAbortBuilder};
 use meilisearch_types::settings::apply_settings_to_builder;
-use meilisearch_types::tasks::{Details, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{
+    Details, KindWithContent, SkippedDocument, Status, Task, MAX_REPORTED_SKIPPED_DOCUMENTS,
+};
 use meilisearch_types::Index;
 use roaring::RoaringBitmap;
 
@@ -75,8 +77,8 @@ impl IndexScheduler {
                 // to a fresh thread.
                 let mut content_files = Vec::new();
                 for operation in &operations {
-                    if let DocumentOperation::Add(content_uuid) = operation {
-                        let content_file = self.queue.file_store.get_update(*content_uuid)?;
+                    if let DocumentOperation::Add { content_file, .. } = operation {
+                        let content_file = self.queue.file_store.get_update(*content_file)?;
                         let mmap = unsafe { memmap2::Mmap::map(&content_file)? };
                         content_files.push(mmap);
                     }
@@ -94,11 +96,14 @@ impl IndexScheduler {
                 let embedders = self.embedders(index_uid.clone(), embedders)?;
                 for operation in operations {
                     match operation {
-                        DocumentOperation::Add(_content_uuid) => {
+                        DocumentOperation::Add { skip_malformed_documents, .. } => {
                             let mmap = content_files_iter.next().unwrap();
-                            indexer
-                                .add_documents(mmap)
-                                .map_err(|e| Error::from_milli(e, Some(index_uid.clone())))?;
+                            let result = if skip_malformed_documents {
+                                indexer.add_documents_skipping_malformed(mmap)
+                            } else {
+                                indexer.add_documents(mmap)
+                            };
+                            result.map_err(|e| Error::from_milli(e, Some(index_uid.clone())))?;
                         }
                         DocumentOperation::Delete(document_ids) => {
                             let document_ids: bumpalo::collections::vec::Vec<_> = document_ids
@@ -149,9 +154,21 @@ impl IndexScheduler {
 
                     task.details = match task.details {
                         Some(Details::DocumentAdditionOrUpdate { received_documents, .. }) => {
+                            let skipped_documents_count = stats.skipped_documents.len() as u64;
+                            let skipped_documents = stats
+                                .skipped_documents
+                                .into_iter()
+                                .take(MAX_REPORTED_SKIPPED_DOCUMENTS)
+                                .map(|skipped| SkippedDocument {
+                                    index: skipped.index as u64,
+                                    reason: skipped.error.to_string(),
+                                })
+                                .collect();
                             Some(Details::DocumentAdditionOrUpdate {
                                 received_documents,
                                 indexed_documents: Some(stats.document_count),
+                                skipped_documents,
+                                skipped_documents_count,
                             })
                         }
                         Some(Details::DocumentDeletion { provided_ids, .. }) => {
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use meilisearch_types::milli::progress::Progress;
+use meilisearch_types::tasks::{Details, IndexedDocumentsCount, KindWithContent, Status, Task};
+
+use super::create_batch::{DocumentOperation, IndexOperation};
+use crate::processing::{MultiIndexDocumentAdditionProgress, VariableNameStep};
+use crate::{Error, IndexScheduler, Result};
+
+impl IndexScheduler {
+    /// Add documents to several indexes as a single unit.
+    ///
+    /// Every index is updated in its own write transaction and none of them is committed
+    /// before the documents of all the indexes have been indexed. If the documents of any
+    /// index are rejected, every transaction is aborted and the indexes created by the task
+    /// are deleted, leaving all the indexes as they were before the task.
+    ///
+    /// The transactions are committed one after the other, thus an I/O error while committing
+    /// one of the last indexes cannot roll back the indexes that were already committed.
+    ///
+    /// ## Return
+    /// The processed task.
+    pub(crate) fn process_multi_index_document_addition(
+        &self,
+        mut task: Task,
+        progress: Progress,
+    ) -> Result<Vec<Task>> {
+        let mut created_indexes = Vec::new();
+        let result =
+            self.apply_multi_index_document_addition(&mut task, &mut created_indexes, &progress);
+
+        if result.is_err() || task.status == Status::Failed {
+            progress
+                .update_progress(MultiIndexDocumentAdditionProgress::RollingBackTheCreatedIndexes);
+            for index_uid in created_indexes {
+                let wtxn = self.env.write_txn()?;
+                if let Err(e) = self.index_mapper.delete_index(wtxn, &index_uid) {
+                    tracing::error!(
+                        index_uid,
+                        error = &e as &dyn std::error::Error,
+                        "Could not delete an index created by a failed multi-index document addition"
+                    );
+                }
+            }
+        }
+
+        result.map(|()| vec![task])
+    }
+
+    fn apply_multi_index_document_addition(
+        &self,
+        task: &mut Task,
+        created_indexes: &mut Vec<String>,
+        progress: &Progress,
+    ) -> Result<()> {
+        let (additions, method, allow_index_creation) = match &task.kind {
+            KindWithContent::MultiIndexDocumentAddition {
+                additions,
+                method,
+                allow_index_creation,
+            } => (additions.clone(), *method, *allow_index_creation),
+            _ => unreachable!(),
+        };
+
+        // 1. Retrieve the indexes, creating the missing ones if allowed.
+        progress.update_progress(MultiIndexDocumentAdditionProgress::RetrievingTheIndexes);
+        let index_uids: Vec<String> = task.indexes().into_iter().map(String::from).collect();
+        let mut indexes = Vec::with_capacity(index_uids.len());
+        for index_uid in index_uids {
+            let rtxn = self.env.read_txn()?;
+            let index = self.index_mapper.index(&rtxn, &index_uid);
+            // drop rtxn before starting a new wtxn on the same db
+            rtxn.commit()?;
+
+            let index = match index {
+                Ok(index) => index,
+                Err(Error::IndexNotFound(_)) if allow_index_creation => {
+                    let wtxn = self.env.write_txn()?;
+                    let index = self.index_mapper.create_index(wtxn, &index_uid, None)?;
+                    created_indexes.push(index_uid.clone());
+                    index
+                }
+                Err(e) => return Err(e),
+            };
+            indexes.push((index_uid, index));
+        }
+
+        // 2. Index the documents of every index without committing anything.
+        progress.update_progress(MultiIndexDocumentAdditionProgress::IndexingTheDocuments);
+        let mut index_wtxns = Vec::with_capacity(indexes.len());
+        let mut counts = BTreeMap::new();
+        for (step, (index_uid, index)) in indexes.iter().enumerate() {
+            progress.update_progress(VariableNameStep::new(
+                index_uid,
+                step as u32,
+                indexes.len() as u32,
+            ));
+
+            // We reuse the document operation pipeline by handing it
+            // one document addition task per addition made to this index.
+            let index_additions: Vec<_> =
+                additions.iter().filter(|addition| &addition.index_uid == index_uid).collect();
+            let primary_key = index_additions.iter().find_map(|a| a.primary_key.clone());
+            let operations = index_additions
+                .iter()
+                .map(|a| DocumentOperation::Add {
+                    content_file: a.content_file,
+                    skip_malformed_documents: false,
+                })
+                .collect();
+            let tasks = index_additions
+                .iter()
+                .map(|addition| Task {
+                    kind: KindWithContent::DocumentAdditionOrUpdate {
+                        index_uid: index_uid.clone(),
+                        primary_key: addition.primary_key.clone(),
+                        method,
+                        content_file: addition.content_file,
+                        documents_count: addition.documents_count,
+                        allow_index_creation,
+                        skip_malformed_documents: false,
+                    },
+                    details: Some(Details::DocumentAdditionOrUpdate {
+                        received_documents: addition.documents_count,
+                        indexed_documents: None,
+                        skipped_documents: Vec::new(),
+                        skipped_documents_count: 0,
+                    }),
+                    ..task.clone()
+                })
+                .collect();
+            let operation = IndexOperation::DocumentOperation {
+                index_uid: index_uid.clone(),
+                primary_key,
+                method,
+                operations,
+                tasks,
+            };
+
+            let mut index_wtxn = index.write_txn()?;
+            let tasks =
+                self.apply_index_operation(&mut index_wtxn, index, operation, progress.clone())?;
+
+            let mut received = 0;
+            let mut indexed = 0;
+            for processed in tasks {
+                if let Some(error) = processed.error {
+                    // The documents of this index were rejected, dropping
+                    // the write transactions aborts the whole addition.
+                    task.status = Status::Failed;
+                    task.error = Some(error);
+                    task.details = task.details.as_ref().map(Details::to_failed);
+                    return Ok(());
+                }
+                if let Some(Details::DocumentAdditionOrUpdate {
+                    received_documents,
+                    indexed_documents,
+                    ..
+                }) = processed.details
+                {
+                    received += received_documents;
+                    indexed += indexed_documents.unwrap_or(0);
+                }
+            }
+            let count = IndexedDocumentsCount {
+                received_documents: received,
+                indexed_documents: Some(indexed),
+            };
+            counts.insert(index_uid.clone(), count);
+            index_wtxns.push(index_wtxn);
+        }
+
+        // 3. Every index succeeded, we can commit them all.
+        progress.update_progress(MultiIndexDocumentAdditionProgress::CommittingTheIndexes);
+        for index_wtxn in index_wtxns {
+            let span = tracing::trace_span!(target: "indexing::scheduler", "commit");
+            let _entered = span.enter();
+
+            index_wtxn.commit()?;
+        }
+
+        // if the update processed successfully, we're going to store the new
+        // stats of the indexes. Since the task has already been processed and
+        // this is a non-critical operation. If it fails, we should not fail
+        // the entire batch.
+        for (index_uid, index) in &indexes {
+            let res = || -> Result<()> {
+                let index_rtxn = index.read_txn()?;
+                let stats = crate::index_mapper::IndexStats::new(index, &index_rtxn)
+                    .map_err(|e| Error::from_milli(e, Some(index_uid.to_string())))?;
+                let mut wtxn = self.env.write_txn()?;
+                self.index_mapper.store_stats_of(&mut wtxn, index_uid, &stats)?;
+                wtxn.commit()?;
+                Ok(())
+            }();
+
+            if let Err(e) = res {
+                tracing::error!(
+                    error = &e as &dyn std::error::Error,
+                    "Could not write the stats of the index"
+                );
+            }
+        }
+
+        task.status = Status::Succeeded;
+        task.details = Some(Details::MultiIndexDocumentAddition { indexes: counts });
+
+        Ok(())
+    }
+}
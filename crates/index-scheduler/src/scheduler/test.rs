@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use big_s::S;
 use meili_snap::{json_string, snapshot};
@@ -6,7 +7,7 @@ use meilisearch_types::milli::index::IndexEmbeddingConfig;
 use meilisearch_types::milli::update::IndexDocumentsMethod::*;
 use meilisearch_types::milli::{self};
 use meilisearch_types::settings::SettingEmbeddingSettings;
-use meilisearch_types::tasks::{IndexSwap, KindWithContent};
+use meilisearch_types::tasks::{Details, IndexSwap, KindWithContent, Status};
 use roaring::RoaringBitmap;
 
 use crate::insta_snapshot::snapshot_index_scheduler;
@@ -293,6 +294,7 @@ fn document_addition_and_index_deletion() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -481,6 +483,7 @@ fn document_addition_and_index_deletion_on_unexisting_index() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -731,10 +734,12 @@ fn basic_get_stats() {
             "documentDeletion": 0,
             "documentEdition": 0,
             "dumpCreation": 0,
+            "indexCompaction": 0,
             "indexCreation": 3,
             "indexDeletion": 0,
             "indexSwap": 0,
             "indexUpdate": 0,
+            "multiIndexDocumentAddition": 0,
             "settingsUpdate": 0,
             "snapshotCreation": 0,
             "taskCancelation": 0,
@@ -763,10 +768,12 @@ fn basic_get_stats() {
             "documentDeletion": 0,
             "documentEdition": 0,
             "dumpCreation": 0,
+            "indexCompaction": 0,
             "indexCreation": 3,
             "indexDeletion": 0,
             "indexSwap": 0,
             "indexUpdate": 0,
+            "multiIndexDocumentAddition": 0,
             "settingsUpdate": 0,
             "snapshotCreation": 0,
             "taskCancelation": 0,
@@ -802,10 +809,12 @@ fn basic_get_stats() {
             "documentDeletion": 0,
             "documentEdition": 0,
             "dumpCreation": 0,
+            "indexCompaction": 0,
             "indexCreation": 3,
             "indexDeletion": 0,
             "indexSwap": 0,
             "indexUpdate": 0,
+            "multiIndexDocumentAddition": 0,
             "settingsUpdate": 0,
             "snapshotCreation": 0,
             "taskCancelation": 0,
@@ -842,10 +851,12 @@ fn basic_get_stats() {
             "documentDeletion": 0,
             "documentEdition": 0,
             "dumpCreation": 0,
+            "indexCompaction": 0,
             "indexCreation": 3,
             "indexDeletion": 0,
             "indexSwap": 0,
             "indexUpdate": 0,
+            "multiIndexDocumentAddition": 0,
             "settingsUpdate": 0,
             "snapshotCreation": 0,
             "taskCancelation": 0,
@@ -876,3 +887,232 @@ fn cancel_processing_dump() {
     handle.advance_one_successful_batch();
     snapshot!(snapshot_index_scheduler(&index_scheduler), name: "cancel_processed");
 }
+
+#[test]
+fn index_compaction_after_deletions() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let documents: Vec<_> = (0..2000)
+        .map(|id| serde_json::json!({ "id": id, "doggo": format!("bork {id} woof {}", id * 7) }))
+        .collect();
+    let content = serde_json::to_string(&documents).unwrap();
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(
+            KindWithContent::DocumentAdditionOrUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("id")),
+                method: ReplaceDocuments,
+                content_file: uuid,
+                documents_count,
+                allow_index_creation: true,
+                skip_malformed_documents: false,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    index_scheduler
+        .register(
+            KindWithContent::DocumentDeletion {
+                index_uid: S("doggos"),
+                documents_ids: (10..2000).map(|id| id.to_string()).collect(),
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    let search_bork = |index: &milli::Index| {
+        let rtxn = index.read_txn().unwrap();
+        let mut search = milli::Search::new(&rtxn, index);
+        search.query("bork");
+        search.execute().unwrap().documents_ids
+    };
+
+    let index = index_scheduler.index("doggos").unwrap();
+    let size_before = index.on_disk_size().unwrap();
+    let documents_before = search_bork(&index);
+    drop(index);
+
+    index_scheduler
+        .register(KindWithContent::IndexCompaction { index_uid: S("doggos") }, None, false)
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    let index = index_scheduler.index("doggos").unwrap();
+    let size_after = index.on_disk_size().unwrap();
+    assert!(size_after < size_before, "{size_after} is not smaller than {size_before}");
+    assert_eq!(search_bork(&index), documents_before);
+    assert_eq!(documents_before.len(), 10);
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 2).unwrap().unwrap();
+    snapshot!(format!("{:?}", task.kind), @r###"IndexCompaction { index_uid: "doggos" }"###);
+    assert_eq!(
+        task.details,
+        Some(Details::IndexCompaction { reclaimed_bytes: Some(size_before - size_after) })
+    );
+}
+
+#[test]
+fn prioritize_task() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    for name in ["doggos", "cattos", "girafos"] {
+        index_scheduler.register(index_creation_task(name, "id"), None, false).unwrap();
+    }
+    index_scheduler.prioritize_task(2).unwrap();
+
+    // the prioritized task is processed before the tasks enqueued before it.
+    handle.advance_one_successful_batch();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let statuses: Vec<_> = (0..3)
+        .map(|id| index_scheduler.queue.tasks.get_task(&rtxn, id).unwrap().unwrap().status)
+        .collect();
+    snapshot!(format!("{statuses:?}"), @"[Enqueued, Enqueued, Succeeded]");
+    drop(rtxn);
+
+    let err = index_scheduler.prioritize_task(2).unwrap_err();
+    snapshot!(err, @"Task `2` cannot be prioritized because it is not enqueued.");
+    let err = index_scheduler.prioritize_task(42).unwrap_err();
+    snapshot!(err, @"Task `42` not found.");
+
+    // without any prioritized task left, the tasks are processed in order.
+    handle.advance_till([Start, BatchCreated]);
+    index_scheduler
+        .register(KindWithContent::DocumentClear { index_uid: S("doggos") }, None, false)
+        .unwrap();
+    let err = index_scheduler.prioritize_task(0).unwrap_err();
+    snapshot!(err, @"Task `0` cannot be prioritized because it is not enqueued.");
+    let err = index_scheduler.prioritize_task(3).unwrap_err();
+    snapshot!(err, @"Task `3` cannot be prioritized because index `doggos` is being processed.");
+    index_scheduler.prioritize_task(1).unwrap();
+
+    while handle.advance() != ProcessBatchSucceeded {}
+    handle.advance_till([AfterProcessing]);
+
+    // the task on `cattos` goes before the document clear enqueued on `doggos`.
+    handle.advance_one_successful_batch();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let statuses: Vec<_> = (0..4)
+        .map(|id| index_scheduler.queue.tasks.get_task(&rtxn, id).unwrap().unwrap().status)
+        .collect();
+    snapshot!(format!("{statuses:?}"), @"[Succeeded, Succeeded, Succeeded, Enqueued]");
+    drop(rtxn);
+
+    handle.advance_one_successful_batch();
+    index_scheduler.assert_internally_consistent();
+}
+
+#[test]
+fn task_priority() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    for (name, priority) in
+        [("doggos", 0), ("cattos", 1), ("girafos", 1), ("bearos", -1), ("pandas", 0)]
+    {
+        let kind = index_creation_task(name, "id");
+        index_scheduler.register_with_priority(kind, None, priority, false).unwrap();
+    }
+
+    // the highest priority goes first and tasks sharing a priority keep their enqueue order.
+    let mut order = Vec::new();
+    for batch_uid in 0..5 {
+        handle.advance_one_successful_batch();
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        let batch = index_scheduler.queue.batches.get_batch(&rtxn, batch_uid).unwrap().unwrap();
+        let task_id = index_scheduler.queue.batch_to_tasks_mapping.get(&rtxn, &batch_uid);
+        order.push((task_id.unwrap().unwrap().min().unwrap(), batch.stats.priorities));
+    }
+    snapshot!(format!("{order:?}"), @"[(1, {1: 1}), (2, {1: 1}), (0, {}), (4, {}), (3, {-1: 1})]");
+
+    index_scheduler.assert_internally_consistent();
+}
+
+#[test]
+fn delete_finished_tasks_older_than() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    index_scheduler.register(index_creation_task("doggos", "id"), None, false).unwrap();
+    index_scheduler.register(index_creation_task("doggos", "id"), None, false).unwrap();
+    handle.advance_one_successful_batch();
+    handle.advance_one_failed_batch();
+    index_scheduler.register(index_creation_task("cattos", "id"), None, false).unwrap();
+
+    // the tasks finished too recently are kept.
+    let mut wtxn = index_scheduler.env.write_txn().unwrap();
+    let deleted = index_scheduler
+        .delete_finished_tasks_older_than(&mut wtxn, Duration::from_secs(3600))
+        .unwrap();
+    wtxn.commit().unwrap();
+    snapshot!(format!("{deleted:?}"), @"RoaringBitmap<[]>");
+
+    // the enqueued task is never deleted.
+    let mut wtxn = index_scheduler.env.write_txn().unwrap();
+    let deleted =
+        index_scheduler.delete_finished_tasks_older_than(&mut wtxn, Duration::ZERO).unwrap();
+    wtxn.commit().unwrap();
+    snapshot!(format!("{deleted:?}"), @"RoaringBitmap<[0, 1]>");
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let remaining = index_scheduler.queue.tasks.all_task_ids(&rtxn).unwrap();
+    snapshot!(format!("{remaining:?}"), @"RoaringBitmap<[2]>");
+    drop(rtxn);
+
+    index_scheduler.assert_internally_consistent();
+}
+
+#[test]
+fn requeue_tasks() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let (file0, documents_count0) = sample_documents(&index_scheduler, 0, 0);
+    file0.persist().unwrap();
+    let to_enqueue = [
+        replace_document_import_task("doggos", Some("id"), 0, documents_count0),
+        KindWithContent::TaskCancelation {
+            query: "test_query".to_owned(),
+            tasks: RoaringBitmap::from_iter([0]),
+        },
+        index_creation_task("cattos", "id"),
+        index_creation_task("cattos", "id"),
+    ];
+    for task in to_enqueue {
+        index_scheduler.register(task, None, false).unwrap();
+    }
+    handle.advance_one_successful_batch();
+    handle.advance_one_successful_batch();
+    handle.advance_one_failed_batch();
+
+    let mut wtxn = index_scheduler.env.write_txn().unwrap();
+    let err = index_scheduler.requeue_tasks(&mut wtxn, RoaringBitmap::from_iter([1])).unwrap_err();
+    snapshot!(err, @"Task `1` cannot be requeued because it has neither failed nor been canceled.");
+    let err = index_scheduler.requeue_tasks(&mut wtxn, RoaringBitmap::from_iter([0])).unwrap_err();
+    snapshot!(err, @"Task `0` cannot be requeued because its content file has been deleted.");
+    let err = index_scheduler.requeue_tasks(&mut wtxn, RoaringBitmap::from_iter([42])).unwrap_err();
+    snapshot!(err, @"Task `42` not found.");
+    // Nothing is requeued when one of the tasks can't be.
+    let err =
+        index_scheduler.requeue_tasks(&mut wtxn, RoaringBitmap::from_iter([1, 3])).unwrap_err();
+    snapshot!(err, @"Task `1` cannot be requeued because it has neither failed nor been canceled.");
+
+    let tasks = index_scheduler.requeue_tasks(&mut wtxn, RoaringBitmap::from_iter([3])).unwrap();
+    wtxn.commit().unwrap();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let original = index_scheduler.queue.tasks.get_task(&rtxn, 3).unwrap().unwrap();
+    let requeued = &tasks[0];
+    assert_eq!(requeued.uid, 4);
+    assert_eq!(requeued.status, Status::Enqueued);
+    assert_eq!(requeued.kind, original.kind);
+    assert!(requeued.error.is_none() && requeued.batch_uid.is_none());
+    assert!(requeued.enqueued_at > original.enqueued_at);
+    drop(rtxn);
+
+    index_scheduler.assert_internally_consistent();
+}
@@ -1,8 +1,14 @@
 use big_s::S;
+use maplit::btreeset;
 use meili_snap::snapshot;
 use meilisearch_types::milli::obkv_to_json;
 use meilisearch_types::milli::update::IndexDocumentsMethod::*;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::milli::update::Setting;
+use meilisearch_types::settings::{DocumentTtlSettings, Settings, Unchecked};
+use meilisearch_types::tasks::{
+    Details, IndexDocumentAddition, KindWithContent, Status, MAX_REPORTED_SKIPPED_DOCUMENTS,
+};
+use time::OffsetDateTime;
 
 use crate::insta_snapshot::snapshot_index_scheduler;
 use crate::test_utils::read_json;
@@ -31,6 +37,7 @@ fn document_addition() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -67,6 +74,7 @@ fn document_addition_and_document_deletion() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -133,6 +141,7 @@ fn document_deletion_and_document_addition() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -185,6 +194,7 @@ fn test_document_replace() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -236,6 +246,7 @@ fn test_document_update() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -289,6 +300,7 @@ fn test_mixed_document_addition() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -343,6 +355,7 @@ fn test_document_replace_without_autobatching() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -398,6 +411,7 @@ fn test_document_update_without_autobatching() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -457,6 +471,7 @@ fn test_document_addition_cant_create_index_without_index() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -509,6 +524,7 @@ fn test_document_addition_cant_create_index_without_index_without_autobatching()
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -571,6 +587,7 @@ fn test_document_addition_cant_create_index_with_index() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -638,6 +655,7 @@ fn test_document_addition_cant_create_index_with_index_without_autobatching() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -710,6 +728,7 @@ fn test_document_addition_mixed_rights_with_index() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -767,6 +786,7 @@ fn test_document_addition_mixed_right_without_index_starts_with_cant_create() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -823,6 +843,7 @@ fn test_document_addition_with_multiple_primary_key() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -886,6 +907,7 @@ fn test_document_addition_with_multiple_primary_key_batch_wrong_key() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -946,6 +968,7 @@ fn test_document_addition_with_bad_primary_key() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -1032,6 +1055,7 @@ fn test_document_addition_with_set_and_null_primary_key() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -1118,6 +1142,7 @@ fn test_document_addition_with_set_and_null_primary_key_inference_works() {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    skip_malformed_documents: false,
                 },
                 None,
                 false,
@@ -1167,3 +1192,329 @@ fn test_document_addition_with_set_and_null_primary_key_inference_works() {
         .collect::<Vec<_>>();
     snapshot!(serde_json::to_string_pretty(&documents).unwrap(), name: "documents");
 }
+
+#[test]
+fn multi_index_document_addition() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let mut additions = Vec::new();
+    for (id, (index_uid, content)) in [
+        ("doggos", r#"[{ "id": 1, "doggo": "jean bob" }, { "id": 2, "doggo": "bork" }]"#),
+        ("cattos", r#"{ "id": 1, "catto": "jorts" }"#),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (uuid, mut file) =
+            index_scheduler.queue.create_update_file_with_uuid(id as u128).unwrap();
+        let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+        file.persist().unwrap();
+        additions.push(IndexDocumentAddition {
+            index_uid: S(index_uid),
+            primary_key: Some(S("id")),
+            content_file: uuid,
+            documents_count,
+        });
+    }
+
+    index_scheduler
+        .register(
+            KindWithContent::MultiIndexDocumentAddition {
+                additions,
+                method: ReplaceDocuments,
+                allow_index_creation: true,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 0).unwrap().unwrap();
+    snapshot!(task.status, @"succeeded");
+    snapshot!(format!("{:?}", task.details.unwrap()), @r###"MultiIndexDocumentAddition { indexes: {"cattos": IndexedDocumentsCount { received_documents: 1, indexed_documents: Some(1) }, "doggos": IndexedDocumentsCount { received_documents: 2, indexed_documents: Some(2) }} }"###);
+
+    let doggos = index_scheduler.index("doggos").unwrap();
+    snapshot!(doggos.number_of_documents(&doggos.read_txn().unwrap()).unwrap(), @"2");
+    let cattos = index_scheduler.index("cattos").unwrap();
+    snapshot!(cattos.number_of_documents(&cattos.read_txn().unwrap()).unwrap(), @"1");
+}
+
+#[test]
+fn multi_index_document_addition_failure_rolls_back_every_index() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let content = r#"{ "id": 1, "doggo": "jean bob" }"#;
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(
+            KindWithContent::DocumentAdditionOrUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("id")),
+                method: ReplaceDocuments,
+                content_file: uuid,
+                documents_count,
+                allow_index_creation: true,
+                skip_malformed_documents: false,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    // The documents of the first index are valid but the second index
+    // receives an invalid document id, nothing must be committed.
+    let mut additions = Vec::new();
+    for (id, (index_uid, content)) in [
+        ("doggos", r#"[{ "id": 2, "doggo": "bork" }, { "id": 3, "doggo": "fifi" }]"#),
+        ("cattos", r#"{ "id": "jorts & jean", "catto": "jorts" }"#),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (uuid, mut file) =
+            index_scheduler.queue.create_update_file_with_uuid(id as u128 + 1).unwrap();
+        let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+        file.persist().unwrap();
+        additions.push(IndexDocumentAddition {
+            index_uid: S(index_uid),
+            primary_key: Some(S("id")),
+            content_file: uuid,
+            documents_count,
+        });
+    }
+
+    index_scheduler
+        .register(
+            KindWithContent::MultiIndexDocumentAddition {
+                additions,
+                method: ReplaceDocuments,
+                allow_index_creation: true,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    // NOTE: it's marked as successful because the batch didn't fails, it's the task that failed.
+    handle.advance_one_successful_batch();
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 1).unwrap().unwrap();
+    assert_eq!(task.status, Status::Failed);
+    let error = serde_json::to_value(task.error.unwrap()).unwrap();
+    snapshot!(error["code"], @r###""invalid_document_id""###);
+    match task.details {
+        Some(Details::MultiIndexDocumentAddition { indexes }) => {
+            assert!(indexes.values().all(|count| count.indexed_documents == Some(0)))
+        }
+        details => panic!("unexpected details {details:?}"),
+    }
+    drop(rtxn);
+
+    // The first index still only contains its original document...
+    let doggos = index_scheduler.index("doggos").unwrap();
+    snapshot!(doggos.number_of_documents(&doggos.read_txn().unwrap()).unwrap(), @"1");
+    // ...and the index created by the task has been removed.
+    snapshot!(index_scheduler.index_exists("cattos").unwrap(), @"false");
+}
+
+#[test]
+fn document_addition_skipping_malformed_documents() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let content = r#"[
+        { "id": 1, "doggo": "jean bob" },
+        { "id": "jorts & jean", "doggo": "jorts" },
+        { "doggo": "fifi" },
+        { "id": 4, "doggo": "kefir" }
+    ]"#;
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(
+            KindWithContent::DocumentAdditionOrUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("id")),
+                method: ReplaceDocuments,
+                content_file: uuid,
+                documents_count,
+                allow_index_creation: true,
+                skip_malformed_documents: true,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    // The malformed documents are reported and the others are indexed.
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 0).unwrap().unwrap();
+    assert_eq!(task.status, Status::Succeeded);
+    snapshot!(serde_json::to_string_pretty(&task.details).unwrap(), @r###"
+    {
+      "DocumentAdditionOrUpdate": {
+        "received_documents": 4,
+        "indexed_documents": 2,
+        "skipped_documents": [
+          {
+            "index": 1,
+            "reason": "Document identifier `\"jorts & jean\"` is invalid. A document identifier can be of type integer or string, only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and underscores (_), and can not be more than 511 bytes."
+          },
+          {
+            "index": 2,
+            "reason": "Document doesn't have a `id` attribute: `{\"doggo\":\"fifi\"}`."
+          }
+        ],
+        "skipped_documents_count": 2
+      }
+    }
+    "###);
+    drop(rtxn);
+
+    let index = index_scheduler.index("doggos").unwrap();
+    snapshot!(index.number_of_documents(&index.read_txn().unwrap()).unwrap(), @"2");
+}
+
+#[test]
+fn document_addition_reports_a_bounded_number_of_skipped_documents() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    // Only the first malformed documents are listed, the others are counted.
+    let malformed = MAX_REPORTED_SKIPPED_DOCUMENTS + 5;
+    let documents: Vec<_> = (0..malformed)
+        .map(|i| serde_json::json!({ "doggo": format!("doggo {i}") }))
+        .chain(std::iter::once(serde_json::json!({ "id": 1, "doggo": "kefir" })))
+        .collect();
+    let content = serde_json::to_string(&documents).unwrap();
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(
+            KindWithContent::DocumentAdditionOrUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("id")),
+                method: ReplaceDocuments,
+                content_file: uuid,
+                documents_count,
+                allow_index_creation: true,
+                skip_malformed_documents: true,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 0).unwrap().unwrap();
+    assert_eq!(task.status, Status::Succeeded);
+    match task.details {
+        Some(Details::DocumentAdditionOrUpdate {
+            indexed_documents,
+            skipped_documents,
+            skipped_documents_count,
+            ..
+        }) => {
+            assert_eq!(indexed_documents, Some(1));
+            assert_eq!(skipped_documents.len(), MAX_REPORTED_SKIPPED_DOCUMENTS);
+            assert_eq!(skipped_documents[0].index, 0);
+            assert_eq!(skipped_documents_count, malformed as u64);
+        }
+        details => panic!("unexpected details {details:?}"),
+    }
+}
+
+#[test]
+fn expired_documents_are_deleted() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let content = format!(
+        r#"[
+            {{ "id": 1, "doggo": "jean bob", "created_at": {} }},
+            {{ "id": 2, "doggo": "bork", "created_at": {} }},
+            {{ "id": 3, "doggo": "fifi" }}
+        ]"#,
+        now - 7200,
+        now
+    );
+
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(
+            KindWithContent::DocumentAdditionOrUpdate {
+                index_uid: S("doggos"),
+                primary_key: Some(S("id")),
+                method: ReplaceDocuments,
+                content_file: uuid,
+                documents_count,
+                allow_index_creation: true,
+                skip_malformed_documents: false,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    // Without a time-to-live, nothing is swept.
+    index_scheduler.enqueue_expired_documents_deletions().unwrap();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    snapshot!(index_scheduler.queue.tasks.all_task_ids(&rtxn).unwrap().len(), @"1");
+    drop(rtxn);
+
+    // The documents expire one hour after their creation.
+    let mut new_settings: Box<Settings<Unchecked>> = Box::default();
+    new_settings.filterable_attributes = Setting::Set(btreeset! { S("created_at") });
+    new_settings.document_ttl =
+        Setting::Set(DocumentTtlSettings { field: S("created_at"), ttl_seconds: 3600 });
+    index_scheduler
+        .register(
+            KindWithContent::SettingsUpdate {
+                index_uid: S("doggos"),
+                new_settings,
+                is_deletion: false,
+                allow_index_creation: true,
+            },
+            None,
+            false,
+        )
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    // The sweep is usually made by the run loop, we trigger it ourselves.
+    index_scheduler.enqueue_expired_documents_deletions().unwrap();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 2).unwrap().unwrap();
+    snapshot!(format!("{:?}", task.kind), @r###"DocumentDeletion { index_uid: "doggos", documents_ids: ["1"] }"###);
+    drop(rtxn);
+
+    // A second sweep doesn't enqueue the same deletion again.
+    index_scheduler.enqueue_expired_documents_deletions().unwrap();
+    let rtxn = index_scheduler.env.read_txn().unwrap();
+    snapshot!(index_scheduler.queue.tasks.all_task_ids(&rtxn).unwrap().len(), @"3");
+    drop(rtxn);
+
+    index_scheduler.scheduler.wake_up.signal();
+    handle.advance_one_successful_batch();
+
+    let index = index_scheduler.index("doggos").unwrap();
+    let rtxn = index.read_txn().unwrap();
+    let field_ids_map = index.fields_ids_map(&rtxn).unwrap();
+    let field_ids = field_ids_map.ids().collect::<Vec<_>>();
+    let ids = index
+        .all_documents(&rtxn)
+        .unwrap()
+        .map(|ret| obkv_to_json(&field_ids, &field_ids_map, ret.unwrap().1).unwrap()["id"].clone())
+        .collect::<Vec<_>>();
+    snapshot!(format!("{ids:?}"), @"[Number(2), Number(3)]");
+}
@@ -148,6 +148,7 @@ fn import_vectors() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -222,6 +223,7 @@ fn import_vectors() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -342,6 +344,7 @@ fn import_vectors_first_and_embedder_later() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -472,6 +475,7 @@ fn import_vectors_first_and_embedder_later() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -573,6 +577,7 @@ fn delete_document_containing_vector() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: false,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -747,6 +752,7 @@ fn delete_embedder_with_user_provided_vectors() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: false,
+                skip_malformed_documents: false,
             },
             None,
             false,
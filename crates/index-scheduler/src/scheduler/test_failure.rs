@@ -53,6 +53,7 @@ fn fail_in_process_batch_for_document_addition() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -95,6 +96,7 @@ fn fail_in_update_task_after_process_batch_success_for_document_addition() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -160,6 +162,7 @@ fn fail_in_process_batch_for_document_deletion() {
                 content_file: uuid,
                 documents_count,
                 allow_index_creation: true,
+                skip_malformed_documents: false,
             },
             None,
             false,
@@ -109,6 +109,7 @@ impl IndexScheduler {
             max_number_of_batched_tasks: usize::MAX,
             batched_tasks_size_limit: u64::MAX,
             instance_features: Default::default(),
+            document_ttl_sweep_interval: None,
         };
         configuration(&mut options);
 
@@ -179,6 +180,7 @@ pub(crate) fn replace_document_import_task(
         content_file: Uuid::from_u128(content_file_uuid),
         documents_count,
         allow_index_creation: true,
+        skip_malformed_documents: false,
     }
 }
 
@@ -74,6 +74,9 @@ impl ProcessingBatch {
             if let Some(index_uid) = task.index_uid() {
                 *self.stats.index_uids.entry(index_uid.to_string()).or_default() += 1;
             }
+            if task.priority != 0 {
+                *self.stats.priorities.entry(task.priority).or_default() += 1;
+            }
             if let Some(ref details) = task.details {
                 self.details.accumulate(&DetailsView::from(details.clone()));
             }
@@ -128,6 +131,23 @@ impl ProcessingBatch {
         if let Some(index_uid) = task.index_uid() {
             *self.stats.index_uids.entry(index_uid.to_string()).or_default() += 1;
         }
+        if task.priority != 0 {
+            *self.stats.priorities.entry(task.priority).or_default() += 1;
+        }
+    }
+
+    /// A coarse estimation of the completion of the batch, from 0 to 100, based on the
+    /// number of its tasks that reached a terminal status.
+    pub fn progress_percent(&self) -> Option<u8> {
+        let total = self.stats.total_nb_tasks;
+        if total == 0 {
+            return None;
+        }
+        let finished: u32 = [Status::Succeeded, Status::Failed, Status::Canceled]
+            .iter()
+            .filter_map(|status| self.stats.status.get(status))
+            .sum();
+        Some((finished.min(total) as u64 * 100 / total as u64) as u8)
     }
 
     pub fn to_batch(&self) -> Batch {
@@ -174,6 +194,9 @@ pub(crate) fn remove_task_datetime(
     Ok(())
 }
 
+/// Keep the ids that are strictly after `after` and strictly before `before`.
+///
+/// See [`keep_ids_within_datetime_bounds`] to choose whether each bound is inclusive.
 pub(crate) fn keep_ids_within_datetimes(
     rtxn: &RoTxn,
     ids: &mut RoaringBitmap,
@@ -181,15 +204,26 @@ pub(crate) fn keep_ids_within_datetimes(
     after: Option<OffsetDateTime>,
     before: Option<OffsetDateTime>,
 ) -> Result<()> {
-    let (start, end) = match (&after, &before) {
-        (None, None) => return Ok(()),
-        (None, Some(before)) => (Bound::Unbounded, Bound::Excluded(*before)),
-        (Some(after), None) => (Bound::Excluded(*after), Bound::Unbounded),
-        (Some(after), Some(before)) => (Bound::Excluded(*after), Bound::Excluded(*before)),
-    };
+    let after = after.map_or(Bound::Unbounded, Bound::Excluded);
+    let before = before.map_or(Bound::Unbounded, Bound::Excluded);
+    keep_ids_within_datetime_bounds(rtxn, ids, database, after, before)
+}
+
+/// Keep the ids that are within the `after` and `before` bounds, a bound is inclusive
+/// when it is [`Bound::Included`].
+pub(crate) fn keep_ids_within_datetime_bounds(
+    rtxn: &RoTxn,
+    ids: &mut RoaringBitmap,
+    database: Database<BEI128, CboRoaringBitmapCodec>,
+    after: Bound<OffsetDateTime>,
+    before: Bound<OffsetDateTime>,
+) -> Result<()> {
+    if matches!((&after, &before), (Bound::Unbounded, Bound::Unbounded)) {
+        return Ok(());
+    }
     let mut collected_ids = RoaringBitmap::new();
-    let start = map_bound(start, |b| b.unix_timestamp_nanos());
-    let end = map_bound(end, |b| b.unix_timestamp_nanos());
+    let start = map_bound(after, |b| b.unix_timestamp_nanos());
+    let end = map_bound(before, |b| b.unix_timestamp_nanos());
     let iter = database.range(rtxn, &(start, end))?;
     for r in iter {
         let (_timestamp, ids) = r?;
@@ -221,6 +255,7 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
         K::IndexDeletion { index_uid } => index_uids.push(index_uid),
         K::IndexCreation { index_uid, .. } => index_uids.push(index_uid),
         K::IndexUpdate { index_uid, .. } => index_uids.push(index_uid),
+        K::IndexCompaction { index_uid } => index_uids.push(index_uid),
         K::IndexSwap { swaps } => {
             for IndexSwap { indexes: (lhs, rhs) } in swaps.iter_mut() {
                 if lhs == swap.0 || lhs == swap.1 {
@@ -231,6 +266,11 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
                 }
             }
         }
+        K::MultiIndexDocumentAddition { additions, .. } => {
+            for addition in additions.iter_mut() {
+                index_uids.push(&mut addition.index_uid);
+            }
+        }
         K::TaskCancelation { .. }
         | K::TaskDeletion { .. }
         | K::DumpCreation { .. }
@@ -253,6 +293,16 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
             swap.0.clone_into(index_uid);
         }
     }
+    if let Some(Details::MultiIndexDocumentAddition { indexes }) = &mut task.details {
+        let lhs = indexes.remove(swap.0);
+        let rhs = indexes.remove(swap.1);
+        if let Some(count) = lhs {
+            indexes.insert(swap.1.to_string(), count);
+        }
+        if let Some(count) = rhs {
+            indexes.insert(swap.0.to_string(), count);
+        }
+    }
 }
 
 /// Remove references to task ids that are greater than the id of the given task.
@@ -326,6 +376,7 @@ impl crate::IndexScheduler {
                 details,
                 status,
                 kind,
+                priority,
             } = task;
             assert_eq!(uid, task.uid);
             if let Some(ref batch) = batch_uid {
@@ -355,6 +406,10 @@ impl crate::IndexScheduler {
                 .unwrap()
                 .unwrap();
             assert!(db_enqueued_at.contains(task_id));
+            if priority != 0 {
+                let db_priority = self.queue.tasks.priority.get(&rtxn, &priority).unwrap().unwrap();
+                assert!(db_priority.contains(task_id));
+            }
             if let Some(started_at) = started_at {
                 let db_started_at = self
                     .queue
@@ -379,14 +434,17 @@ impl crate::IndexScheduler {
                 let db_canceled_tasks =
                     self.queue.tasks.get_status(&rtxn, Status::Canceled).unwrap();
                 assert!(db_canceled_tasks.contains(uid));
-                let db_canceling_task =
-                    self.queue.tasks.get_task(&rtxn, canceled_by).unwrap().unwrap();
-                assert_eq!(db_canceling_task.status, Status::Succeeded);
-                match db_canceling_task.kind {
-                    KindWithContent::TaskCancelation { query: _, tasks } => {
-                        assert!(tasks.contains(uid));
+                // The canceling task may have been deleted since, as a finished task.
+                if let Some(db_canceling_task) =
+                    self.queue.tasks.get_task(&rtxn, canceled_by).unwrap()
+                {
+                    assert_eq!(db_canceling_task.status, Status::Succeeded);
+                    match db_canceling_task.kind {
+                        KindWithContent::TaskCancelation { query: _, tasks } => {
+                            assert!(tasks.contains(uid));
+                        }
+                        _ => panic!(),
                     }
-                    _ => panic!(),
                 }
             }
             if let Some(details) = details {
@@ -396,8 +454,15 @@ impl crate::IndexScheduler {
                             assert_eq!(&sw1, sw2);
                         }
                     }
-                    Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
+                    Details::DocumentAdditionOrUpdate {
+                        received_documents,
+                        indexed_documents,
+                        skipped_documents,
+                        skipped_documents_count,
+                    } => {
                         assert_eq!(kind.as_kind(), Kind::DocumentAdditionOrUpdate);
+                        assert!(skipped_documents.len() as u64 <= skipped_documents_count);
+                        assert!(skipped_documents_count <= received_documents);
                         match indexed_documents {
                             Some(indexed_documents) => {
                                 assert!(matches!(
@@ -547,6 +612,29 @@ impl crate::IndexScheduler {
                     Details::Dump { dump_uid: _ } => {
                         assert_eq!(kind.as_kind(), Kind::DumpCreation);
                     }
+                    Details::IndexCompaction { reclaimed_bytes } => {
+                        assert_eq!(kind.as_kind(), Kind::IndexCompaction);
+                        if reclaimed_bytes.is_some() {
+                            assert_ne!(status, Status::Enqueued);
+                        } else {
+                            assert_ne!(status, Status::Succeeded);
+                        }
+                    }
+                    Details::MultiIndexDocumentAddition { indexes } => {
+                        assert_eq!(kind.as_kind(), Kind::MultiIndexDocumentAddition);
+                        for count in indexes.values() {
+                            match count.indexed_documents {
+                                Some(indexed_documents) => match status {
+                                    Status::Succeeded => assert!(indexed_documents <= count.received_documents),
+                                    Status::Failed | Status::Canceled => assert_eq!(indexed_documents, 0),
+                                    status => panic!("MultiIndexDocumentAddition can't have an indexed_documents set if it's {}", status),
+                                },
+                                None => {
+                                    assert!(matches!(status, Status::Enqueued | Status::Processing))
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
@@ -576,6 +664,21 @@ impl crate::IndexScheduler {
                 }
             }
         }
+
+        // The deleted tasks must not be referenced by the reverse indexes anymore.
+        let all_tasks = self.queue.tasks.all_task_ids(&rtxn).unwrap();
+        for status in self.queue.tasks.status.iter(&rtxn).unwrap() {
+            let (_status, task_ids) = status.unwrap();
+            assert!(task_ids.is_subset(&all_tasks));
+        }
+        for kind in self.queue.tasks.kind.iter(&rtxn).unwrap() {
+            let (_kind, task_ids) = kind.unwrap();
+            assert!(task_ids.is_subset(&all_tasks));
+        }
+        for finished_at in self.queue.tasks.finished_at.iter(&rtxn).unwrap() {
+            let (_timestamp, task_ids) = finished_at.unwrap();
+            assert!(task_ids.is_subset(&all_tasks));
+        }
     }
 }
 
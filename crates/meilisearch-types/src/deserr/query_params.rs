@@ -26,7 +26,8 @@ use crate::tasks::{Kind, Status};
 ///
 /// Note that if the field is optional, it is better to use
 /// `Option<Param<T>>` instead of `Param<Option<T>>`.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, serde::Deserialize)]
+#[serde(transparent)]
 pub struct Param<T>(pub T);
 
 impl<T> Deref for Param<T> {
@@ -36,10 +36,32 @@ impl fmt::Display for PayloadType {
     }
 }
 
+/// The location, in a payload, of a document that could not be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentLocation {
+    /// The line of the document in an NDJSON payload, starting at one.
+    Line(usize),
+    /// The index of the document in the array of a JSON payload,
+    /// and the offset of its first byte in the payload.
+    ArrayElement { index: u64, byte_offset: usize },
+}
+
+impl fmt::Display for DocumentLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentLocation::Line(line) => write!(f, "at line {line}"),
+            DocumentLocation::ArrayElement { index, byte_offset } => {
+                write!(f, "at index {index} (byte {byte_offset})")
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum DocumentFormatError {
     Io(io::Error),
     MalformedPayload(Error, PayloadType),
+    MalformedDocument(Error, PayloadType, DocumentLocation),
 }
 
 impl Display for DocumentFormatError {
@@ -78,6 +100,18 @@ impl Display for DocumentFormatError {
                 }
                 _ => write!(f, "The `{}` payload provided is malformed: `{}`.", b, me),
             },
+            Self::MalformedDocument(me, b, location) => match me {
+                Error::Json(se) if se.classify() == Category::Data => write!(
+                    f,
+                    "The `{}` payload provided is malformed: the document {} is not an object.",
+                    b, location
+                ),
+                _ => write!(
+                    f,
+                    "The `{}` payload provided is malformed: the document {} is invalid: `{}`.",
+                    b, location, me
+                ),
+            },
         }
     }
 }
@@ -124,6 +158,7 @@ impl ErrorCode for DocumentFormatError {
         match self {
             DocumentFormatError::Io(e) => e.error_code(),
             DocumentFormatError::MalformedPayload(_, _) => Code::MalformedPayload,
+            DocumentFormatError::MalformedDocument(_, _, _) => Code::MalformedPayload,
         }
     }
 }
@@ -219,16 +254,35 @@ pub fn read_json(input: &File, output: impl io::Write) -> Result<u64> {
 
     let mut out = BufWriter::new(output);
     let mut deserializer = serde_json::Deserializer::from_slice(&input);
+    let mut index = 0;
+    let mut malformed = None;
     let res = array_each(&mut deserializer, |obj: &RawValue| {
         doc_alloc.reset();
-        let map = RawMap::from_raw_value_and_hasher(obj, FxBuildHasher, &doc_alloc)?;
+        let map = match RawMap::from_raw_value_and_hasher(obj, FxBuildHasher, &doc_alloc) {
+            Ok(map) => map,
+            Err(e) => {
+                let byte_offset = byte_offset(&input, obj);
+                malformed = Some(DocumentLocation::ArrayElement { index, byte_offset });
+                return Err(e);
+            }
+        };
+        index += 1;
         to_writer(&mut out, &map)
     });
     let count = match res {
         // The json data has been deserialized and does not need to be processed again.
         // The data has been transferred to the writer during the deserialization process.
         Ok(Ok(count)) => count,
-        Ok(Err(e)) => return Err(DocumentFormatError::from((PayloadType::Json, e))),
+        Ok(Err(e)) => match malformed {
+            Some(location) => {
+                return Err(DocumentFormatError::MalformedDocument(
+                    Error::Json(e),
+                    PayloadType::Json,
+                    location,
+                ))
+            }
+            None => return Err(DocumentFormatError::from((PayloadType::Json, e))),
+        },
         Err(e) => {
             // Attempt to deserialize a single json string when the cause of the exception is not Category.data
             // Other types of deserialisation exceptions are returned directly to the front-end
@@ -264,8 +318,15 @@ pub fn read_ndjson(input: &File) -> Result<u64> {
         match result {
             Ok(raw) => {
                 // try to deserialize as a map
-                RawMap::from_raw_value_and_hasher(raw, FxBuildHasher, &bump)
-                    .map_err(|e| DocumentFormatError::from((PayloadType::Ndjson, e)))?;
+                RawMap::from_raw_value_and_hasher(raw, FxBuildHasher, &bump).map_err(|e| {
+                    let offset = byte_offset(&input, raw);
+                    let line = input[..offset].iter().filter(|&&b| b == b'\n').count() + 1;
+                    DocumentFormatError::MalformedDocument(
+                        Error::Json(e),
+                        PayloadType::Ndjson,
+                        DocumentLocation::Line(line),
+                    )
+                })?;
                 count += 1;
             }
             Err(e) => return Err(DocumentFormatError::from((PayloadType::Ndjson, e))),
@@ -275,6 +336,11 @@ pub fn read_ndjson(input: &File) -> Result<u64> {
     Ok(count)
 }
 
+/// Returns the offset of the raw value in the payload it was deserialized from.
+fn byte_offset(input: &[u8], raw: &RawValue) -> usize {
+    raw.get().as_ptr() as usize - input.as_ptr() as usize
+}
+
 /// The actual handling of the deserialization process in serde
 /// avoids storing the deserialized object in memory.
 ///
@@ -323,3 +389,48 @@ where
     let visitor = SeqVisitor(f, PhantomData);
     deserializer.deserialize_seq(visitor)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn payload(content: &str) -> File {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn ndjson_error_reports_the_line() {
+        let file = payload("{\"id\": 1}\n{\"id\": 2}\n42\n{\"id\": 4}");
+        let error = read_ndjson(&file).unwrap_err();
+        assert!(matches!(
+            error,
+            DocumentFormatError::MalformedDocument(_, _, DocumentLocation::Line(3))
+        ));
+        assert_eq!(
+            error.to_string(),
+            "The `ndjson` payload provided is malformed: the document at line 3 is not an object."
+        );
+    }
+
+    #[test]
+    fn json_error_reports_the_index_and_byte_offset() {
+        let file = payload("[{\"id\": 1}, {\"id\": 2}, \"three\"]");
+        let error = read_json(&file, io::sink()).unwrap_err();
+        assert!(matches!(
+            error,
+            DocumentFormatError::MalformedDocument(
+                _,
+                _,
+                DocumentLocation::ArrayElement { index: 2, byte_offset: 23 }
+            )
+        ));
+        assert_eq!(
+            error.to_string(),
+            "The `json` payload provided is malformed: the document at index 2 (byte 23) is not an object."
+        );
+    }
+}
@@ -194,183 +194,189 @@ macro_rules! make_error_codes {
 
 // An exhaustive list of all the error codes used by meilisearch.
 make_error_codes! {
-ApiKeyAlreadyExists                   , InvalidRequest       , CONFLICT ;
-ApiKeyNotFound                        , InvalidRequest       , NOT_FOUND ;
-BadParameter                          , InvalidRequest       , BAD_REQUEST;
-BadRequest                            , InvalidRequest       , BAD_REQUEST;
-DatabaseSizeLimitReached              , Internal             , INTERNAL_SERVER_ERROR;
-DocumentNotFound                      , InvalidRequest       , NOT_FOUND;
-DumpAlreadyProcessing                 , InvalidRequest       , CONFLICT;
-DumpNotFound                          , InvalidRequest       , NOT_FOUND;
-DumpProcessFailed                     , Internal             , INTERNAL_SERVER_ERROR;
-DuplicateIndexFound                   , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyActions                , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyCreatedAt              , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyExpiresAt              , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyIndexes                , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyKey                    , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyUid                    , InvalidRequest       , BAD_REQUEST;
-ImmutableApiKeyUpdatedAt              , InvalidRequest       , BAD_REQUEST;
-ImmutableIndexCreatedAt               , InvalidRequest       , BAD_REQUEST;
-ImmutableIndexUid                     , InvalidRequest       , BAD_REQUEST;
-ImmutableIndexUpdatedAt               , InvalidRequest       , BAD_REQUEST;
-IndexAlreadyExists                    , InvalidRequest       , CONFLICT ;
-IndexCreationFailed                   , Internal             , INTERNAL_SERVER_ERROR;
-IndexNotFound                         , InvalidRequest       , NOT_FOUND;
-IndexPrimaryKeyAlreadyExists          , InvalidRequest       , BAD_REQUEST ;
-IndexPrimaryKeyMultipleCandidatesFound, InvalidRequest       , BAD_REQUEST;
-IndexPrimaryKeyNoCandidateFound       , InvalidRequest       , BAD_REQUEST ;
-Internal                              , Internal             , INTERNAL_SERVER_ERROR ;
-InvalidApiKey                         , Auth                 , FORBIDDEN ;
-InvalidApiKeyActions                  , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyDescription              , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyIndexes                  , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyLimit                    , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyName                     , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyOffset                   , InvalidRequest       , BAD_REQUEST ;
-InvalidApiKeyUid                      , InvalidRequest       , BAD_REQUEST ;
-InvalidContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
-InvalidDocumentCsvDelimiter           , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentFields                 , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentRetrieveVectors        , InvalidRequest       , BAD_REQUEST ;
-MissingDocumentFilter                 , InvalidRequest       , BAD_REQUEST ;
-MissingDocumentEditionFunction        , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentFilter                 , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentGeoField               , InvalidRequest       , BAD_REQUEST ;
-InvalidVectorDimensions               , InvalidRequest       , BAD_REQUEST ;
-InvalidVectorsType                    , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentId                     , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentLimit                  , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentOffset                 , InvalidRequest       , BAD_REQUEST ;
-InvalidEmbedder                       , InvalidRequest       , BAD_REQUEST ;
-InvalidHybridQuery                    , InvalidRequest       , BAD_REQUEST ;
-InvalidIndexLimit                     , InvalidRequest       , BAD_REQUEST ;
-InvalidIndexOffset                    , InvalidRequest       , BAD_REQUEST ;
-InvalidIndexPrimaryKey                , InvalidRequest       , BAD_REQUEST ;
-InvalidIndexUid                       , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchFacets              , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchFacetsByIndex       , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchFacetOrder          , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchFederated           , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchFederationOptions   , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchMaxValuesPerFacet   , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchMergeFacets         , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchQueryFacets         , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchQueryPagination     , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchQueryRankingRules   , InvalidRequest       , BAD_REQUEST ;
-InvalidMultiSearchWeight              , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchAttributesToSearchOn     , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchAttributesToCrop         , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchAttributesToHighlight    , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarAttributesToRetrieve    , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarRetrieveVectors         , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchAttributesToRetrieve     , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchRankingScoreThreshold    , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarRankingScoreThreshold   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchRetrieveVectors          , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchCropLength               , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchCropMarker               , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchFacets                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchSemanticRatio            , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchLocales                  , InvalidRequest       , BAD_REQUEST ;
-InvalidFacetSearchFacetName           , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarId                      , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchFilter                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarFilter                  , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchHighlightPostTag         , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchHighlightPreTag          , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchHitsPerPage              , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarLimit                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchLimit                    , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchMatchingStrategy         , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarOffset                  , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchOffset                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchPage                     , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchQ                        , InvalidRequest       , BAD_REQUEST ;
-InvalidFacetSearchQuery               , InvalidRequest       , BAD_REQUEST ;
-InvalidFacetSearchName                , InvalidRequest       , BAD_REQUEST ;
-FacetSearchDisabled                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchVector                   , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchShowMatchesPosition      , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchShowRankingScore         , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarShowRankingScore        , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchShowRankingScoreDetails  , InvalidRequest       , BAD_REQUEST ;
-InvalidSimilarShowRankingScoreDetails , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchSort                     , InvalidRequest       , BAD_REQUEST ;
-InvalidSearchDistinct                 , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsDisplayedAttributes    , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsDistinctAttribute      , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsProximityPrecision     , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsFacetSearch            , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsPrefixSearch           , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsFaceting               , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsFilterableAttributes   , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsPagination             , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsSearchCutoffMs         , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsEmbedders              , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsRankingRules           , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsSearchableAttributes   , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsSortableAttributes     , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsStopWords              , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsNonSeparatorTokens     , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsSeparatorTokens        , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsDictionary             , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsSynonyms               , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsTypoTolerance          , InvalidRequest       , BAD_REQUEST ;
-InvalidSettingsLocalizedAttributes    , InvalidRequest       , BAD_REQUEST ;
-InvalidState                          , Internal             , INTERNAL_SERVER_ERROR ;
-InvalidStoreFile                      , Internal             , INTERNAL_SERVER_ERROR ;
-InvalidSwapDuplicateIndexFound        , InvalidRequest       , BAD_REQUEST ;
-InvalidSwapIndexes                    , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskAfterEnqueuedAt            , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskAfterFinishedAt            , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskAfterStartedAt             , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskBeforeEnqueuedAt           , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskBeforeFinishedAt           , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskBeforeStartedAt            , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskCanceledBy                 , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskFrom                       , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskLimit                      , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskReverse                    , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskStatuses                   , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskTypes                      , InvalidRequest       , BAD_REQUEST ;
-InvalidTaskUids                       , InvalidRequest       , BAD_REQUEST  ;
-InvalidBatchUids                      , InvalidRequest       , BAD_REQUEST  ;
-IoError                               , System               , UNPROCESSABLE_ENTITY;
-FeatureNotEnabled                     , InvalidRequest       , BAD_REQUEST ;
-MalformedPayload                      , InvalidRequest       , BAD_REQUEST ;
-MaxFieldsLimitExceeded                , InvalidRequest       , BAD_REQUEST ;
-MissingApiKeyActions                  , InvalidRequest       , BAD_REQUEST ;
-MissingApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST ;
-MissingApiKeyIndexes                  , InvalidRequest       , BAD_REQUEST ;
-MissingAuthorizationHeader            , Auth                 , UNAUTHORIZED ;
-MissingContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
-MissingDocumentId                     , InvalidRequest       , BAD_REQUEST ;
-MissingFacetSearchFacetName           , InvalidRequest       , BAD_REQUEST ;
-MissingIndexUid                       , InvalidRequest       , BAD_REQUEST ;
-MissingMasterKey                      , Auth                 , UNAUTHORIZED ;
-MissingPayload                        , InvalidRequest       , BAD_REQUEST ;
-MissingSearchHybrid                   , InvalidRequest       , BAD_REQUEST ;
-MissingSwapIndexes                    , InvalidRequest       , BAD_REQUEST ;
-MissingTaskFilters                    , InvalidRequest       , BAD_REQUEST ;
-NoSpaceLeftOnDevice                   , System               , UNPROCESSABLE_ENTITY;
-PayloadTooLarge                       , InvalidRequest       , PAYLOAD_TOO_LARGE ;
-TooManySearchRequests                 , System               , SERVICE_UNAVAILABLE ;
-TaskNotFound                          , InvalidRequest       , NOT_FOUND ;
-BatchNotFound                         , InvalidRequest       , NOT_FOUND ;
-TooManyOpenFiles                      , System               , UNPROCESSABLE_ENTITY ;
-TooManyVectors                        , InvalidRequest       , BAD_REQUEST ;
-UnretrievableDocument                 , Internal             , BAD_REQUEST ;
-UnretrievableErrorCode                , InvalidRequest       , BAD_REQUEST ;
-UnsupportedMediaType                  , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
+AliasNotFound                           , InvalidRequest       , NOT_FOUND ;
+ApiKeyAlreadyExists                     , InvalidRequest       , CONFLICT ;
+ApiKeyNotFound                          , InvalidRequest       , NOT_FOUND ;
+BadParameter                            , InvalidRequest       , BAD_REQUEST;
+BadRequest                              , InvalidRequest       , BAD_REQUEST;
+DatabaseSizeLimitReached                , Internal             , INTERNAL_SERVER_ERROR;
+DocumentNotFound                        , InvalidRequest       , NOT_FOUND;
+DumpAlreadyProcessing                   , InvalidRequest       , CONFLICT;
+DumpNotFound                            , InvalidRequest       , NOT_FOUND;
+DumpProcessFailed                       , Internal             , INTERNAL_SERVER_ERROR;
+DuplicateIndexFound                     , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyActions                  , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyCreatedAt                , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyIndexes                  , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyKey                      , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyUid                      , InvalidRequest       , BAD_REQUEST;
+ImmutableApiKeyUpdatedAt                , InvalidRequest       , BAD_REQUEST;
+ImmutableIndexCreatedAt                 , InvalidRequest       , BAD_REQUEST;
+ImmutableIndexUid                       , InvalidRequest       , BAD_REQUEST;
+ImmutableIndexUpdatedAt                 , InvalidRequest       , BAD_REQUEST;
+IndexAliasConflict                      , InvalidRequest       , CONFLICT ;
+IndexAlreadyExists                      , InvalidRequest       , CONFLICT ;
+IndexCreationFailed                     , Internal             , INTERNAL_SERVER_ERROR;
+IndexNotFound                           , InvalidRequest       , NOT_FOUND;
+IndexPrimaryKeyAlreadyExists            , InvalidRequest       , BAD_REQUEST ;
+IndexPrimaryKeyMultipleCandidatesFound  , InvalidRequest       , BAD_REQUEST;
+IndexPrimaryKeyNoCandidateFound         , InvalidRequest       , BAD_REQUEST ;
+Internal                                , Internal             , INTERNAL_SERVER_ERROR ;
+InvalidApiKey                           , Auth                 , FORBIDDEN ;
+InvalidApiKeyActions                    , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyDescription                , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyExpiresAt                  , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyIndexes                    , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyLimit                      , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyName                       , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyOffset                     , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyUid                        , InvalidRequest       , BAD_REQUEST ;
+InvalidContentType                      , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
+InvalidDocumentCsvDelimiter             , InvalidRequest       , BAD_REQUEST ;
+// Also used for `excludeFields`, which is applied after the `fields` selection.
+InvalidDocumentFields                   , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentRetrieveVectors          , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentSkipMalformedDocuments   , InvalidRequest       , BAD_REQUEST ;
+MissingDocumentFilter                   , InvalidRequest       , BAD_REQUEST ;
+MissingDocumentEditionFunction          , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentFilter                   , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentGeoField                 , InvalidRequest       , BAD_REQUEST ;
+InvalidVectorDimensions                 , InvalidRequest       , BAD_REQUEST ;
+InvalidVectorsType                      , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentId                       , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentLimit                    , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentOffset                   , InvalidRequest       , BAD_REQUEST ;
+InvalidEmbedder                         , InvalidRequest       , BAD_REQUEST ;
+InvalidHybridQuery                      , InvalidRequest       , BAD_REQUEST ;
+InvalidIndexLimit                       , InvalidRequest       , BAD_REQUEST ;
+InvalidIndexOffset                      , InvalidRequest       , BAD_REQUEST ;
+InvalidIndexPrimaryKey                  , InvalidRequest       , BAD_REQUEST ;
+InvalidIndexUid                         , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchFacets                , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchFacetsByIndex         , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchFacetOrder            , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchFederated             , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchFederationOptions     , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchMaxValuesPerFacet     , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchMergeFacets           , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchQueryFacets           , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchQueryPagination       , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchQueryRankingRules     , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchWeight                , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributesToSearchOn       , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributesToCrop           , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributesToHighlight      , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarAttributesToRetrieve      , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarRetrieveVectors           , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributesToRetrieve       , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchRankingScoreThreshold      , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarRankingScoreThreshold     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchRetrieveVectors            , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchCropLength                 , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchCropMarker                 , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchFacets                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchSemanticRatio              , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchLocales                    , InvalidRequest       , BAD_REQUEST ;
+InvalidFacetSearchFacetName             , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarId                        , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchFilter                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarFilter                    , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchHighlightPostTag           , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchHighlightPreTag            , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchHitsPerPage                , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarLimit                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchLimit                      , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchMatchingStrategy           , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarOffset                    , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchOffset                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchPage                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchQ                          , InvalidRequest       , BAD_REQUEST ;
+InvalidFacetSearchQuery                 , InvalidRequest       , BAD_REQUEST ;
+InvalidFacetSearchName                  , InvalidRequest       , BAD_REQUEST ;
+FacetSearchDisabled                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchVector                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowMatchesPosition        , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowRankingScore           , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarShowRankingScore          , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowRankingScoreDetails    , InvalidRequest       , BAD_REQUEST ;
+InvalidSimilarShowRankingScoreDetails   , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchSort                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchDistinct                   , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDisplayedAttributes      , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDistinctAttribute        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsProximityPrecision       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsFacetSearch              , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsPrefixSearch             , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsPrefixDisabledAttributes , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDocumentTtl              , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsFaceting                 , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsFilterableAttributes     , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsPagination               , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSearchCutoffMs           , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsEmbedders                , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsRankingRules             , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSearchableAttributes     , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSortableAttributes       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsStopWords                , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsNonSeparatorTokens       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSeparatorTokens          , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDictionary               , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSynonyms                 , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsTypoTolerance            , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsLocalizedAttributes      , InvalidRequest       , BAD_REQUEST ;
+InvalidState                            , Internal             , INTERNAL_SERVER_ERROR ;
+InvalidStoreFile                        , Internal             , INTERNAL_SERVER_ERROR ;
+InvalidSwapDuplicateIndexFound          , InvalidRequest       , BAD_REQUEST ;
+InvalidSwapIndexes                      , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskAfterEnqueuedAt              , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskAfterFinishedAt              , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskAfterStartedAt               , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskBeforeEnqueuedAt             , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskBeforeFinishedAt             , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskBeforeStartedAt              , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskCanceledBy                   , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskFrom                         , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskLimit                        , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskReverse                      , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskStatuses                     , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskTypes                        , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskUids                         , InvalidRequest       , BAD_REQUEST  ;
+InvalidBatchUids                        , InvalidRequest       , BAD_REQUEST  ;
+IoError                                 , System               , UNPROCESSABLE_ENTITY;
+FeatureNotEnabled                       , InvalidRequest       , BAD_REQUEST ;
+MalformedPayload                        , InvalidRequest       , BAD_REQUEST ;
+MaxFieldsLimitExceeded                  , InvalidRequest       , BAD_REQUEST ;
+MissingApiKeyActions                    , InvalidRequest       , BAD_REQUEST ;
+MissingApiKeyExpiresAt                  , InvalidRequest       , BAD_REQUEST ;
+MissingApiKeyIndexes                    , InvalidRequest       , BAD_REQUEST ;
+MissingAuthorizationHeader              , Auth                 , UNAUTHORIZED ;
+MissingContentType                      , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
+MissingDocumentId                       , InvalidRequest       , BAD_REQUEST ;
+MissingFacetSearchFacetName             , InvalidRequest       , BAD_REQUEST ;
+MissingIndexUid                         , InvalidRequest       , BAD_REQUEST ;
+MissingMasterKey                        , Auth                 , UNAUTHORIZED ;
+MissingPayload                          , InvalidRequest       , BAD_REQUEST ;
+MissingSearchHybrid                     , InvalidRequest       , BAD_REQUEST ;
+MissingSwapIndexes                      , InvalidRequest       , BAD_REQUEST ;
+MissingTaskFilters                      , InvalidRequest       , BAD_REQUEST ;
+NoSpaceLeftOnDevice                     , System               , UNPROCESSABLE_ENTITY;
+PayloadTooLarge                         , InvalidRequest       , PAYLOAD_TOO_LARGE ;
+TooManySearchRequests                   , System               , SERVICE_UNAVAILABLE ;
+TaskNotFound                            , InvalidRequest       , NOT_FOUND ;
+BatchNotFound                           , InvalidRequest       , NOT_FOUND ;
+TooManyOpenFiles                        , System               , UNPROCESSABLE_ENTITY ;
+TooManyVectors                          , InvalidRequest       , BAD_REQUEST ;
+UnretrievableDocument                   , Internal             , BAD_REQUEST ;
+UnretrievableErrorCode                  , InvalidRequest       , BAD_REQUEST ;
+UnsupportedMediaType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
 
 // Experimental features
-VectorEmbeddingError                  , InvalidRequest       , BAD_REQUEST ;
-NotFoundSimilarId                     , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentEditionContext         , InvalidRequest       , BAD_REQUEST ;
-InvalidDocumentEditionFunctionFilter  , InvalidRequest       , BAD_REQUEST ;
-EditDocumentsByFunctionError          , InvalidRequest       , BAD_REQUEST
+VectorEmbeddingError                    , InvalidRequest       , BAD_REQUEST ;
+NotFoundSimilarId                       , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentEditionContext           , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentEditionFunctionFilter    , InvalidRequest       , BAD_REQUEST ;
+EditDocumentsByFunctionError            , InvalidRequest       , BAD_REQUEST
 }
 
 impl ErrorCode for JoinError {
@@ -425,7 +431,8 @@ impl ErrorCode for milli::Error {
                     UserError::PrimaryKeyCannotBeChanged(_) => Code::IndexPrimaryKeyAlreadyExists,
                     UserError::InvalidDistinctAttribute { .. } => Code::InvalidSearchDistinct,
                     UserError::SortRankingRuleMissing => Code::InvalidSearchSort,
-                    UserError::InvalidFacetsDistribution { .. } => Code::InvalidSearchFacets,
+                    UserError::InvalidFacetsDistribution { .. }
+                    | UserError::InvalidHistogramBuckets(_) => Code::InvalidSearchFacets,
                     UserError::InvalidSortableAttribute { .. } => Code::InvalidSearchSort,
                     UserError::InvalidSearchableAttribute { .. } => {
                         Code::InvalidSearchAttributesToSearchOn
@@ -443,6 +450,7 @@ impl ErrorCode for milli::Error {
                     UserError::InvalidMinTypoWordLenSetting(_, _) => {
                         Code::InvalidSettingsTypoTolerance
                     }
+                    UserError::InvalidDocumentTtlField { .. } => Code::InvalidSettingsDocumentTtl,
                     UserError::InvalidEmbedder(_) => Code::InvalidEmbedder,
                     UserError::VectorEmbeddingError(_) | UserError::DocumentEmbeddingError(_) => {
                         Code::VectorEmbeddingError
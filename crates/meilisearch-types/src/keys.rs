@@ -8,7 +8,7 @@ use milli::update::Setting;
 use serde::{Deserialize, Serialize};
 use time::format_description::well_known::Rfc3339;
 use time::macros::{format_description, time};
-use time::{Date, OffsetDateTime, PrimitiveDateTime};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -158,13 +158,50 @@ impl Key {
             updated_at: now,
         }
     }
+
+    /// Builds a search-only key restricted to the given index patterns.
+    ///
+    /// Returns `None` if `indexes` is empty, as such a key would be useless,
+    /// or if one of the patterns is invalid, e.g. built with `new_unchecked`.
+    pub fn search_for_indexes(indexes: Vec<IndexUidPattern>) -> Option<Self> {
+        if indexes.is_empty()
+            || indexes.iter().any(|pattern| pattern.parse::<IndexUidPattern>().is_err())
+        {
+            return None;
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let uid = Uuid::new_v4();
+        let patterns =
+            indexes.iter().map(|pattern| format!("`{}`", &**pattern)).collect::<Vec<_>>();
+        Some(Self {
+            name: Some("Search API Key".to_string()),
+            description: Some(format!(
+                "Use it to search the {} indexes from the frontend",
+                patterns.join(", ")
+            )),
+            uid,
+            actions: vec![Action::Search],
+            indexes,
+            expires_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
 }
 
 fn parse_expiration_date(
     string: Option<String>,
 ) -> std::result::Result<Option<OffsetDateTime>, ParseOffsetDateTimeError> {
     let Some(string) = string else { return Ok(None) };
-    let datetime = if let Ok(datetime) = OffsetDateTime::parse(&string, &Rfc3339) {
+    let datetime = if let Some(relative) = string.strip_prefix('+') {
+        match parse_relative_duration(relative)
+            .and_then(|duration| OffsetDateTime::now_utc().checked_add(duration))
+        {
+            Some(datetime) => datetime,
+            None => return Err(ParseOffsetDateTimeError(string)),
+        }
+    } else if let Ok(datetime) = OffsetDateTime::parse(&string, &Rfc3339) {
         datetime
     } else if let Ok(primitive_datetime) = PrimitiveDateTime::parse(
         &string,
@@ -195,6 +232,22 @@ fn parse_expiration_date(
     }
 }
 
+/// Parses a duration made of a number followed by a unit, e.g. `30d` or `12h`.
+fn parse_relative_duration(string: &str) -> Option<Duration> {
+    let unit_start = string.find(|c: char| !c.is_ascii_digit())?;
+    let (number, unit) = string.split_at(unit_start);
+    let number: i64 = number.parse().ok()?;
+    let seconds_per_unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+    number.checked_mul(seconds_per_unit).map(Duration::seconds)
+}
+
 #[derive(
     Copy, Clone, Serialize, Deserialize, Debug, Eq, PartialEq, Hash, Sequence, Deserr, ToSchema,
 )]
@@ -302,6 +355,9 @@ pub enum Action {
     #[serde(rename = "experimental.update")]
     #[deserr(rename = "experimental.update")]
     ExperimentalFeaturesUpdate,
+    #[serde(rename = "search.facets")]
+    #[deserr(rename = "search.facets")]
+    FacetSearch,
 }
 
 impl Action {
@@ -341,6 +397,7 @@ impl Action {
             KEYS_DELETE => Some(Self::KeysDelete),
             EXPERIMENTAL_FEATURES_GET => Some(Self::ExperimentalFeaturesGet),
             EXPERIMENTAL_FEATURES_UPDATE => Some(Self::ExperimentalFeaturesUpdate),
+            FACET_SEARCH => Some(Self::FacetSearch),
             _otherwise => None,
         }
     }
@@ -348,6 +405,30 @@ impl Action {
     pub const fn repr(&self) -> u8 {
         *self as u8
     }
+
+    /// Whether a key granted this action is allowed to perform the `other` action,
+    /// the `*` actions include all the actions of their family.
+    pub fn includes(&self, other: Action) -> bool {
+        use Action::*;
+        match self {
+            All => true,
+            Search => matches!(other, Search | FacetSearch),
+            DocumentsAll => {
+                matches!(other, DocumentsAll | DocumentsAdd | DocumentsGet | DocumentsDelete)
+            }
+            IndexesAll => matches!(
+                other,
+                IndexesAll | IndexesAdd | IndexesGet | IndexesUpdate | IndexesDelete | IndexesSwap
+            ),
+            TasksAll => matches!(other, TasksAll | TasksCancel | TasksDelete | TasksGet),
+            SettingsAll => matches!(other, SettingsAll | SettingsGet | SettingsUpdate),
+            StatsAll => matches!(other, StatsAll | StatsGet),
+            MetricsAll => matches!(other, MetricsAll | MetricsGet),
+            DumpsAll => matches!(other, DumpsAll | DumpsCreate),
+            SnapshotsAll => matches!(other, SnapshotsAll | SnapshotsCreate),
+            action => *action == other,
+        }
+    }
 }
 
 pub mod actions {
@@ -386,4 +467,70 @@ pub mod actions {
     pub const KEYS_DELETE: u8 = KeysDelete.repr();
     pub const EXPERIMENTAL_FEATURES_GET: u8 = ExperimentalFeaturesGet.repr();
     pub const EXPERIMENTAL_FEATURES_UPDATE: u8 = ExperimentalFeaturesUpdate.repr();
+    pub const FACET_SEARCH: u8 = FacetSearch.repr();
+}
+
+#[cfg(test)]
+mod tests {
+    use enum_iterator::all;
+
+    use super::*;
+
+    fn included_by(granted: Action) -> Vec<Action> {
+        all::<Action>().filter(|action| granted.includes(*action)).collect()
+    }
+
+    #[test]
+    fn action_includes() {
+        use Action::*;
+
+        // The wildcard includes every action but is only included by itself.
+        assert_eq!(included_by(All), all::<Action>().collect::<Vec<_>>());
+        assert!(all::<Action>().all(|action| action.includes(action)));
+        assert!(all::<Action>().filter(|action| action.includes(All)).eq([All]));
+
+        assert_eq!(included_by(Search), [Search, FacetSearch]);
+        assert_eq!(
+            included_by(DocumentsAll),
+            [DocumentsAll, DocumentsAdd, DocumentsGet, DocumentsDelete]
+        );
+        assert_eq!(
+            included_by(IndexesAll),
+            [IndexesAll, IndexesAdd, IndexesGet, IndexesUpdate, IndexesDelete, IndexesSwap]
+        );
+        assert_eq!(included_by(TasksAll), [TasksAll, TasksCancel, TasksDelete, TasksGet]);
+        assert_eq!(included_by(SettingsAll), [SettingsAll, SettingsGet, SettingsUpdate]);
+        assert_eq!(included_by(StatsAll), [StatsAll, StatsGet]);
+        assert_eq!(included_by(MetricsAll), [MetricsAll, MetricsGet]);
+        assert_eq!(included_by(DumpsAll), [DumpsAll, DumpsCreate]);
+        assert_eq!(included_by(SnapshotsAll), [SnapshotsAll, SnapshotsCreate]);
+
+        // The actions of a family don't include the wildcard of their family nor each other.
+        assert_eq!(included_by(DocumentsAdd), [DocumentsAdd]);
+        assert_eq!(included_by(TasksGet), [TasksGet]);
+        assert_eq!(included_by(KeysGet), [KeysGet]);
+    }
+
+    #[test]
+    fn search_for_indexes() {
+        assert_eq!(Key::search_for_indexes(Vec::new()), None);
+        let invalid = vec![IndexUidPattern::all(), IndexUidPattern::new_unchecked("bad uid!")];
+        assert_eq!(Key::search_for_indexes(invalid), None);
+
+        let indexes = vec!["movies".parse().unwrap(), "books_*".parse().unwrap()];
+        let key = Key::search_for_indexes(indexes.clone()).unwrap();
+        assert_eq!(key.name.as_deref(), Some("Search API Key"));
+        assert_eq!(
+            key.description.as_deref(),
+            Some("Use it to search the `movies`, `books_*` indexes from the frontend")
+        );
+        assert_eq!(key.actions, [Action::Search]);
+        assert_eq!(key.indexes, indexes);
+        assert_eq!(key.expires_at, None);
+        assert_eq!(key.created_at, key.updated_at);
+
+        // Every key gets its own uid.
+        let other = Key::search_for_indexes(indexes).unwrap();
+        assert_ne!(key.uid, other.uid);
+    }
 }
@@ -8,7 +8,7 @@ use std::str::FromStr;
 
 use deserr::{DeserializeError, Deserr, ErrorKind, MergeWithError, ValuePointerRef};
 use fst::IntoStreamer;
-use milli::index::{IndexEmbeddingConfig, PrefixSearch};
+use milli::index::{DocumentTtl, IndexEmbeddingConfig, PrefixSearch};
 use milli::proximity::ProximityPrecision;
 use milli::update::Setting;
 use milli::{Criterion, CriterionError, Index, DEFAULT_VALUES_PER_FACET};
@@ -288,6 +288,16 @@ pub struct Settings<T> {
     #[deserr(default, error = DeserrJsonError<InvalidSettingsPrefixSearch>)]
     #[schema(value_type = Option<PrefixSearchSettings>, example = json!("Hemlo"))]
     pub prefix_search: Setting<PrefixSearchSettings>,
+    /// Attributes in which the last word of the query only matches whole words.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsPrefixDisabledAttributes>)]
+    #[schema(value_type = Option<Vec<String>>, example = json!(["sku"]))]
+    pub prefix_disabled_attributes: Setting<BTreeSet<String>>,
+    /// Time-to-live after which the documents are deleted.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDocumentTtl>)]
+    #[schema(value_type = Option<DocumentTtlSettings>, example = json!({ "field": "createdAt", "ttlSeconds": 86400 }))]
+    pub document_ttl: Setting<DocumentTtlSettings>,
 
     #[serde(skip)]
     #[deserr(skip)]
@@ -354,6 +364,8 @@ impl Settings<Checked> {
             localized_attributes: Setting::Reset,
             facet_search: Setting::Reset,
             prefix_search: Setting::Reset,
+            prefix_disabled_attributes: Setting::Reset,
+            document_ttl: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -380,6 +392,8 @@ impl Settings<Checked> {
             localized_attributes: localized_attributes_rules,
             facet_search,
             prefix_search,
+            prefix_disabled_attributes,
+            document_ttl,
             _kind,
         } = self;
 
@@ -404,6 +418,8 @@ impl Settings<Checked> {
             localized_attributes: localized_attributes_rules,
             facet_search,
             prefix_search,
+            prefix_disabled_attributes,
+            document_ttl,
             _kind: PhantomData,
         }
     }
@@ -454,6 +470,8 @@ impl Settings<Unchecked> {
             localized_attributes: self.localized_attributes,
             facet_search: self.facet_search,
             prefix_search: self.prefix_search,
+            prefix_disabled_attributes: self.prefix_disabled_attributes,
+            document_ttl: self.document_ttl,
             _kind: PhantomData,
         }
     }
@@ -529,6 +547,11 @@ impl Settings<Unchecked> {
                 }
             },
             prefix_search: other.prefix_search.or(self.prefix_search),
+            prefix_disabled_attributes: other
+                .prefix_disabled_attributes
+                .clone()
+                .or(self.prefix_disabled_attributes.clone()),
+            document_ttl: other.document_ttl.clone().or(self.document_ttl.clone()),
             facet_search: other.facet_search.or(self.facet_search),
             _kind: PhantomData,
         }
@@ -568,6 +591,8 @@ pub fn apply_settings_to_builder(
         localized_attributes: localized_attributes_rules,
         facet_search,
         prefix_search,
+        prefix_disabled_attributes,
+        document_ttl,
         _kind,
     } = settings;
 
@@ -767,6 +792,20 @@ pub fn apply_settings_to_builder(
         Setting::NotSet => (),
     }
 
+    match prefix_disabled_attributes {
+        Setting::Set(attrs) => {
+            builder.set_prefix_disabled_attributes(attrs.iter().cloned().collect())
+        }
+        Setting::Reset => builder.reset_prefix_disabled_attributes(),
+        Setting::NotSet => (),
+    }
+
+    match document_ttl {
+        Setting::Set(ttl) => builder.set_document_ttl(ttl.clone().into()),
+        Setting::Reset => builder.reset_document_ttl(),
+        Setting::NotSet => (),
+    }
+
     match facet_search {
         Setting::Set(facet_search) => builder.set_facet_search(*facet_search),
         Setting::Reset => builder.reset_facet_search(),
@@ -874,6 +913,11 @@ pub fn settings(
 
     let prefix_search = index.prefix_search(rtxn)?.map(PrefixSearchSettings::from);
 
+    let prefix_disabled_attributes =
+        index.prefix_disabled_attributes(rtxn)?.into_iter().map(String::from).collect();
+
+    let document_ttl = index.document_ttl(rtxn)?.map(DocumentTtlSettings::from);
+
     let facet_search = index.facet_search(rtxn)?;
 
     let mut settings = Settings {
@@ -913,6 +957,11 @@ pub fn settings(
             None => Setting::Reset,
         },
         prefix_search: Setting::Set(prefix_search.unwrap_or_default()),
+        prefix_disabled_attributes: Setting::Set(prefix_disabled_attributes),
+        document_ttl: match document_ttl {
+            Some(ttl) => Setting::Set(ttl),
+            None => Setting::Reset,
+        },
         facet_search: Setting::Set(facet_search),
         _kind: PhantomData,
     };
@@ -1113,6 +1162,30 @@ impl From<PrefixSearchSettings> for PrefixSearch {
     }
 }
 
+/// The documents are deleted `ttlSeconds` after the unix timestamp, in seconds, of their `field`.
+///
+/// The field must be filterable or sortable.
+#[derive(Debug, Clone, PartialEq, Eq, Deserr, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDocumentTtl>, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentTtlSettings {
+    pub field: String,
+    pub ttl_seconds: u64,
+}
+
+impl From<DocumentTtl> for DocumentTtlSettings {
+    fn from(DocumentTtl { field, ttl_seconds }: DocumentTtl) -> Self {
+        DocumentTtlSettings { field, ttl_seconds }
+    }
+}
+
+impl From<DocumentTtlSettings> for DocumentTtl {
+    fn from(DocumentTtlSettings { field, ttl_seconds }: DocumentTtlSettings) -> Self {
+        DocumentTtl { field, ttl_seconds }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
@@ -1141,6 +1214,8 @@ pub(crate) mod test {
             search_cutoff_ms: Setting::NotSet,
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
+            prefix_disabled_attributes: Setting::NotSet,
+            document_ttl: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -1172,6 +1247,8 @@ pub(crate) mod test {
             search_cutoff_ms: Setting::NotSet,
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
+            prefix_disabled_attributes: Setting::NotSet,
+            document_ttl: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
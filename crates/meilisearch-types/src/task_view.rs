@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use milli::Object;
 use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
@@ -6,7 +8,10 @@ use utoipa::ToSchema;
 use crate::batches::BatchId;
 use crate::error::ResponseError;
 use crate::settings::{Settings, Unchecked};
-use crate::tasks::{serialize_duration, Details, IndexSwap, Kind, Status, Task, TaskId};
+use crate::tasks::{
+    serialize_duration, Details, IndexSwap, IndexedDocumentsCount, Kind, SkippedDocument, Status,
+    Task, TaskId, MAX_REPORTED_SKIPPED_DOCUMENTS,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -77,6 +82,12 @@ pub struct DetailsView {
     /// Number of documents finally indexed for documentAdditionOrUpdate task or a documentAdditionOrUpdate batch of tasks.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed_documents: Option<Option<u64>>,
+    /// Malformed documents skipped by a documentAdditionOrUpdate task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_documents: Option<Vec<SkippedDocument>>,
+    /// Number of malformed documents skipped by a documentAdditionOrUpdate task, only the first ones are listed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_documents_count: Option<u64>,
     /// Number of documents edited for editDocumentByFunction task.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_documents: Option<Option<u64>>,
@@ -114,6 +125,12 @@ pub struct DetailsView {
     pub settings: Option<Box<Settings<Unchecked>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub swaps: Option<Vec<IndexSwap>>,
+    /// Number of bytes released on disk by the indexCompaction task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reclaimed_bytes: Option<Option<u64>>,
+    /// Number of documents received and indexed per index for the multiIndexDocumentAddition task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<BTreeMap<String, IndexedDocumentsCount>>,
 }
 
 impl DetailsView {
@@ -226,6 +243,26 @@ impl DetailsView {
                     Some(left)
                 }
             },
+            skipped_documents: match (
+                self.skipped_documents.clone(),
+                other.skipped_documents.clone(),
+            ) {
+                (None, None) => None,
+                (None, Some(skipped)) | (Some(skipped), None) => Some(skipped),
+                (Some(mut left), Some(mut right)) => {
+                    left.append(&mut right);
+                    left.truncate(MAX_REPORTED_SKIPPED_DOCUMENTS);
+                    Some(left)
+                }
+            },
+            skipped_documents_count: match (
+                self.skipped_documents_count,
+                other.skipped_documents_count,
+            ) {
+                (None, None) => None,
+                (None, Some(count)) | (Some(count), None) => Some(count),
+                (Some(left), Some(right)) => Some(left + right),
+            },
             swaps: match (self.swaps.clone(), other.swaps.clone()) {
                 (None, None) => None,
                 (None, Some(swaps)) | (Some(swaps), None) => Some(swaps),
@@ -234,6 +271,37 @@ impl DetailsView {
                     Some(left)
                 }
             },
+            reclaimed_bytes: match (self.reclaimed_bytes, other.reclaimed_bytes) {
+                (None, None) => None,
+                (None, Some(None)) | (Some(None), None) | (Some(None), Some(None)) => Some(None),
+                (None | Some(None), Some(Some(bytes))) | (Some(Some(bytes)), None | Some(None)) => {
+                    Some(Some(bytes))
+                }
+                (Some(Some(left)), Some(Some(right))) => Some(Some(left + right)),
+            },
+            indexes: match (self.indexes.clone(), other.indexes.clone()) {
+                (None, None) => None,
+                (None, Some(indexes)) | (Some(indexes), None) => Some(indexes),
+                (Some(mut left), Some(right)) => {
+                    for (index_uid, count) in right {
+                        match left.get_mut(&index_uid) {
+                            Some(left) => {
+                                left.received_documents += count.received_documents;
+                                left.indexed_documents =
+                                    match (left.indexed_documents, count.indexed_documents) {
+                                        (None, None) => None,
+                                        (None, Some(doc)) | (Some(doc), None) => Some(doc),
+                                        (Some(left), Some(right)) => Some(left + right),
+                                    };
+                            }
+                            None => {
+                                left.insert(index_uid, count);
+                            }
+                        }
+                    }
+                    Some(left)
+                }
+            },
         }
     }
 }
@@ -241,13 +309,19 @@ impl DetailsView {
 impl From<Details> for DetailsView {
     fn from(details: Details) -> Self {
         match details {
-            Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
-                DetailsView {
-                    received_documents: Some(received_documents),
-                    indexed_documents: Some(indexed_documents),
-                    ..DetailsView::default()
-                }
-            }
+            Details::DocumentAdditionOrUpdate {
+                received_documents,
+                indexed_documents,
+                skipped_documents,
+                skipped_documents_count,
+            } => DetailsView {
+                received_documents: Some(received_documents),
+                indexed_documents: Some(indexed_documents),
+                skipped_documents: (!skipped_documents.is_empty()).then_some(skipped_documents),
+                skipped_documents_count: (skipped_documents_count != 0)
+                    .then_some(skipped_documents_count),
+                ..DetailsView::default()
+            },
             Details::DocumentEdition {
                 deleted_documents,
                 edited_documents,
@@ -311,6 +385,22 @@ impl From<Details> for DetailsView {
             Details::IndexSwap { swaps } => {
                 DetailsView { swaps: Some(swaps), ..Default::default() }
             }
+            Details::IndexCompaction { reclaimed_bytes } => {
+                DetailsView { reclaimed_bytes: Some(reclaimed_bytes), ..DetailsView::default() }
+            }
+            Details::MultiIndexDocumentAddition { indexes } => {
+                let received_documents = indexes.values().map(|c| c.received_documents).sum();
+                let indexed_documents = indexes
+                    .values()
+                    .map(|c| c.indexed_documents)
+                    .try_fold(0, |acc, count| count.map(|count| acc + count));
+                DetailsView {
+                    received_documents: Some(received_documents),
+                    indexed_documents: Some(indexed_documents),
+                    indexes: Some(indexes),
+                    ..DetailsView::default()
+                }
+            }
         }
     }
 }
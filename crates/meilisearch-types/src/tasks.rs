@@ -1,5 +1,5 @@
 use core::fmt;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Display, Write};
 use std::str::FromStr;
 
@@ -39,6 +39,18 @@ pub struct Task {
 
     pub status: Status,
     pub kind: KindWithContent,
+
+    /// Tasks with a higher priority are batched before the other enqueued tasks.
+    #[serde(default, skip_serializing_if = "is_default_priority")]
+    pub priority: i8,
+}
+
+fn is_default_priority(priority: &i8) -> bool {
+    *priority == 0
+}
+
+fn is_zero(count: &u64) -> bool {
+    *count == 0
 }
 
 impl Task {
@@ -50,7 +62,8 @@ impl Task {
             | SnapshotCreation
             | TaskCancelation { .. }
             | TaskDeletion { .. }
-            | IndexSwap { .. } => None,
+            | IndexSwap { .. }
+            | MultiIndexDocumentAddition { .. } => None,
             DocumentAdditionOrUpdate { index_uid, .. }
             | DocumentEdition { index_uid, .. }
             | DocumentDeletion { index_uid, .. }
@@ -59,6 +72,7 @@ impl Task {
             | SettingsUpdate { index_uid, .. }
             | IndexCreation { index_uid, .. }
             | IndexUpdate { index_uid, .. }
+            | IndexCompaction { index_uid }
             | IndexDeletion { index_uid } => Some(index_uid),
         }
     }
@@ -68,10 +82,13 @@ impl Task {
         self.kind.indexes()
     }
 
-    /// Return the content-uuid if there is one
-    pub fn content_uuid(&self) -> Option<Uuid> {
-        match self.kind {
-            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => Some(content_file),
+    /// Return the content-uuids associated with this task, if any
+    pub fn content_uuids(&self) -> Vec<Uuid> {
+        match &self.kind {
+            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => vec![*content_file],
+            KindWithContent::MultiIndexDocumentAddition { additions, .. } => {
+                additions.iter().map(|addition| addition.content_file).collect()
+            }
             KindWithContent::DocumentEdition { .. }
             | KindWithContent::DocumentDeletion { .. }
             | KindWithContent::DocumentDeletionByFilter { .. }
@@ -80,11 +97,12 @@ impl Task {
             | KindWithContent::IndexDeletion { .. }
             | KindWithContent::IndexCreation { .. }
             | KindWithContent::IndexUpdate { .. }
+            | KindWithContent::IndexCompaction { .. }
             | KindWithContent::IndexSwap { .. }
             | KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
-            | KindWithContent::SnapshotCreation => None,
+            | KindWithContent::SnapshotCreation => vec![],
         }
     }
 }
@@ -99,6 +117,9 @@ pub enum KindWithContent {
         content_file: Uuid,
         documents_count: u64,
         allow_index_creation: bool,
+        /// Skip the malformed documents instead of rejecting the whole task.
+        #[serde(default)]
+        skip_malformed_documents: bool,
     },
     DocumentDeletion {
         index_uid: String,
@@ -134,9 +155,17 @@ pub enum KindWithContent {
         index_uid: String,
         primary_key: Option<String>,
     },
+    IndexCompaction {
+        index_uid: String,
+    },
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
+    MultiIndexDocumentAddition {
+        additions: Vec<IndexDocumentAddition>,
+        method: IndexDocumentsMethod,
+        allow_index_creation: bool,
+    },
     TaskCancelation {
         query: String,
         tasks: RoaringBitmap,
@@ -158,6 +187,38 @@ pub struct IndexSwap {
     pub indexes: (String, String),
 }
 
+/// The documents to add to one of the indexes of a `MultiIndexDocumentAddition`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexDocumentAddition {
+    pub index_uid: String,
+    pub primary_key: Option<String>,
+    pub content_file: Uuid,
+    pub documents_count: u64,
+}
+
+/// The maximum number of skipped documents reported in the details of a task,
+/// the others are only counted.
+pub const MAX_REPORTED_SKIPPED_DOCUMENTS: usize = 20;
+
+/// A document that was skipped by a `DocumentAdditionOrUpdate` task because it was malformed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedDocument {
+    /// The position of the document in the payload, starting at zero.
+    pub index: u64,
+    /// Why the document could not be indexed.
+    pub reason: String,
+}
+
+/// The number of documents received and indexed in one of the indexes of a `MultiIndexDocumentAddition`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedDocumentsCount {
+    pub received_documents: u64,
+    pub indexed_documents: Option<u64>,
+}
+
 impl KindWithContent {
     pub fn as_kind(&self) -> Kind {
         match self {
@@ -170,7 +231,9 @@ impl KindWithContent {
             KindWithContent::IndexCreation { .. } => Kind::IndexCreation,
             KindWithContent::IndexDeletion { .. } => Kind::IndexDeletion,
             KindWithContent::IndexUpdate { .. } => Kind::IndexUpdate,
+            KindWithContent::IndexCompaction { .. } => Kind::IndexCompaction,
             KindWithContent::IndexSwap { .. } => Kind::IndexSwap,
+            KindWithContent::MultiIndexDocumentAddition { .. } => Kind::MultiIndexDocumentAddition,
             KindWithContent::TaskCancelation { .. } => Kind::TaskCancelation,
             KindWithContent::TaskDeletion { .. } => Kind::TaskDeletion,
             KindWithContent::DumpCreation { .. } => Kind::DumpCreation,
@@ -194,6 +257,7 @@ impl KindWithContent {
             | SettingsUpdate { index_uid, .. }
             | IndexCreation { index_uid, .. }
             | IndexUpdate { index_uid, .. }
+            | IndexCompaction { index_uid }
             | IndexDeletion { index_uid } => vec![index_uid],
             IndexSwap { swaps } => {
                 let mut indexes = HashSet::<&str>::default();
@@ -203,9 +267,33 @@ impl KindWithContent {
                 }
                 indexes.into_iter().collect()
             }
+            MultiIndexDocumentAddition { additions, .. } => {
+                let mut indexes = Vec::<&str>::new();
+                for addition in additions {
+                    if !indexes.contains(&addition.index_uid.as_str()) {
+                        indexes.push(&addition.index_uid);
+                    }
+                }
+                indexes
+            }
         }
     }
 
+    /// Returns the number of documents received for each index of a `MultiIndexDocumentAddition`.
+    fn multi_index_documents_counts(
+        additions: &[IndexDocumentAddition],
+        indexed_documents: Option<u64>,
+    ) -> BTreeMap<String, IndexedDocumentsCount> {
+        let mut indexes = BTreeMap::<String, IndexedDocumentsCount>::new();
+        for IndexDocumentAddition { index_uid, documents_count, .. } in additions {
+            let count = indexes
+                .entry(index_uid.clone())
+                .or_insert(IndexedDocumentsCount { received_documents: 0, indexed_documents });
+            count.received_documents += documents_count;
+        }
+        indexes
+    }
+
     /// Returns the default `Details` that correspond to this `KindWithContent`,
     /// `None` if it cannot be generated.
     pub fn default_details(&self) -> Option<Details> {
@@ -214,6 +302,8 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    skipped_documents: Vec::new(),
+                    skipped_documents_count: 0,
                 })
             }
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
@@ -247,9 +337,17 @@ impl KindWithContent {
             | KindWithContent::IndexUpdate { primary_key, .. } => {
                 Some(Details::IndexInfo { primary_key: primary_key.clone() })
             }
+            KindWithContent::IndexCompaction { .. } => {
+                Some(Details::IndexCompaction { reclaimed_bytes: None })
+            }
             KindWithContent::IndexSwap { swaps } => {
                 Some(Details::IndexSwap { swaps: swaps.clone() })
             }
+            KindWithContent::MultiIndexDocumentAddition { additions, .. } => {
+                Some(Details::MultiIndexDocumentAddition {
+                    indexes: Self::multi_index_documents_counts(additions, None),
+                })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: None,
@@ -271,6 +369,8 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: Some(0),
+                    skipped_documents: Vec::new(),
+                    skipped_documents_count: 0,
                 })
             }
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
@@ -305,9 +405,17 @@ impl KindWithContent {
             | KindWithContent::IndexUpdate { primary_key, .. } => {
                 Some(Details::IndexInfo { primary_key: primary_key.clone() })
             }
+            KindWithContent::IndexCompaction { .. } => {
+                Some(Details::IndexCompaction { reclaimed_bytes: Some(0) })
+            }
             KindWithContent::IndexSwap { .. } => {
                 todo!()
             }
+            KindWithContent::MultiIndexDocumentAddition { additions, .. } => {
+                Some(Details::MultiIndexDocumentAddition {
+                    indexes: Self::multi_index_documents_counts(additions, Some(0)),
+                })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: Some(0),
@@ -331,6 +439,8 @@ impl From<&KindWithContent> for Option<Details> {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    skipped_documents: Vec::new(),
+                    skipped_documents_count: 0,
                 })
             }
             KindWithContent::DocumentEdition { .. } => None,
@@ -347,7 +457,15 @@ impl From<&KindWithContent> for Option<Details> {
             KindWithContent::IndexUpdate { primary_key, .. } => {
                 Some(Details::IndexInfo { primary_key: primary_key.clone() })
             }
+            KindWithContent::IndexCompaction { .. } => {
+                Some(Details::IndexCompaction { reclaimed_bytes: None })
+            }
             KindWithContent::IndexSwap { .. } => None,
+            KindWithContent::MultiIndexDocumentAddition { additions, .. } => {
+                Some(Details::MultiIndexDocumentAddition {
+                    indexes: KindWithContent::multi_index_documents_counts(additions, None),
+                })
+            }
             KindWithContent::TaskCancelation { query, tasks } => Some(Details::TaskCancelation {
                 matched_tasks: tasks.len(),
                 canceled_tasks: None,
@@ -468,6 +586,8 @@ pub enum Kind {
     TaskDeletion,
     DumpCreation,
     SnapshotCreation,
+    IndexCompaction,
+    MultiIndexDocumentAddition,
 }
 
 impl Kind {
@@ -479,8 +599,10 @@ impl Kind {
             | Kind::SettingsUpdate
             | Kind::IndexCreation
             | Kind::IndexDeletion
-            | Kind::IndexUpdate => true,
+            | Kind::IndexUpdate
+            | Kind::IndexCompaction => true,
             Kind::IndexSwap
+            | Kind::MultiIndexDocumentAddition
             | Kind::TaskCancelation
             | Kind::TaskDeletion
             | Kind::DumpCreation
@@ -503,6 +625,8 @@ impl Display for Kind {
             Kind::TaskDeletion => write!(f, "taskDeletion"),
             Kind::DumpCreation => write!(f, "dumpCreation"),
             Kind::SnapshotCreation => write!(f, "snapshotCreation"),
+            Kind::IndexCompaction => write!(f, "indexCompaction"),
+            Kind::MultiIndexDocumentAddition => write!(f, "multiIndexDocumentAddition"),
         }
     }
 }
@@ -518,8 +642,12 @@ impl FromStr for Kind {
             Ok(Kind::IndexSwap)
         } else if kind.eq_ignore_ascii_case("indexDeletion") {
             Ok(Kind::IndexDeletion)
+        } else if kind.eq_ignore_ascii_case("indexCompaction") {
+            Ok(Kind::IndexCompaction)
         } else if kind.eq_ignore_ascii_case("documentAdditionOrUpdate") {
             Ok(Kind::DocumentAdditionOrUpdate)
+        } else if kind.eq_ignore_ascii_case("multiIndexDocumentAddition") {
+            Ok(Kind::MultiIndexDocumentAddition)
         } else if kind.eq_ignore_ascii_case("documentEdition") {
             Ok(Kind::DocumentEdition)
         } else if kind.eq_ignore_ascii_case("documentDeletion") {
@@ -566,6 +694,11 @@ pub enum Details {
     DocumentAdditionOrUpdate {
         received_documents: u64,
         indexed_documents: Option<u64>,
+        /// The first skipped documents, see [`MAX_REPORTED_SKIPPED_DOCUMENTS`].
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        skipped_documents: Vec<SkippedDocument>,
+        #[serde(default, skip_serializing_if = "is_zero")]
+        skipped_documents_count: u64,
     },
     SettingsUpdate {
         settings: Box<Settings<Unchecked>>,
@@ -607,6 +740,12 @@ pub enum Details {
     IndexSwap {
         swaps: Vec<IndexSwap>,
     },
+    IndexCompaction {
+        reclaimed_bytes: Option<u64>,
+    },
+    MultiIndexDocumentAddition {
+        indexes: BTreeMap<String, IndexedDocumentsCount>,
+    },
 }
 
 impl Details {
@@ -624,6 +763,10 @@ impl Details {
             Self::ClearAll { deleted_documents } => *deleted_documents = Some(0),
             Self::TaskCancelation { canceled_tasks, .. } => *canceled_tasks = Some(0),
             Self::TaskDeletion { deleted_tasks, .. } => *deleted_tasks = Some(0),
+            Self::IndexCompaction { reclaimed_bytes } => *reclaimed_bytes = Some(0),
+            Self::MultiIndexDocumentAddition { indexes } => {
+                indexes.values_mut().for_each(|count| count.indexed_documents = Some(0))
+            }
             Self::SettingsUpdate { .. }
             | Self::IndexInfo { .. }
             | Self::Dump { .. }
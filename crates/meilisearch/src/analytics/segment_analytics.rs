@@ -185,6 +185,7 @@ struct Infos {
     experimental_contains_filter: bool,
     experimental_vector_store: bool,
     experimental_enable_metrics: bool,
+    experimental_http_response_time_buckets: bool,
     experimental_edit_documents_by_function: bool,
     experimental_search_queue_size: usize,
     experimental_drop_search_after: usize,
@@ -232,6 +233,7 @@ impl Infos {
             db_path,
             experimental_contains_filter,
             experimental_enable_metrics,
+            experimental_http_response_time_buckets,
             experimental_search_queue_size,
             experimental_drop_search_after,
             experimental_nb_searches_per_core,
@@ -295,6 +297,8 @@ impl Infos {
             experimental_vector_store: vector_store,
             experimental_edit_documents_by_function: edit_documents_by_function,
             experimental_enable_metrics: experimental_enable_metrics | metrics,
+            experimental_http_response_time_buckets: experimental_http_response_time_buckets
+                .is_some(),
             experimental_search_queue_size,
             experimental_drop_search_after: experimental_drop_search_after.into(),
             experimental_nb_searches_per_core: experimental_nb_searches_per_core.into(),
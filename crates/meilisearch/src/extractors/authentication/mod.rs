@@ -9,6 +9,7 @@ use actix_web::FromRequest;
 pub use error::AuthenticationError;
 use futures::future::err;
 use futures::Future;
+use index_scheduler::IndexScheduler;
 use meilisearch_auth::{AuthController, AuthFilter};
 use meilisearch_types::error::{Code, ResponseError};
 
@@ -29,6 +30,7 @@ impl<P, D> GuardedData<P, D> {
         auth: Data<AuthController>,
         token: String,
         index: Option<String>,
+        index_scheduler: Option<Data<IndexScheduler>>,
         data: Option<D>,
     ) -> Result<Self, ResponseError>
     where
@@ -36,7 +38,7 @@ impl<P, D> GuardedData<P, D> {
     {
         let missing_master_key = auth.get_master_key().is_none();
 
-        match Self::authenticate(auth, token, index).await? {
+        match Self::authenticate(auth, token, index, index_scheduler).await? {
             Ok(filters) => match data {
                 Some(data) => Ok(Self { data, filters, _marker: PhantomData }),
                 None => Err(AuthenticationError::IrretrievableState.into()),
@@ -52,7 +54,7 @@ impl<P, D> GuardedData<P, D> {
     {
         let missing_master_key = auth.get_master_key().is_none();
 
-        match Self::authenticate(auth, String::new(), None).await? {
+        match Self::authenticate(auth, String::new(), None, None).await? {
             Ok(filters) => match data {
                 Some(data) => Ok(Self { data, filters, _marker: PhantomData }),
                 None => Err(AuthenticationError::IrretrievableState.into()),
@@ -66,13 +68,23 @@ impl<P, D> GuardedData<P, D> {
         auth: Data<AuthController>,
         token: String,
         index: Option<String>,
+        index_scheduler: Option<Data<IndexScheduler>>,
     ) -> Result<Result<AuthFilter, AuthError>, ResponseError>
     where
         P: Policy + 'static,
     {
-        tokio::task::spawn_blocking(move || P::authenticate(auth, token.as_ref(), index.as_deref()))
-            .await
-            .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))
+        tokio::task::spawn_blocking(move || {
+            // The key must be authorized on the index the alias points to, not on the alias.
+            let index = match (index, index_scheduler) {
+                (Some(index), Some(index_scheduler)) => {
+                    Some(index_scheduler.resolve_alias(&index)?)
+                }
+                (index, _) => index,
+            };
+            Ok::<_, ResponseError>(P::authenticate(auth, token.as_ref(), index.as_deref()))
+        })
+        .await
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))?
     }
 }
 
@@ -108,6 +120,7 @@ impl<P: Policy + 'static, D: 'static + Clone> FromRequest for GuardedData<P, D>
                                 auth,
                                 token.to_string(),
                                 index.map(String::from),
+                                req.app_data::<Data<IndexScheduler>>().cloned(),
                                 req.app_data::<D>().cloned(),
                             )),
                             None => Box::pin(err(AuthenticationError::InvalidToken.into())),
@@ -289,6 +302,12 @@ pub mod policies {
             if auth.is_key_authorized(key_uuid, action, index).unwrap_or(false) {
                 return Ok(auth_filter);
             }
+            // The keys created before the facet search action existed only hold the search action.
+            if A == actions::FACET_SEARCH
+                && auth.is_key_authorized(key_uuid, Action::Search, index).unwrap_or(false)
+            {
+                return Ok(auth_filter);
+            }
 
             Err(AuthError::InvalidApiKey)
         }
@@ -299,8 +318,8 @@ pub mod policies {
             auth: &AuthController,
             token: &str,
         ) -> Result<TenantTokenOutcome, AuthError> {
-            // Only search action can be accessed by a tenant token.
-            if A != actions::SEARCH {
+            // Only search actions can be accessed by a tenant token.
+            if A != actions::SEARCH && A != actions::FACET_SEARCH {
                 return Ok(TenantTokenOutcome::NotATenantToken);
             }
 
@@ -15,6 +15,7 @@ pub mod search_queue;
 
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
+use std::num::NonZeroU64;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -30,6 +31,7 @@ use actix_web::web::Data;
 use actix_web::{web, HttpRequest};
 use analytics::Analytics;
 use anyhow::bail;
+use dump::DumpProgress;
 use error::PayloadError;
 use extractors::payload::PayloadConfig;
 use index_scheduler::{IndexScheduler, IndexSchedulerOptions};
@@ -76,6 +78,12 @@ const DEFAULT_INDEX_COUNT: usize = 4;
 #[cfg(not(windows))]
 const DEFAULT_INDEX_COUNT: usize = 20;
 
+/// Interval at which the documents past the time-to-live of their index are deleted.
+///
+/// The scheduler only wakes up to sweep when an index has a time-to-live,
+/// and only the indexes with a time-to-live are opened.
+const DOCUMENT_TTL_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Check if a db is empty. It does not provide any information on the
 /// validity of the data in it.
 /// We consider a database as non empty when it's a non empty directory.
@@ -206,6 +214,10 @@ enum OnFailure {
 }
 
 pub fn setup_meilisearch(opt: &Opt) -> anyhow::Result<(Arc<IndexScheduler>, Arc<AuthController>)> {
+    if let Some(buckets) = &opt.experimental_http_response_time_buckets {
+        metrics::configure_http_response_time_buckets(buckets.clone())?;
+    }
+
     let empty_db = is_empty_db(&opt.db_path);
     let (index_scheduler, auth_controller) = if let Some(ref snapshot_path) = opt.import_snapshot {
         let snapshot_path_exists = snapshot_path.exists();
@@ -316,6 +328,7 @@ fn open_or_create_database_unchecked(
             index_growth_amount: byte_unit::Byte::from_str("10GiB").unwrap().as_u64() as usize,
             index_count: DEFAULT_INDEX_COUNT,
             instance_features,
+            document_ttl_sweep_interval: Some(DOCUMENT_TTL_SWEEP_INTERVAL),
         })?)
     };
 
@@ -354,6 +367,15 @@ fn import_dump(
 ) -> Result<(), anyhow::Error> {
     let reader = File::open(dump_path)?;
     let mut dump_reader = dump::DumpReader::open(reader)?;
+    // Converting and reading large dumps can take a while, let the user know it is progressing.
+    dump_reader.on_progress(NonZeroU64::new(100_000).unwrap(), |progress| match progress {
+        // the indexes are already logged when we start importing them
+        DumpProgress::Index { .. } => (),
+        DumpProgress::Documents { index_uid, count } => {
+            tracing::info!(%index_uid, count, "Read {count} documents from the dump.")
+        }
+        DumpProgress::Tasks { count } => tracing::info!(count, "Read {count} tasks from the dump."),
+    });
 
     if let Some(date) = dump_reader.date() {
         tracing::info!(
@@ -462,9 +484,14 @@ fn import_dump(
         index_scheduler.refresh_index_stats(&uid)?;
     }
 
+    // 5. Import the aliases, once the indexes they point to exist.
+    for (alias, index_uid) in dump_reader.aliases() {
+        index_scheduler.put_alias(&alias, &index_uid)?;
+    }
+
     let mut index_scheduler_dump = index_scheduler.register_dumped_task()?;
 
-    // 5. Import the tasks.
+    // 6. Import the tasks.
     for ret in dump_reader.tasks()? {
         let (task, file) = ret?;
         index_scheduler_dump.register_dumped_task(task, file)?;
@@ -1,13 +1,41 @@
+use std::sync::OnceLock;
+
+use anyhow::bail;
 use lazy_static::lazy_static;
 use prometheus::{
     opts, register_gauge, register_histogram_vec, register_int_counter_vec, register_int_gauge,
     register_int_gauge_vec, Gauge, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec,
 };
 
+/// The upper bounds of the HTTP response time buckets when none are configured.
+const DEFAULT_HTTP_RESPONSE_TIME_BUCKETS: &[f64] =
+    &[0.005, 0.01, 0.025, 0.05, 0.075, 0.1, 0.25, 0.5, 0.75, 1.0, 2.5, 5.0, 7.5, 10.0];
+
+static HTTP_RESPONSE_TIME_BUCKETS: OnceLock<Vec<f64>> = OnceLock::new();
+
+/// Sets the buckets of [`MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS`].
+///
+/// Must be called once, at startup, before the first request is measured.
+pub fn configure_http_response_time_buckets(buckets: Vec<f64>) -> anyhow::Result<()> {
+    if buckets.is_empty() {
+        bail!("the HTTP response time buckets must not be empty");
+    }
+    if buckets.iter().any(|bound| !bound.is_finite()) {
+        bail!("the HTTP response time buckets must be finite numbers");
+    }
+    if buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+        bail!("the HTTP response time buckets must be in strictly increasing order");
+    }
+    if HTTP_RESPONSE_TIME_BUCKETS.set(buckets).is_err() {
+        bail!("the HTTP response time buckets are already configured");
+    }
+    Ok(())
+}
+
 lazy_static! {
     pub static ref MEILISEARCH_HTTP_REQUESTS_TOTAL: IntCounterVec = register_int_counter_vec!(
         opts!("meilisearch_http_requests_total", "Meilisearch HTTP requests total"),
-        &["method", "path", "status"]
+        &["method", "path", "index_uid", "status"]
     )
     .expect("Can't create a metric");
     pub static ref MEILISEARCH_DEGRADED_SEARCH_REQUESTS: IntGauge = register_int_gauge!(opts!(
@@ -34,8 +62,29 @@ lazy_static! {
     pub static ref MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS: HistogramVec = register_histogram_vec!(
         "meilisearch_http_response_time_seconds",
         "Meilisearch HTTP response times",
+        &["method", "path", "index_uid"],
+        HTTP_RESPONSE_TIME_BUCKETS
+            .get()
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_HTTP_RESPONSE_TIME_BUCKETS.to_vec())
+    )
+    .expect("Can't create a metric");
+    pub static ref MEILISEARCH_HTTP_REQUEST_SIZE_BYTES: HistogramVec = register_histogram_vec!(
+        "meilisearch_http_request_size_bytes",
+        "Meilisearch HTTP request body sizes",
         &["method", "path"],
-        vec![0.005, 0.01, 0.025, 0.05, 0.075, 0.1, 0.25, 0.5, 0.75, 1.0, 2.5, 5.0, 7.5, 10.0]
+        vec![
+            1_024.0,
+            4_096.0,
+            16_384.0,
+            65_536.0,
+            262_144.0,
+            1_048_576.0,
+            4_194_304.0,
+            16_777_216.0,
+            67_108_864.0,
+            268_435_456.0
+        ]
     )
     .expect("Can't create a metric");
     pub static ref MEILISEARCH_NB_TASKS: IntGaugeVec = register_int_gauge_vec!(
@@ -69,3 +118,41 @@ lazy_static! {
     )
     .expect("Can't create a metric");
 }
+
+#[cfg(test)]
+mod tests {
+    use prometheus::core::Collector;
+    use prometheus::{Encoder, TextEncoder};
+
+    use super::*;
+
+    #[test]
+    fn configured_http_response_time_buckets() {
+        assert!(configure_http_response_time_buckets(vec![]).is_err());
+        assert!(configure_http_response_time_buckets(vec![0.1, 0.1]).is_err());
+        assert!(configure_http_response_time_buckets(vec![0.1, f64::INFINITY]).is_err());
+
+        configure_http_response_time_buckets(vec![0.0005, 0.001, 0.0025]).unwrap();
+        // the buckets can only be configured once, before the histogram is built
+        assert!(configure_http_response_time_buckets(vec![1.0]).is_err());
+
+        MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS
+            .with_label_values(&["GET", "/health", "-"])
+            .observe(0.0007);
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS.collect(), &mut buffer)
+            .unwrap();
+        let metrics = String::from_utf8(buffer).unwrap();
+        let buckets: Vec<_> = metrics
+            .lines()
+            .filter_map(|line| {
+                line.strip_prefix(r#"meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/health","#)
+            })
+            .collect();
+        assert_eq!(
+            buckets,
+            [r#"le="0.0005"} 0"#, r#"le="0.001"} 1"#, r#"le="0.0025"} 1"#, r#"le="+Inf"} 1"#]
+        );
+    }
+}
@@ -1,13 +1,33 @@
 //! Contains all the custom middleware used in meilisearch
 
 use std::future::{ready, Ready};
+use std::time::Instant;
 
 use actix_web::dev::{self, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::CONTENT_LENGTH;
 use actix_web::web::Data;
 use actix_web::Error;
 use futures_util::future::LocalBoxFuture;
 use index_scheduler::IndexScheduler;
-use prometheus::HistogramTimer;
+
+/// The `index_uid` label of the routes that are not about a single index, and of the
+/// requests that failed.
+const NO_INDEX_LABEL: &str = "-";
+
+/// Returns the segment of the path matching the `{index_uid}` segment of its route pattern.
+///
+/// The routing is not done yet when the middleware is called, so the match info is empty.
+fn index_uid_segment<'a>(pattern: &str, path: &'a str) -> Option<&'a str> {
+    pattern
+        .split('/')
+        .zip(path.split('/'))
+        .find_map(|(pattern, segment)| (pattern == "{index_uid}").then_some(segment))
+}
+
+/// Returns the size of the body announced by the `Content-Length` header, if any.
+fn content_length(req: &ServiceRequest) -> Option<u64> {
+    req.headers().get(CONTENT_LENGTH)?.to_str().ok()?.parse().ok()
+}
 
 pub struct RouteMetrics;
 
@@ -48,7 +68,7 @@ where
     dev::forward_ready!(service);
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
-        let mut histogram_timer: Option<HistogramTimer> = None;
+        let mut request_start: Option<Instant> = None;
 
         // calling unwrap here is safe because index scheduler is added to app data while creating actix app.
         // also, the tests will fail if this is not present.
@@ -59,15 +79,23 @@ where
         let request_pattern = req.match_pattern();
         let metric_path = request_pattern.as_ref().map_or(request_path, String::as_str).to_string();
         let request_method = req.method().to_string();
+        let mut request_index_uid = None;
 
         if features.check_metrics().is_ok() {
             let is_registered_resource = req.resource_map().has_resource(request_path);
             if is_registered_resource {
-                histogram_timer = Some(
-                    crate::metrics::MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS
+                request_index_uid = request_pattern
+                    .as_deref()
+                    .and_then(|pattern| index_uid_segment(pattern, request_path))
+                    .map(ToString::to_string);
+                request_start = Some(Instant::now());
+
+                // chunked requests have no length, recording them as empty would skew the sizes
+                if let Some(content_length) = content_length(&req) {
+                    crate::metrics::MEILISEARCH_HTTP_REQUEST_SIZE_BYTES
                         .with_label_values(&[&request_method, &metric_path])
-                        .start_timer(),
-                );
+                        .observe(content_length as f64);
+                }
             }
         };
 
@@ -76,12 +104,26 @@ where
         Box::pin(async move {
             let res = fut.await?;
 
+            // The requests reading a missing index fail and the ones writing to it create it,
+            // only labelling the successful ones keeps the label cardinality bounded by the
+            // number of indexes without looking the index up on every request.
+            let metric_index_uid = request_index_uid
+                .filter(|_| res.status().is_success())
+                .unwrap_or_else(|| NO_INDEX_LABEL.to_string());
+
             crate::metrics::MEILISEARCH_HTTP_REQUESTS_TOTAL
-                .with_label_values(&[&request_method, &metric_path, res.status().as_str()])
+                .with_label_values(&[
+                    &request_method,
+                    &metric_path,
+                    &metric_index_uid,
+                    res.status().as_str(),
+                ])
                 .inc();
 
-            if let Some(histogram_timer) = histogram_timer {
-                histogram_timer.observe_duration();
+            if let Some(request_start) = request_start {
+                crate::metrics::MEILISEARCH_HTTP_RESPONSE_TIME_SECONDS
+                    .with_label_values(&[&request_method, &metric_path, &metric_index_uid])
+                    .observe(request_start.elapsed().as_secs_f64());
             };
             Ok(res)
         })
@@ -53,6 +53,8 @@ const MEILI_EXPERIMENTAL_REPLICATION_PARAMETERS: &str = "MEILI_EXPERIMENTAL_REPL
 const MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE: &str = "MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE";
 const MEILI_EXPERIMENTAL_CONTAINS_FILTER: &str = "MEILI_EXPERIMENTAL_CONTAINS_FILTER";
 const MEILI_EXPERIMENTAL_ENABLE_METRICS: &str = "MEILI_EXPERIMENTAL_ENABLE_METRICS";
+const MEILI_EXPERIMENTAL_HTTP_RESPONSE_TIME_BUCKETS: &str =
+    "MEILI_EXPERIMENTAL_HTTP_RESPONSE_TIME_BUCKETS";
 const MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE: &str = "MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE";
 const MEILI_EXPERIMENTAL_DROP_SEARCH_AFTER: &str = "MEILI_EXPERIMENTAL_DROP_SEARCH_AFTER";
 const MEILI_EXPERIMENTAL_NB_SEARCHES_PER_CORE: &str = "MEILI_EXPERIMENTAL_NB_SEARCHES_PER_CORE";
@@ -360,6 +362,14 @@ pub struct Opt {
     #[serde(default)]
     pub experimental_enable_metrics: bool,
 
+    /// Experimental metrics feature.
+    ///
+    /// Sets the upper bounds, in seconds and in increasing order, of the buckets of the
+    /// `meilisearch_http_response_time_seconds` histogram, e.g. `0.0005,0.001,0.0025,0.005`.
+    #[clap(long, env = MEILI_EXPERIMENTAL_HTTP_RESPONSE_TIME_BUCKETS, value_delimiter = ',')]
+    #[serde(default)]
+    pub experimental_http_response_time_buckets: Option<Vec<f64>>,
+
     /// Experimental search queue size. For more information,
     /// see: <https://github.com/orgs/meilisearch/discussions/729>
     ///
@@ -531,6 +541,7 @@ impl Opt {
             no_analytics,
             experimental_contains_filter,
             experimental_enable_metrics,
+            experimental_http_response_time_buckets,
             experimental_search_queue_size,
             experimental_drop_search_after,
             experimental_nb_searches_per_core,
@@ -592,6 +603,12 @@ impl Opt {
             MEILI_EXPERIMENTAL_ENABLE_METRICS,
             experimental_enable_metrics.to_string(),
         );
+        if let Some(buckets) = experimental_http_response_time_buckets {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_HTTP_RESPONSE_TIME_BUCKETS,
+                buckets.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
+            );
+        }
         export_to_env_if_not_present(
             MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE,
             experimental_search_queue_size.to_string(),
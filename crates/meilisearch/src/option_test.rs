@@ -7,6 +7,17 @@ fn test_valid_opt() {
     assert!(Opt::try_parse_from(Some("")).is_ok());
 }
 
+#[test]
+fn test_http_response_time_buckets_opt() {
+    let opt = Opt::try_parse_from([
+        "meilisearch",
+        "--experimental-http-response-time-buckets",
+        "0.0005,0.001,0.0025",
+    ])
+    .unwrap();
+    assert_eq!(opt.experimental_http_response_time_buckets, Some(vec![0.0005, 0.001, 0.0025]));
+}
+
 #[test]
 #[ignore]
 fn test_meilli_config_file_path_valid() {
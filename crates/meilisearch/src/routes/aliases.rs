@@ -0,0 +1,206 @@
+use actix_web::web::Data;
+use actix_web::{web, HttpResponse};
+use deserr::actix_web::AwebJson;
+use deserr::Deserr;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::InvalidIndexUid;
+use meilisearch_types::error::ResponseError;
+use meilisearch_types::index_uid::IndexUid;
+use serde::Serialize;
+use tracing::debug;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::{AuthenticationError, GuardedData};
+use crate::extractors::sequential_extractor::SeqHandler;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(list_aliases, put_alias, delete_alias),
+    tags((
+        name = "Aliases",
+        description = "An alias is a stable name pointing to an index. The index, documents, settings and search routes of an alias are applied to the index it points to, which allows reindexing in a new index and then pointing the alias to it.
+
+This route is **synchronous**. This means that no task object will be returned, and the alias is usable, or removed, immediately.",
+    )),
+)]
+pub struct AliasesApi;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(list_aliases))).service(
+        web::resource("/{alias}")
+            .route(web::put().to(SeqHandler(put_alias)))
+            .route(web::delete().to(SeqHandler(delete_alias))),
+    );
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct AliasView {
+    /// The name of the alias
+    #[schema(example = "movies")]
+    pub alias: String,
+    /// The index the alias points to
+    #[schema(example = "movies_v2")]
+    pub index_uid: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AliasesView {
+    pub results: Vec<AliasView>,
+}
+
+/// List aliases
+///
+/// List the aliases pointing to the indexes the API key can access.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "Aliases",
+    security(("Bearer" = ["indexes.get", "indexes.*", "*"])),
+    responses(
+        (status = 200, description = "The aliases are returned", body = AliasesView, content_type = "application/json", example = json!(
+            {
+                "results": [{ "alias": "movies", "indexUid": "movies_v2" }]
+            }
+        )),
+        (status = 401, description = "The authorization header is missing", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn list_aliases(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
+) -> Result<HttpResponse, ResponseError> {
+    let filters = index_scheduler.filters();
+    let results = index_scheduler
+        .aliases()?
+        .into_iter()
+        .filter(|(alias, index_uid)| {
+            filters.is_index_authorized(alias) && filters.is_index_authorized(index_uid)
+        })
+        .map(|(alias, index_uid)| AliasView { alias, index_uid })
+        .collect();
+
+    let aliases = AliasesView { results };
+    debug!(returns = ?aliases, "List aliases");
+    Ok(HttpResponse::Ok().json(aliases))
+}
+
+#[derive(Deserr, Debug, ToSchema)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+#[schema(rename_all = "camelCase")]
+pub struct AliasPayload {
+    /// The index the alias points to
+    #[schema(example = "movies_v2")]
+    #[deserr(error = DeserrJsonError<InvalidIndexUid>, missing_field_error = DeserrJsonError::missing_index_uid)]
+    index_uid: IndexUid,
+}
+
+/// Create or update an alias
+///
+/// Make the alias point to an index, creating the alias if it doesn't exist.
+/// An alias cannot have the name of an index and must point to an existing index.
+#[utoipa::path(
+    put,
+    path = "/{alias}",
+    tag = "Aliases",
+    security(("Bearer" = ["indexes.update", "indexes.*", "*"])),
+    params(("alias", example = "movies", description = "The name of the alias", nullable = false)),
+    request_body = AliasPayload,
+    responses(
+        (status = 200, description = "The alias points to the index", body = AliasView, content_type = "application/json", example = json!(
+            { "alias": "movies", "indexUid": "movies_v2" }
+        )),
+        (status = 401, description = "The authorization header is missing", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+        (status = 409, description = "An index has the name of the alias", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "`movies` cannot be used both as an index uid and as an alias.",
+                "code": "index_alias_conflict",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#index_alias_conflict"
+            }
+        )),
+    )
+)]
+pub async fn put_alias(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    alias: web::Path<String>,
+    body: AwebJson<AliasPayload, DeserrJsonError>,
+) -> Result<HttpResponse, ResponseError> {
+    let alias = IndexUid::try_from(alias.into_inner())?.into_inner();
+    let AliasPayload { index_uid } = body.into_inner();
+    debug!(parameters = ?(&alias, &index_uid), "Put alias");
+
+    let filters = index_scheduler.filters();
+    if !filters.is_index_authorized(&alias) || !filters.is_index_authorized(&index_uid) {
+        return Err(AuthenticationError::InvalidToken.into());
+    }
+
+    let index_uid = index_uid.into_inner();
+    index_scheduler.put_alias(&alias, &index_uid)?;
+
+    let alias = AliasView { alias, index_uid };
+    debug!(returns = ?alias, "Put alias");
+    Ok(HttpResponse::Ok().json(alias))
+}
+
+/// Delete an alias
+///
+/// Delete the alias, the index it points to is left untouched.
+#[utoipa::path(
+    delete,
+    path = "/{alias}",
+    tag = "Aliases",
+    security(("Bearer" = ["indexes.update", "indexes.*", "*"])),
+    params(("alias", example = "movies", description = "The name of the alias", nullable = false)),
+    responses(
+        (status = 204, description = "The alias is deleted"),
+        (status = 401, description = "The authorization header is missing", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+        (status = 404, description = "The alias doesn't exist", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "Alias `movies` not found.",
+                "code": "alias_not_found",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#alias_not_found"
+            }
+        )),
+    )
+)]
+pub async fn delete_alias(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    alias: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let alias = alias.into_inner();
+    debug!(parameters = ?alias, "Delete alias");
+
+    let filters = index_scheduler.filters();
+    let index_uid = index_scheduler.resolve_alias(&alias)?;
+    if !filters.is_index_authorized(&alias) || !filters.is_index_authorized(&index_uid) {
+        return Err(AuthenticationError::InvalidToken.into());
+    }
+
+    index_scheduler.delete_alias(&alias)?;
+    Ok(HttpResponse::NoContent().finish())
+}
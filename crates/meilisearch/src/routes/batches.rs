@@ -1,23 +1,28 @@
 use actix_web::web::{self, Data};
 use actix_web::HttpResponse;
 use deserr::actix_web::AwebQueryParameter;
+use deserr::Deserr;
 use index_scheduler::{IndexScheduler, Query};
 use meilisearch_types::batch_view::BatchView;
 use meilisearch_types::batches::BatchId;
+use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::keys::actions;
+use meilisearch_types::task_view::TaskView;
+use meilisearch_types::tasks::TaskId;
 use serde::Serialize;
-use utoipa::{OpenApi, ToSchema};
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-use super::tasks::TasksFilterQuery;
-use super::ActionPolicy;
+use super::tasks::{AllTasks, TasksFilterQuery};
+use super::{ActionPolicy, PAGINATION_DEFAULT_LIMIT};
 use crate::extractors::authentication::GuardedData;
 use crate::extractors::sequential_extractor::SeqHandler;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(get_batch, get_batches),
+    paths(get_batch, get_batches, get_batch_tasks),
     tags((
         name = "Batches",
         description = "The /batches route gives information about the progress of batches of asynchronous operations.",
@@ -28,7 +33,10 @@ pub struct BatchesApi;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(web::resource("").route(web::get().to(SeqHandler(get_batches))))
-        .service(web::resource("/{batch_id}").route(web::get().to(SeqHandler(get_batch))));
+        .service(web::resource("/{batch_id}").route(web::get().to(SeqHandler(get_batch))))
+        .service(
+            web::resource("/{batch_id}/tasks").route(web::get().to(SeqHandler(get_batch_tasks))),
+        );
 }
 
 /// Get one batch
@@ -194,3 +202,104 @@ async fn get_batches(
 
     Ok(HttpResponse::Ok().json(tasks))
 }
+
+#[derive(Debug, Deserr, IntoParams)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+#[into_params(rename_all = "camelCase", parameter_in = Query)]
+pub struct BatchTasksQuery {
+    /// Maximum number of results to return.
+    #[deserr(default = Param(PAGINATION_DEFAULT_LIMIT as u32), error = DeserrQueryParamError<InvalidTaskLimit>)]
+    #[param(required = false, value_type = u32, example = 12, default = json!(PAGINATION_DEFAULT_LIMIT))]
+    pub limit: Param<u32>,
+    /// Fetch the next set of results from the given task uid.
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskFrom>)]
+    #[param(required = false, value_type = Option<u32>, example = 12421)]
+    pub from: Option<Param<TaskId>>,
+}
+
+/// Get the tasks of a batch
+///
+/// List the tasks processed in a batch. The task objects are contained in the results array.
+/// Tasks are returned in ascending order of uid and are paginated with the `from` and `limit` query parameters.
+#[utoipa::path(
+    get,
+    path = "/{batchUid}/tasks",
+    tag = "Batches",
+    security(("Bearer" = ["tasks.get", "tasks.*", "*"])),
+    params(
+        ("batchUid" = String, Path, example = "8685", description = "The unique batch id", nullable = false),
+        BatchTasksQuery,
+    ),
+    responses(
+        (status = OK, description = "Return the tasks of the batch", body = AllTasks, content_type = "application/json", example = json!(
+            {
+                "results": [
+                    {
+                        "uid": 1,
+                        "batchUid": 1,
+                        "indexUid": "movies",
+                        "status": "succeeded",
+                        "type": "documentDeletion",
+                        "canceledBy": null,
+                        "details": {
+                            "providedIds": 1,
+                            "deletedDocuments": 1,
+                            "originalFilter": null
+                        },
+                        "error": null,
+                        "duration": "PT0.006962S",
+                        "enqueuedAt": "2024-12-10T15:48:49.655829Z",
+                        "startedAt": "2024-12-10T15:48:49.672141Z",
+                        "finishedAt": "2024-12-10T15:48:49.679103Z"
+                    }
+                ],
+                "total": 2,
+                "limit": 1,
+                "from": 1,
+                "next": 2
+            }
+        )),
+        (status = 401, description = "The authorization header is missing", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+async fn get_batch_tasks(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    batch_uid: web::Path<String>,
+    params: AwebQueryParameter<BatchTasksQuery, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    let batch_uid_string = batch_uid.into_inner();
+
+    let batch_uid: BatchId = match batch_uid_string.parse() {
+        Ok(id) => id,
+        Err(_e) => {
+            return Err(
+                index_scheduler::Error::InvalidBatchUid { batch_uid: batch_uid_string }.into()
+            )
+        }
+    };
+
+    let BatchTasksQuery { limit, from } = params.into_inner();
+    // We +1 just to know if there is more after this "page" or not.
+    let limit = limit.0.saturating_add(1);
+
+    let filters = index_scheduler.filters();
+    let (tasks, total) =
+        index_scheduler.tasks_in_batch(batch_uid, from.map(|from| from.0), limit, filters)?;
+    let mut results: Vec<_> = tasks.iter().map(TaskView::from_task).collect();
+
+    // If we were able to fetch the number +1 tasks we asked
+    // it means that there is more to come.
+    let next = if results.len() == limit as usize { results.pop().map(|t| t.uid) } else { None };
+
+    let from = results.first().map(|t| t.uid);
+    let tasks = AllTasks { results, limit: limit.saturating_sub(1), total, from, next };
+
+    Ok(HttpResponse::Ok().json(tasks))
+}
@@ -1,14 +1,15 @@
 use std::collections::HashSet;
+use std::hash::Hasher as _;
 use std::io::{ErrorKind, Seek as _};
 use std::marker::PhantomData;
 
-use actix_web::http::header::CONTENT_TYPE;
-use actix_web::web::Data;
+use actix_web::http::header::{ETag, EntityTag, Header, IfNoneMatch, ACCEPT, CONTENT_TYPE};
+use actix_web::web::{Bytes, Data};
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use bstr::ByteSlice as _;
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
 use deserr::Deserr;
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use index_scheduler::{IndexScheduler, RoFeatures, TaskId};
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
@@ -20,16 +21,20 @@ use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::update::IndexDocumentsMethod;
 use meilisearch_types::milli::vector::parsed_vectors::ExplicitVectors;
 use meilisearch_types::milli::DocumentId;
+use meilisearch_types::serde_cs::vec::CS;
 use meilisearch_types::star_or::OptionStarOrList;
 use meilisearch_types::tasks::KindWithContent;
 use meilisearch_types::{milli, Document, Index};
 use mime::Mime;
 use once_cell::sync::Lazy;
+use roaring::RoaringBitmap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use siphasher::sip::SipHasher13;
 use tempfile::tempfile;
 use tokio::fs::File;
 use tokio::io::{AsyncSeekExt, AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
 use tracing::debug;
 use utoipa::{IntoParams, OpenApi, ToSchema};
 
@@ -50,6 +55,20 @@ static ACCEPTED_CONTENT_TYPE: Lazy<Vec<String>> = Lazy::new(|| {
     vec!["application/json".to_string(), "application/x-ndjson".to_string(), "text/csv".to_string()]
 });
 
+/// The number of NDJSON lines that can be waiting to be sent to the client
+/// while streaming documents.
+const NDJSON_STREAM_CHANNEL_CAPACITY: usize = 100;
+
+/// Returns `true` if the client asked for the documents to be streamed as NDJSON.
+fn accepts_ndjson(req: &HttpRequest) -> bool {
+    req.headers()
+        .get_all(ACCEPT)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|media_type| media_type.split(';').next())
+        .any(|media_type| media_type.trim() == "application/x-ndjson")
+}
+
 /// Extracts the mime type from the content type and return
 /// a meilisearch error if anything bad happen.
 fn extract_mime_type(req: &HttpRequest) -> Result<Option<Mime>, MeilisearchHttpError> {
@@ -116,6 +135,12 @@ pub struct GetDocument {
     #[param(value_type = Option<Vec<String>>)]
     #[schema(value_type = Option<Vec<String>>)]
     fields: OptionStarOrList<String>,
+    /// Fields to remove from the document once `fields` has been applied. Nested fields can be
+    /// excluded with the dot notation, e.g. `author.email`.
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentFields>)]
+    #[param(value_type = Option<Vec<String>>)]
+    #[schema(value_type = Option<Vec<String>>)]
+    exclude_fields: Option<CS<String>>,
     #[deserr(default, error = DeserrQueryParamError<InvalidDocumentRetrieveVectors>)]
     #[param(value_type = Option<bool>)]
     #[schema(value_type = Option<bool>)]
@@ -197,6 +222,8 @@ impl<Method: AggregateMethod> Aggregate for DocumentsFetchAggregator<Method> {
 /// Get one document
 ///
 /// Get one document from its primary key.
+/// The response carries an `ETag` that can be sent back in an `If-None-Match` header to avoid
+/// downloading the document again if it did not change.
 #[utoipa::path(
     get,
     path = "{indexUid}/documents/{documentId}",
@@ -217,6 +244,7 @@ impl<Method: AggregateMethod> Aggregate for DocumentsFetchAggregator<Method> {
                 "release_date": 725846400
             }
         )),
+        (status = 304, description = "The document did not change since the `ETag` sent in the `If-None-Match` header"),
         (status = 404, description = "Index not found", body = ResponseError, content_type = "application/json", example = json!(
             {
                 "message": "Index `movies` not found.",
@@ -254,8 +282,10 @@ pub async fn get_document(
     debug!(parameters = ?params, "Get document");
     let index_uid = IndexUid::try_from(index_uid)?;
 
-    let GetDocument { fields, retrieve_vectors: param_retrieve_vectors } = params.into_inner();
+    let GetDocument { fields, exclude_fields, retrieve_vectors: param_retrieve_vectors } =
+        params.into_inner();
     let attributes_to_retrieve = fields.merge_star_and_none();
+    let exclude_fields = exclude_fields.map(CS::into_inner);
 
     let features = index_scheduler.features();
     let retrieve_vectors = RetrieveVectors::new(param_retrieve_vectors.0, features)?;
@@ -272,11 +302,36 @@ pub async fn get_document(
         &req,
     );
 
+    // The embeddings are not stored in the document and can change without it,
+    // so we cannot tag the document when they are retrieved.
+    let etag_query = (retrieve_vectors != RetrieveVectors::Retrieve).then(|| req.query_string());
+    let if_none_match = IfNoneMatch::parse(&req).ok();
+
     let index = index_scheduler.index(&index_uid)?;
-    let document =
-        retrieve_document(&index, &document_id, attributes_to_retrieve, retrieve_vectors)?;
-    debug!(returns = ?document, "Get document");
-    Ok(HttpResponse::Ok().json(document))
+    let document = retrieve_document(
+        &index,
+        &document_id,
+        attributes_to_retrieve,
+        exclude_fields,
+        retrieve_vectors,
+        etag_query,
+        if_none_match.as_ref(),
+    )?;
+
+    match document {
+        RetrievedDocument::NotModified(etag) => {
+            debug!(returns = ?etag, "Get document not modified");
+            Ok(HttpResponse::NotModified().insert_header(ETag(etag)).finish())
+        }
+        RetrievedDocument::Document(document, etag) => {
+            debug!(returns = ?document, "Get document");
+            let mut response = HttpResponse::Ok();
+            if let Some(etag) = etag {
+                response.insert_header(ETag(etag));
+            }
+            Ok(response.json(document))
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -385,6 +440,10 @@ pub struct BrowseQueryGet {
     #[param(default, value_type = Option<Vec<String>>)]
     #[deserr(default, error = DeserrQueryParamError<InvalidDocumentFields>)]
     fields: OptionStarOrList<String>,
+    /// Fields to remove from the documents once `fields` has been applied.
+    #[param(default, value_type = Option<Vec<String>>)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentFields>)]
+    exclude_fields: Option<CS<String>>,
     #[param(default, value_type = Option<bool>)]
     #[deserr(default, error = DeserrQueryParamError<InvalidDocumentRetrieveVectors>)]
     retrieve_vectors: Param<bool>,
@@ -406,6 +465,10 @@ pub struct BrowseQuery {
     #[schema(example = json!(["title, description"]))]
     #[deserr(default, error = DeserrJsonError<InvalidDocumentFields>)]
     fields: Option<Vec<String>>,
+    /// Fields to remove from the documents once `fields` has been applied.
+    #[schema(example = json!(["author.email"]))]
+    #[deserr(default, error = DeserrJsonError<InvalidDocumentFields>)]
+    exclude_fields: Option<Vec<String>>,
     #[schema(default, example = true)]
     #[deserr(default, error = DeserrJsonError<InvalidDocumentRetrieveVectors>)]
     retrieve_vectors: bool,
@@ -416,7 +479,7 @@ pub struct BrowseQuery {
 
 /// Get documents with POST
 ///
-/// Get a set of documents.
+/// Get a set of documents. Send `Accept: application/x-ndjson` to stream them one per line instead.
 #[utoipa::path(
     post,
     path = "{indexUid}/documents/fetch",
@@ -486,12 +549,12 @@ pub async fn documents_by_query_post(
         &req,
     );
 
-    documents_by_query(&index_scheduler, index_uid, body)
+    documents_by_query(&index_scheduler, index_uid, body, accepts_ndjson(&req))
 }
 
 /// Get documents
 ///
-/// Get documents by batches.
+/// Get documents by batches. Send `Accept: application/x-ndjson` to stream them one per line instead.
 #[utoipa::path(
     get,
     path = "{indexUid}/documents",
@@ -552,7 +615,8 @@ pub async fn get_documents(
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?params, "Get documents GET");
 
-    let BrowseQueryGet { limit, offset, fields, retrieve_vectors, filter } = params.into_inner();
+    let BrowseQueryGet { limit, offset, fields, exclude_fields, retrieve_vectors, filter } =
+        params.into_inner();
 
     let filter = match filter {
         Some(f) => match serde_json::from_str(&f) {
@@ -566,6 +630,7 @@ pub async fn get_documents(
         offset: offset.0,
         limit: limit.0,
         fields: fields.merge_star_and_none(),
+        exclude_fields: exclude_fields.map(CS::into_inner),
         retrieve_vectors: retrieve_vectors.0,
         filter,
     };
@@ -582,27 +647,45 @@ pub async fn get_documents(
         &req,
     );
 
-    documents_by_query(&index_scheduler, index_uid, query)
+    documents_by_query(&index_scheduler, index_uid, query, accepts_ndjson(&req))
 }
 
 fn documents_by_query(
     index_scheduler: &IndexScheduler,
     index_uid: web::Path<String>,
     query: BrowseQuery,
+    ndjson: bool,
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
-    let BrowseQuery { offset, limit, fields, retrieve_vectors, filter } = query;
+    let BrowseQuery { offset, limit, fields, exclude_fields, retrieve_vectors, filter } = query;
 
     let features = index_scheduler.features();
     let retrieve_vectors = RetrieveVectors::new(retrieve_vectors, features)?;
 
     let index = index_scheduler.index(&index_uid)?;
+    if ndjson {
+        let stream = stream_documents(
+            index,
+            offset,
+            limit,
+            filter,
+            fields,
+            exclude_fields,
+            retrieve_vectors,
+            index_scheduler.features(),
+        )?;
+
+        debug!("Streaming documents as NDJSON");
+        return Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(stream));
+    }
+
     let (total, documents) = retrieve_documents(
         &index,
         offset,
         limit,
         filter,
         fields,
+        exclude_fields,
         retrieve_vectors,
         index_scheduler.features(),
     )?;
@@ -626,6 +709,12 @@ pub struct UpdateDocumentsQuery {
     #[param(value_type = char, default = ",", example = ";")]
     #[deserr(default, try_from(char) = from_char_csv_delimiter -> DeserrQueryParamError<InvalidDocumentCsvDelimiter>, error = DeserrQueryParamError<InvalidDocumentCsvDelimiter>)]
     pub csv_delimiter: Option<u8>,
+    /// Skip the documents with a missing or invalid identifier instead of failing the whole task.
+    /// The skipped documents are reported in the details of the task.
+    #[param(default, value_type = Option<bool>)]
+    #[serde(default)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentSkipMalformedDocuments>)]
+    pub skip_malformed_documents: Param<bool>,
 }
 
 fn from_char_csv_delimiter(
@@ -770,6 +859,7 @@ pub async fn replace_documents(
         uid,
         dry_run,
         allow_index_creation,
+        params.skip_malformed_documents.0,
     )
     .await?;
     debug!(returns = ?task, "Replace documents");
@@ -871,6 +961,7 @@ pub async fn update_documents(
         uid,
         dry_run,
         allow_index_creation,
+        params.skip_malformed_documents.0,
     )
     .await?;
     debug!(returns = ?task, "Update documents");
@@ -890,6 +981,7 @@ async fn document_addition(
     task_id: Option<TaskId>,
     dry_run: bool,
     allow_index_creation: bool,
+    skip_malformed_documents: bool,
 ) -> Result<SummarizedTaskView, MeilisearchHttpError> {
     let format = match (
         mime_type.as_ref().map(|m| (m.type_().as_str(), m.subtype().as_str())),
@@ -1005,6 +1097,7 @@ async fn document_addition(
         documents_count,
         primary_key,
         allow_index_creation,
+        skip_malformed_documents,
         index_uid: index_uid.to_string(),
     };
 
@@ -1454,33 +1547,19 @@ fn some_documents<'a, 't: 'a>(
     }))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn retrieve_documents<S: AsRef<str>>(
     index: &Index,
     offset: usize,
     limit: usize,
     filter: Option<Value>,
     attributes_to_retrieve: Option<Vec<S>>,
+    exclude_fields: Option<Vec<String>>,
     retrieve_vectors: RetrieveVectors,
     features: RoFeatures,
 ) -> Result<(u64, Vec<Document>), ResponseError> {
     let rtxn = index.read_txn()?;
-    let filter = &filter;
-    let filter = if let Some(filter) = filter {
-        parse_filter(filter, Code::InvalidDocumentFilter, features)?
-    } else {
-        None
-    };
-
-    let candidates = if let Some(filter) = filter {
-        filter.evaluate(&rtxn, index).map_err(|err| match err {
-            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
-                ResponseError::from_msg(err.to_string(), Code::InvalidDocumentFilter)
-            }
-            e => e.into(),
-        })?
-    } else {
-        index.documents_ids(&rtxn)?
-    };
+    let candidates = filtered_documents_ids(index, &rtxn, filter, features)?;
 
     let (it, number_of_documents) = {
         let number_of_documents = candidates.len();
@@ -1497,27 +1576,157 @@ fn retrieve_documents<S: AsRef<str>>(
 
     let documents: Vec<_> = it
         .map(|document| {
-            Ok(match &attributes_to_retrieve {
-                Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
-                    &document?,
-                    attributes_to_retrieve.iter().map(|s| s.as_ref()).chain(
-                        (retrieve_vectors == RetrieveVectors::Retrieve).then_some("_vectors"),
-                    ),
-                ),
-                None => document?,
-            })
+            select_document_fields(
+                document?,
+                attributes_to_retrieve.as_deref(),
+                exclude_fields.as_deref(),
+                retrieve_vectors,
+            )
         })
         .collect::<Result<_, ResponseError>>()?;
 
     Ok((number_of_documents, documents))
 }
 
+/// Same as [`retrieve_documents`] but streams the documents as NDJSON, one document per line,
+/// instead of collecting all of them in memory.
+///
+/// The filter is evaluated before returning so that its errors are reported with the
+/// right status code; errors encountered while streaming terminate the stream.
+#[allow(clippy::too_many_arguments)]
+fn stream_documents(
+    index: Index,
+    offset: usize,
+    limit: usize,
+    filter: Option<Value>,
+    attributes_to_retrieve: Option<Vec<String>>,
+    exclude_fields: Option<Vec<String>>,
+    retrieve_vectors: RetrieveVectors,
+    features: RoFeatures,
+) -> Result<impl Stream<Item = Result<Bytes, ResponseError>>, ResponseError> {
+    let rtxn = index.static_read_txn()?;
+    let candidates = filtered_documents_ids(&index, &rtxn, filter, features)?;
+
+    let (sender, receiver) = mpsc::channel(NDJSON_STREAM_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        let result = (|| -> Result<(), ResponseError> {
+            let documents = some_documents(
+                &index,
+                &rtxn,
+                candidates.into_iter().skip(offset).take(limit),
+                retrieve_vectors,
+            )?;
+            for document in documents {
+                let document = select_document_fields(
+                    document?,
+                    attributes_to_retrieve.as_deref(),
+                    exclude_fields.as_deref(),
+                    retrieve_vectors,
+                )?;
+                let mut line = serde_json::to_vec(&document).map_err(MeilisearchHttpError::from)?;
+                line.push(b'\n');
+                if sender.blocking_send(Ok(Bytes::from(line))).is_err() {
+                    // the client went away, no need to read the remaining documents
+                    return Ok(());
+                }
+            }
+            Ok(())
+        })();
+
+        if let Err(error) = result {
+            let _ = sender.blocking_send(Err(error));
+        }
+    });
+
+    Ok(futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|line| (line, receiver))
+    }))
+}
+
+/// Returns the ids of the documents matching the filter, or all the documents ids if there is none.
+fn filtered_documents_ids(
+    index: &Index,
+    rtxn: &RoTxn,
+    filter: Option<Value>,
+    features: RoFeatures,
+) -> Result<RoaringBitmap, ResponseError> {
+    let filter = &filter;
+    let filter = if let Some(filter) = filter {
+        parse_filter(filter, Code::InvalidDocumentFilter, features)?
+    } else {
+        None
+    };
+
+    let candidates = if let Some(filter) = filter {
+        filter.evaluate(rtxn, index).map_err(|err| match err {
+            milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
+                ResponseError::from_msg(err.to_string(), Code::InvalidDocumentFilter)
+            }
+            e => e.into(),
+        })?
+    } else {
+        index.documents_ids(rtxn)?
+    };
+
+    Ok(candidates)
+}
+
+/// Only keeps the `attributes_to_retrieve` of the document, then removes its `exclude_fields`.
+fn select_document_fields<S: AsRef<str>>(
+    document: Document,
+    attributes_to_retrieve: Option<&[S]>,
+    exclude_fields: Option<&[String]>,
+    retrieve_vectors: RetrieveVectors,
+) -> Result<Document, ResponseError> {
+    let mut document = match attributes_to_retrieve {
+        Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
+            &document,
+            attributes_to_retrieve
+                .iter()
+                .map(|s| s.as_ref())
+                .chain((retrieve_vectors == RetrieveVectors::Retrieve).then_some("_vectors")),
+        ),
+        None => document,
+    };
+
+    if let Some(exclude_fields) = exclude_fields {
+        permissive_json_pointer::remove_values(
+            &mut document,
+            exclude_fields.iter().map(String::as_str),
+        );
+    }
+
+    Ok(document)
+}
+
+enum RetrievedDocument {
+    /// The document, along with its `ETag` if it can be cached.
+    Document(Document, Option<EntityTag>),
+    /// The client already has the current version of the document.
+    NotModified(EntityTag),
+}
+
+/// Computes a strong `ETag` from the raw bytes of a stored document and the query string
+/// of the request, as the query parameters change the representation of the document.
+fn document_etag(document: &[u8], query: &str) -> EntityTag {
+    let mut hasher = SipHasher13::new();
+    hasher.write(document);
+    hasher.write(query.as_bytes());
+    EntityTag::new_strong(format!("{:016x}", hasher.finish()))
+}
+
+/// Retrieves a document, or skips building it entirely if its `ETag` matches `if_none_match`.
+///
+/// No `ETag` is computed when `etag_query` is `None`.
 fn retrieve_document<S: AsRef<str>>(
     index: &Index,
     doc_id: &str,
     attributes_to_retrieve: Option<Vec<S>>,
+    exclude_fields: Option<Vec<String>>,
     retrieve_vectors: RetrieveVectors,
-) -> Result<Document, ResponseError> {
+    etag_query: Option<&str>,
+    if_none_match: Option<&IfNoneMatch>,
+) -> Result<RetrievedDocument, ResponseError> {
     let txn = index.read_txn()?;
 
     let internal_id = index
@@ -1525,20 +1734,31 @@ fn retrieve_document<S: AsRef<str>>(
         .get(&txn, doc_id)?
         .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))?;
 
+    let etag = match etag_query {
+        Some(query) => Some(document_etag(index.document(&txn, internal_id)?.as_bytes(), query)),
+        None => None,
+    };
+
+    if let (Some(etag), Some(if_none_match)) = (&etag, if_none_match) {
+        let not_modified = match if_none_match {
+            IfNoneMatch::Any => true,
+            IfNoneMatch::Items(tags) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        };
+        if not_modified {
+            return Ok(RetrievedDocument::NotModified(etag.clone()));
+        }
+    }
+
     let document = some_documents(index, &txn, Some(internal_id), retrieve_vectors)?
         .next()
         .ok_or_else(|| MeilisearchHttpError::DocumentNotFound(doc_id.to_string()))??;
 
-    let document = match &attributes_to_retrieve {
-        Some(attributes_to_retrieve) => permissive_json_pointer::select_values(
-            &document,
-            attributes_to_retrieve
-                .iter()
-                .map(|s| s.as_ref())
-                .chain((retrieve_vectors == RetrieveVectors::Retrieve).then_some("_vectors")),
-        ),
-        None => document,
-    };
+    let document = select_document_fields(
+        document,
+        attributes_to_retrieve.as_deref(),
+        exclude_fields.as_deref(),
+        retrieve_vectors,
+    )?;
 
-    Ok(document)
+    Ok(RetrievedDocument::Document(document, etag))
 }
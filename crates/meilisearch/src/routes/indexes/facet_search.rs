@@ -179,7 +179,7 @@ impl Aggregate for FacetSearchAggregator {
     post,
     path = "{indexUid}/facet-search",
     tag = "Facet Search",
-    security(("Bearer" = ["search", "*"])),
+    security(("Bearer" = ["search.facets", "search", "*"])),
     params(("indexUid", example = "movies", description = "Index Unique Identifier", nullable = false)),
     request_body = FacetSearchQuery,
     responses(
@@ -227,7 +227,7 @@ impl Aggregate for FacetSearchAggregator {
     )
 )]
 pub async fn search(
-    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    index_scheduler: GuardedData<ActionPolicy<{ actions::FACET_SEARCH }>, Data<IndexScheduler>>,
     search_queue: Data<SearchQueue>,
     index_uid: web::Path<String>,
     params: AwebJson<FacetSearchQuery, DeserrJsonError>,
@@ -246,8 +246,11 @@ pub async fn search(
     let locales = query.locales.clone().map(|l| l.into_iter().map(Into::into).collect());
     let mut search_query = SearchQuery::from(query);
 
-    // Tenant token search_rules.
-    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+    // Tenant token search_rules, of the index the alias points to.
+    let resolved_index_uid = index_scheduler.resolve_alias(&index_uid)?;
+    if let Some(search_rules) =
+        index_scheduler.filters().get_index_search_rules(&resolved_index_uid)
+    {
         add_search_rules(&mut search_query.filter, search_rules);
     }
 
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 
 use actix_web::web::Data;
@@ -47,7 +47,15 @@ mod similar_analytics;
         (path = "/", api = similar::SimilarApi),
         (path = "/", api = settings::SettingsApi),
     ),
-    paths(list_indexes, create_index, get_index, update_index, delete_index, get_index_stats),
+    paths(
+        list_indexes,
+        create_index,
+        get_index,
+        update_index,
+        delete_index,
+        compact_index,
+        get_index_stats
+    ),
     tags(
         (
             name = "Indexes",
@@ -73,6 +81,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route(web::delete().to(SeqHandler(delete_index))),
             )
             .service(web::resource("/stats").route(web::get().to(SeqHandler(get_index_stats))))
+            .service(web::resource("/compact").route(web::post().to(SeqHandler(compact_index))))
             .service(web::scope("/documents").configure(documents::configure))
             .service(web::scope("/search").configure(search::configure))
             .service(web::scope("/facet-search").configure(facet_search::configure))
@@ -488,6 +497,55 @@ pub async fn delete_index(
     Ok(HttpResponse::Accepted().json(task))
 }
 
+/// Compact index
+///
+/// Rewrite the databases of an index without the space left by the deleted documents.
+/// The index cannot be searched while it is being compacted.
+#[utoipa::path(
+    post,
+    path = "/{indexUid}/compact",
+    tag = "Indexes",
+    security(("Bearer" = ["indexes.update", "indexes.*", "*"])),
+    params(("indexUid", example = "movies", description = "Index Unique Identifier", nullable = false)),
+    responses(
+        (status = ACCEPTED, description = "Task successfully enqueued", body = SummarizedTaskView, content_type = "application/json", example = json!(
+            {
+                "taskUid": 0,
+                "indexUid": "movies",
+                "status": "enqueued",
+                "type": "indexCompaction",
+                "enqueuedAt": "2021-01-01T09:39:00.000000Z"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn compact_index(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let task = KindWithContent::IndexCompaction { index_uid: index_uid.into_inner() };
+    let uid = get_task_id(&req, &opt)?;
+    let dry_run = is_dry_run(&req, &opt)?;
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
+            .await??
+            .into();
+    debug!(returns = ?task, "Compact index");
+
+    Ok(HttpResponse::Accepted().json(task))
+}
+
 /// Stats of an `Index`, as known to the `stats` route.
 #[derive(Serialize, Debug, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -499,6 +557,17 @@ pub struct IndexStats {
     /// Association of every field name with the number of times it occurs in the documents.
     #[schema(value_type = HashMap<String, u64>)]
     pub field_distribution: FieldDistribution,
+    /// The smallest and biggest values of every filterable or sortable field containing numbers.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[schema(value_type = HashMap<String, NumericFieldBounds>)]
+    pub numeric_field_bounds: BTreeMap<String, NumericFieldBounds>,
+}
+
+/// The smallest and biggest numbers of a field, as known to the `stats` route.
+#[derive(Serialize, Debug, Clone, Copy, ToSchema)]
+pub struct NumericFieldBounds {
+    pub min: f64,
+    pub max: f64,
 }
 
 impl From<index_scheduler::IndexStats> for IndexStats {
@@ -507,6 +576,12 @@ impl From<index_scheduler::IndexStats> for IndexStats {
             number_of_documents: stats.inner_stats.number_of_documents,
             is_indexing: stats.is_indexing,
             field_distribution: stats.inner_stats.field_distribution,
+            numeric_field_bounds: stats
+                .inner_stats
+                .numeric_field_bounds
+                .into_iter()
+                .map(|(field, (min, max))| (field, NumericFieldBounds { min, max }))
+                .collect(),
         }
     }
 }
@@ -328,8 +328,11 @@ pub async fn search_with_url_query(
 
     let mut query: SearchQuery = params.into_inner().try_into()?;
 
-    // Tenant token search_rules.
-    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+    // Tenant token search_rules, of the index the alias points to.
+    let resolved_index_uid = index_scheduler.resolve_alias(&index_uid)?;
+    if let Some(search_rules) =
+        index_scheduler.filters().get_index_search_rules(&resolved_index_uid)
+    {
         add_search_rules(&mut query.filter, search_rules);
     }
 
@@ -435,8 +438,11 @@ pub async fn search_with_post(
     let mut query = params.into_inner();
     debug!(parameters = ?query, "Search post");
 
-    // Tenant token search_rules.
-    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+    // Tenant token search_rules, of the index the alias points to.
+    let resolved_index_uid = index_scheduler.resolve_alias(&index_uid)?;
+    if let Some(search_rules) =
+        index_scheduler.filters().get_index_search_rules(&resolved_index_uid)
+    {
         add_search_rules(&mut query.filter, search_rules);
     }
 
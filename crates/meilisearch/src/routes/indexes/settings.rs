@@ -503,6 +503,28 @@ make_setting_routes!(
         camelcase_attr: "prefixSearch",
         analytics: PrefixSearchAnalytics
     },
+    {
+        route: "/prefix-disabled-attributes",
+        update_verb: put,
+        value_type: std::collections::BTreeSet<String>,
+        err_type: meilisearch_types::deserr::DeserrJsonError<
+            meilisearch_types::error::deserr_codes::InvalidSettingsPrefixDisabledAttributes,
+        >,
+        attr: prefix_disabled_attributes,
+        camelcase_attr: "prefixDisabledAttributes",
+        analytics: PrefixDisabledAttributesAnalytics
+    },
+    {
+        route: "/document-ttl",
+        update_verb: put,
+        value_type: meilisearch_types::settings::DocumentTtlSettings,
+        err_type: meilisearch_types::deserr::DeserrJsonError<
+            meilisearch_types::error::deserr_codes::InvalidSettingsDocumentTtl,
+        >,
+        attr: document_ttl,
+        camelcase_attr: "documentTtl",
+        analytics: DocumentTtlAnalytics
+    },
 );
 
 #[utoipa::path(
@@ -592,6 +614,10 @@ pub async fn update_all(
             ),
             facet_search: FacetSearchAnalytics::new(new_settings.facet_search.as_ref().set()),
             prefix_search: PrefixSearchAnalytics::new(new_settings.prefix_search.as_ref().set()),
+            prefix_disabled_attributes: PrefixDisabledAttributesAnalytics::new(
+                new_settings.prefix_disabled_attributes.as_ref().set(),
+            ),
+            document_ttl: DocumentTtlAnalytics::new(new_settings.document_ttl.as_ref().set()),
         },
         &req,
     );
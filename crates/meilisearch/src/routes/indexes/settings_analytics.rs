@@ -9,8 +9,8 @@ use meilisearch_types::facet_values_sort::FacetValuesSort;
 use meilisearch_types::locales::{Locale, LocalizedAttributesRuleView};
 use meilisearch_types::milli::update::Setting;
 use meilisearch_types::settings::{
-    FacetingSettings, PaginationSettings, PrefixSearchSettings, ProximityPrecisionView,
-    RankingRuleView, SettingEmbeddingSettings, TypoSettings,
+    DocumentTtlSettings, FacetingSettings, PaginationSettings, PrefixSearchSettings,
+    ProximityPrecisionView, RankingRuleView, SettingEmbeddingSettings, TypoSettings,
 };
 use serde::Serialize;
 
@@ -38,6 +38,8 @@ pub struct SettingsAnalytics {
     pub non_separator_tokens: NonSeparatorTokensAnalytics,
     pub facet_search: FacetSearchAnalytics,
     pub prefix_search: PrefixSearchAnalytics,
+    pub prefix_disabled_attributes: PrefixDisabledAttributesAnalytics,
+    pub document_ttl: DocumentTtlAnalytics,
 }
 
 impl Aggregate for SettingsAnalytics {
@@ -193,6 +195,16 @@ impl Aggregate for SettingsAnalytics {
                 set: new.prefix_search.set | self.prefix_search.set,
                 value: new.prefix_search.value.or(self.prefix_search.value),
             },
+            prefix_disabled_attributes: PrefixDisabledAttributesAnalytics {
+                total: new
+                    .prefix_disabled_attributes
+                    .total
+                    .or(self.prefix_disabled_attributes.total),
+            },
+            document_ttl: DocumentTtlAnalytics {
+                set: new.document_ttl.set | self.document_ttl.set,
+                ttl_seconds: new.document_ttl.ttl_seconds.or(self.document_ttl.ttl_seconds),
+            },
         })
     }
 
@@ -662,3 +674,34 @@ impl PrefixSearchAnalytics {
         SettingsAnalytics { prefix_search: self, ..Default::default() }
     }
 }
+
+#[derive(Serialize, Default)]
+pub struct PrefixDisabledAttributesAnalytics {
+    pub total: Option<usize>,
+}
+
+impl PrefixDisabledAttributesAnalytics {
+    pub fn new(attributes: Option<&BTreeSet<String>>) -> Self {
+        Self { total: attributes.as_ref().map(|attributes| attributes.len()) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { prefix_disabled_attributes: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct DocumentTtlAnalytics {
+    pub set: bool,
+    pub ttl_seconds: Option<u64>,
+}
+
+impl DocumentTtlAnalytics {
+    pub fn new(settings: Option<&DocumentTtlSettings>) -> Self {
+        Self { set: settings.is_some(), ttl_seconds: settings.map(|ttl| ttl.ttl_seconds) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { document_ttl: self, ..Default::default() }
+    }
+}
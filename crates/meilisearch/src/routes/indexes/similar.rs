@@ -222,8 +222,11 @@ async fn similar(
 
     let retrieve_vectors = RetrieveVectors::new(query.retrieve_vectors, features)?;
 
-    // Tenant token search_rules.
-    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+    // Tenant token search_rules, of the index the alias points to.
+    let resolved_index_uid = index_scheduler.resolve_alias(&index_uid)?;
+    if let Some(search_rules) =
+        index_scheduler.filters().get_index_search_rules(&resolved_index_uid)
+    {
         add_search_rules(&mut query.filter, search_rules);
     }
 
@@ -41,27 +41,27 @@ pub fn configure(config: &mut web::ServiceConfig) {
 meilisearch_db_size_bytes 1130496
 # HELP meilisearch_http_requests_total Meilisearch HTTP requests total
 # TYPE meilisearch_http_requests_total counter
-meilisearch_http_requests_total{method="GET",path="/metrics",status="400"} 1
-meilisearch_http_requests_total{method="PATCH",path="/experimental-features",status="200"} 1
+meilisearch_http_requests_total{index_uid="-",method="GET",path="/metrics",status="400"} 1
+meilisearch_http_requests_total{index_uid="-",method="PATCH",path="/experimental-features",status="200"} 1
 # HELP meilisearch_http_response_time_seconds Meilisearch HTTP response times
 # TYPE meilisearch_http_response_time_seconds histogram
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.005"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.01"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.025"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.05"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.075"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.1"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.25"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.5"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="0.75"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="1"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="2.5"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="5"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="7.5"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="10"} 0
-meilisearch_http_response_time_seconds_bucket{method="GET",path="/metrics",le="+Inf"} 0
-meilisearch_http_response_time_seconds_sum{method="GET",path="/metrics"} 0
-meilisearch_http_response_time_seconds_count{method="GET",path="/metrics"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.005"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.01"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.025"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.05"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.075"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.1"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.25"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.5"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="0.75"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="1"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="2.5"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="5"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="7.5"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="10"} 0
+meilisearch_http_response_time_seconds_bucket{index_uid="-",method="GET",path="/metrics",le="+Inf"} 0
+meilisearch_http_response_time_seconds_sum{index_uid="-",method="GET",path="/metrics"} 0
+meilisearch_http_response_time_seconds_count{index_uid="-",method="GET",path="/metrics"} 0
 # HELP meilisearch_index_count Meilisearch Index Count
 # TYPE meilisearch_index_count gauge
 meilisearch_index_count 1
@@ -86,10 +86,12 @@ meilisearch_nb_tasks{kind="types",value="documentAdditionOrUpdate"} 9
 meilisearch_nb_tasks{kind="types",value="documentDeletion"} 0
 meilisearch_nb_tasks{kind="types",value="documentEdition"} 0
 meilisearch_nb_tasks{kind="types",value="dumpCreation"} 0
+meilisearch_nb_tasks{kind="types",value="indexCompaction"} 0
 meilisearch_nb_tasks{kind="types",value="indexCreation"} 0
 meilisearch_nb_tasks{kind="types",value="indexDeletion"} 8
 meilisearch_nb_tasks{kind="types",value="indexSwap"} 0
 meilisearch_nb_tasks{kind="types",value="indexUpdate"} 0
+meilisearch_nb_tasks{kind="types",value="multiIndexDocumentAddition"} 0
 meilisearch_nb_tasks{kind="types",value="settingsUpdate"} 22
 meilisearch_nb_tasks{kind="types",value="snapshotCreation"} 0
 meilisearch_nb_tasks{kind="types",value="taskCancelation"} 0
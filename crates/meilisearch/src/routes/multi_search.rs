@@ -161,14 +161,17 @@ pub async fn multi_search_with_post(
     // regardless of federation, check authorization and apply search rules
     let auth = 'check_authorization: {
         for (query_index, federated_query) in queries.iter_mut().enumerate() {
-            let index_uid = federated_query.index_uid.as_str();
-            // Check index from API key
-            if !index_scheduler.filters().is_index_authorized(index_uid) {
+            // Check the index the alias points to from API key
+            let index_uid = match index_scheduler.resolve_alias(&federated_query.index_uid) {
+                Ok(index_uid) => index_uid,
+                Err(err) => break 'check_authorization Err(err).with_index(query_index),
+            };
+            if !index_scheduler.filters().is_index_authorized(&index_uid) {
                 break 'check_authorization Err(AuthenticationError::InvalidToken)
                     .with_index(query_index);
             }
             // Apply search rules from tenant token
-            if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(index_uid)
+            if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid)
             {
                 add_search_rules(&mut federated_query.filter, search_rules);
             }
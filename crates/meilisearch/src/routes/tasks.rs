@@ -484,15 +484,15 @@ async fn delete_tasks(
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AllTasks {
     /// The list of tasks that matched the filter.
-    results: Vec<TaskView>,
+    pub(crate) results: Vec<TaskView>,
     /// Total number of browsable results using offset/limit parameters for the given resource.
-    total: u64,
+    pub(crate) total: u64,
     /// Limit given for the query. If limit is not provided as a query parameter, this parameter displays the default limit value.
-    limit: u32,
+    pub(crate) limit: u32,
     /// The first task uid returned.
-    from: Option<u32>,
+    pub(crate) from: Option<u32>,
     /// Represents the value to send in from to fetch the next slice of the results. The first item for the next slice starts at this exact number. When the returned value is null, it means that all the data have been browsed in the given order.
-    next: Option<u32>,
+    pub(crate) next: Option<u32>,
 }
 
 /// Get all tasks
@@ -914,7 +914,7 @@ mod tests {
             let err = deserr_query_params::<TaskDeletionOrCancelationQuery>(params).unwrap_err();
             snapshot!(meili_snap::json_string!(err), @r###"
             {
-              "message": "Invalid value in parameter `types`: `createIndex` is not a valid task type. Available types are `documentAdditionOrUpdate`, `documentEdition`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`.",
+              "message": "Invalid value in parameter `types`: `createIndex` is not a valid task type. Available types are `documentAdditionOrUpdate`, `documentEdition`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`, `indexCompaction`, `multiIndexDocumentAddition`.",
               "code": "invalid_task_types",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_task_types"
@@ -666,10 +666,18 @@ pub fn perform_federated_search(
                 let milli::SearchResult {
                     matching_words,
                     candidates: query_candidates,
+                    total_hits: _,
                     documents_ids,
                     document_scores,
                     degraded: query_degraded,
                     used_negative_operator: query_used_negative_operator,
+                    suggestions: _,
+                    had_typo_correction: _,
+                    matched_terms: _,
+                    total_documents: _,
+                    matches: _,
+                    facet_distribution: _,
+                    facet_distribution_exhaustive: _,
                 } = result;
 
                 candidates |= query_candidates;
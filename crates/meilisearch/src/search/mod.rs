@@ -939,9 +939,17 @@ pub fn perform_search(
             documents_ids,
             matching_words,
             candidates,
+            total_hits: _,
             document_scores,
             degraded,
             used_negative_operator,
+            suggestions: _,
+            had_typo_correction: _,
+            matched_terms: _,
+            total_documents: _,
+            matches: _,
+            facet_distribution: _,
+            facet_distribution_exhaustive: _,
         },
         semantic_hit_count,
     ) = search_from_kind(index_uid, search_kind, search)?;
@@ -1005,9 +1013,7 @@ pub fn perform_search(
     let hits_info = if is_finite_pagination {
         let hits_per_page = hits_per_page.unwrap_or_else(DEFAULT_SEARCH_LIMIT);
         // If hit_per_page is 0, then pages can't be computed and so we respond 0.
-        let total_pages = (number_of_hits + hits_per_page.saturating_sub(1))
-            .checked_div(hits_per_page)
-            .unwrap_or(0);
+        let total_pages = milli::Pagination::new(number_of_hits, 0, hits_per_page).total_pages;
 
         HitsInfo::Pagination {
             hits_per_page,
@@ -1548,9 +1554,17 @@ pub fn perform_similar(
         documents_ids,
         matching_words: _,
         candidates,
+        total_hits: _,
         document_scores,
         degraded: _,
         used_negative_operator: _,
+        suggestions: _,
+        had_typo_correction: _,
+        matched_terms: _,
+        total_documents: _,
+        matches: _,
+        facet_distribution: _,
+        facet_distribution_exhaustive: _,
     } = similar.execute().map_err(|err| match err {
         milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
             ResponseError::from_msg(err.to_string(), Code::InvalidSimilarFilter)
@@ -1666,7 +1680,7 @@ fn add_highlight_to_formatted_options(
     displayed_ids: &BTreeSet<FieldId>,
 ) {
     for attr in attr_to_highlight {
-        let new_format = FormatOptions { highlight: true, crop: None };
+        let new_format = FormatOptions { highlight: true, ..Default::default() };
 
         if attr == "*" {
             for id in displayed_ids {
@@ -1705,7 +1719,7 @@ fn add_crop_to_formatted_options(
                 formatted_options
                     .entry(*id)
                     .and_modify(|f| f.crop = Some(attr_len))
-                    .or_insert(FormatOptions { highlight: false, crop: Some(attr_len) });
+                    .or_insert(FormatOptions { crop: Some(attr_len), ..Default::default() });
             }
         }
 
@@ -1714,7 +1728,7 @@ fn add_crop_to_formatted_options(
                 formatted_options
                     .entry(id)
                     .and_modify(|f| f.crop = Some(attr_len))
-                    .or_insert(FormatOptions { highlight: false, crop: Some(attr_len) });
+                    .or_insert(FormatOptions { crop: Some(attr_len), ..Default::default() });
             }
         }
     }
@@ -1725,7 +1739,7 @@ fn add_non_formatted_ids_to_formatted_options(
     to_retrieve_ids: &BTreeSet<FieldId>,
 ) {
     for id in to_retrieve_ids {
-        formatted_options.entry(*id).or_insert(FormatOptions { highlight: false, crop: None });
+        formatted_options.entry(*id).or_insert(FormatOptions::default());
     }
 }
 
@@ -0,0 +1,204 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn search_through_alias() {
+    let server = Server::new().await;
+    let index = server.index("products_v1");
+    let (task, _) = index.add_documents(json!([{ "id": 1, "name": "old kefir" }]), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let index = server.index("products_v2");
+    let (task, _) = index.add_documents(json!([{ "id": 1, "name": "new kefir" }]), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.put_alias("products", json!({ "indexUid": "products_v1" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "alias": "products",
+      "indexUid": "products_v1"
+    }
+    "###);
+
+    let alias = server.index("products");
+    let (response, code) = alias.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "name": "old kefir"
+      }
+    ]
+    "###);
+
+    // re-pointing the alias makes it search in the new index.
+    let (_, code) = server.put_alias("products", json!({ "indexUid": "products_v2" })).await;
+    snapshot!(code, @"200 OK");
+    let (response, code) = alias.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "name": "new kefir"
+      }
+    ]
+    "###);
+
+    let (response, code) = server.list_aliases().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": [
+        {
+          "alias": "products",
+          "indexUid": "products_v2"
+        }
+      ]
+    }
+    "###);
+
+    // the documents sent to the alias are added to the index it points to.
+    let (task, _) = alias.add_documents(json!([{ "id": 2, "name": "kefir again" }]), None).await;
+    let task = server.wait_task(task.uid()).await.succeeded();
+    snapshot!(task["indexUid"], @r###""products_v2""###);
+
+    let (_, code) = server.delete_alias("products").await;
+    snapshot!(code, @"204 No Content");
+    let (response, code) = alias.search_post(json!({ "q": "kefir" })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""index_not_found""###);
+}
+
+#[actix_rt::test]
+async fn alias_and_index_cannot_share_a_name() {
+    let server = Server::new().await;
+    let index = server.index("products_v1");
+    let (task, _) = index.create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        server.put_alias("products_v1", json!({ "indexUid": "products_v1" })).await;
+    snapshot!(code, @"409 Conflict");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "`products_v1` cannot be used both as an index uid and as an alias.",
+      "code": "index_alias_conflict",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#index_alias_conflict"
+    }
+    "###);
+
+    let (response, code) = server.put_alias("products", json!({ "indexUid": "missing" })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""index_not_found""###);
+
+    let (_, code) = server.put_alias("products", json!({ "indexUid": "products_v1" })).await;
+    snapshot!(code, @"200 OK");
+    let (response, code) = server.create_index(json!({ "uid": "products" })).await;
+    snapshot!(code, @"409 Conflict");
+    snapshot!(response["code"], @r###""index_alias_conflict""###);
+
+    let (response, code) = server.delete_alias("missing").await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""alias_not_found""###);
+}
+
+#[actix_rt::test]
+async fn update_and_delete_index_through_alias() {
+    let server = Server::new().await;
+    let index = server.index("products_v1");
+    let (task, _) = index.create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (_, code) = server.put_alias("products", json!({ "indexUid": "products_v1" })).await;
+    snapshot!(code, @"200 OK");
+
+    let alias = server.index("products");
+    let (task, _) = alias.update(Some("sku")).await;
+    let task = server.wait_task(task.uid()).await.succeeded();
+    snapshot!(task["indexUid"], @r###""products_v1""###);
+    let (response, _) = index.get().await;
+    snapshot!(response["primaryKey"], @r###""sku""###);
+
+    // deleting the index through the alias deletes the alias too.
+    let (task, _) = alias.delete().await;
+    let task = server.wait_task(task.uid()).await.succeeded();
+    snapshot!(task["indexUid"], @r###""products_v1""###);
+    let (_, code) = index.get().await;
+    snapshot!(code, @"404 Not Found");
+    let (response, code) = server.list_aliases().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "results": []
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn api_keys_and_tenant_tokens_apply_to_the_aliased_index() {
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+    let index = server.index("products_v1");
+    let (task, _) = index
+        .add_documents(json!([{ "id": 1, "color": "blue" }, { "id": 2, "color": "red" }]), None)
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _) = index.update_settings_filterable_attributes(json!(["color"])).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (_, code) = server.put_alias("products", json!({ "indexUid": "products_v1" })).await;
+    snapshot!(code, @"200 OK");
+
+    // the tasks filtered by the alias are the tasks of the index it points to.
+    let (response, code) = server.tasks_filter("indexUids=products").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["total"], @"2");
+
+    let (alias_key, code) = server
+        .add_api_key(json!({ "indexes": ["products"], "actions": ["search"], "expiresAt": null }))
+        .await;
+    snapshot!(code, @"201 Created");
+    let (index_key, code) = server
+        .add_api_key(
+            json!({ "indexes": ["products_v1"], "actions": ["search"], "expiresAt": null }),
+        )
+        .await;
+    snapshot!(code, @"201 Created");
+
+    // a key scoped to the alias doesn't give access to the index it points to.
+    server.use_api_key(alias_key["key"].as_str().unwrap());
+    let (response, code) = server.index("products").search_post(json!({})).await;
+    snapshot!(code, @"403 Forbidden");
+    snapshot!(response["code"], @r###""invalid_api_key""###);
+
+    // a key scoped to the index can search through the alias.
+    server.use_api_key(index_key["key"].as_str().unwrap());
+    let (response, code) = server.index("products").search_post(json!({})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["estimatedTotalHits"], @"2");
+
+    // the search rules of the index are applied when searching through the alias.
+    let claims = json!({
+        "searchRules": { "products_v1": { "filter": "color = blue" } },
+        "apiKeyUid": index_key["uid"],
+    });
+    let key = index_key["key"].as_str().unwrap();
+    let token =
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(key.as_bytes())).unwrap();
+    server.use_api_key(token);
+    let (response, code) = server.index("products").search_post(json!({})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "color": "blue"
+      }
+    ]
+    "###);
+}
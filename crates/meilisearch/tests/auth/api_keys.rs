@@ -1,5 +1,7 @@
 use std::{thread, time};
 
+use ::time::format_description::well_known::Rfc3339;
+
 use crate::common::{Server, Value};
 use crate::json;
 
@@ -123,6 +125,36 @@ async fn add_valid_api_key_expired_at() {
     "###);
 }
 
+#[actix_rt::test]
+async fn add_valid_api_key_relative_expires_at() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let content = json!({
+        "indexes": ["products"],
+        "actions": ["search"],
+        "expiresAt": "+30d"
+    });
+
+    let (response, code) = server.add_api_key(content).await;
+    meili_snap::snapshot!(code, @"201 Created");
+    let expires_at = response["expiresAt"].as_str().unwrap();
+    let expires_at = ::time::OffsetDateTime::parse(expires_at, &Rfc3339).unwrap();
+    let expected = ::time::OffsetDateTime::now_utc() + ::time::Duration::days(30);
+    assert!((expected - expires_at).abs() < ::time::Duration::minutes(1), "{expires_at}");
+
+    for expires_at in ["+30", "+30y", "+d", "+0s"] {
+        let content = json!({
+            "indexes": ["products"],
+            "actions": ["search"],
+            "expiresAt": expires_at
+        });
+        let (response, code) = server.add_api_key(content).await;
+        assert_eq!(code, 400, "{expires_at}: {response:?}");
+        assert_eq!(response["code"], "invalid_api_key_expires_at");
+    }
+}
+
 #[actix_rt::test]
 async fn add_valid_api_key_no_description() {
     let mut server = Server::new_auth().await;
@@ -421,7 +453,7 @@ async fn error_add_api_key_invalid_parameters_actions() {
     meili_snap::snapshot!(code, @"400 Bad Request");
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r###"
     {
-      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`",
+      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `search.facets`",
       "code": "invalid_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_api_key_actions"
@@ -350,6 +350,18 @@ impl<State> Server<State> {
         self.service.post("/swap-indexes", value).await
     }
 
+    pub async fn list_aliases(&self) -> (Value, StatusCode) {
+        self.service.get("/aliases").await
+    }
+
+    pub async fn put_alias(&self, alias: &str, value: Value) -> (Value, StatusCode) {
+        self.service.put(format!("/aliases/{alias}"), value).await
+    }
+
+    pub async fn delete_alias(&self, alias: &str) -> (Value, StatusCode) {
+        self.service.delete(format!("/aliases/{alias}")).await
+    }
+
     pub async fn cancel_tasks(&self, value: &str) -> (Value, StatusCode) {
         self.service.post(format!("/tasks/cancel?{}", value), json!(null)).await
     }
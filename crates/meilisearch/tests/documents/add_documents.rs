@@ -1500,6 +1500,65 @@ async fn error_add_documents_missing_document_id() {
     "###);
 }
 
+#[actix_rt::test]
+async fn add_documents_skipping_malformed_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(Some("id")).await;
+    let documents = r#"[
+        { "id": 1, "doggo": "jean bob" },
+        { "id": "jorts & jean", "doggo": "jorts" },
+        { "doggo": "fifi" },
+        { "id": 4, "doggo": "kefir" }
+    ]"#;
+    let (task, code) = index
+        .raw_add_documents(
+            documents,
+            vec![("Content-Type", "application/json")],
+            "?skipMalformedDocuments=true",
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, code) = index.get_task(task.uid()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".duration" => "[duration]", ".enqueuedAt" => "[date]", ".startedAt" => "[date]", ".finishedAt" => "[date]" }),
+        @r###"
+    {
+      "uid": 1,
+      "batchUid": 1,
+      "indexUid": "test",
+      "status": "succeeded",
+      "type": "documentAdditionOrUpdate",
+      "canceledBy": null,
+      "details": {
+        "receivedDocuments": 4,
+        "indexedDocuments": 2,
+        "skippedDocuments": [
+          {
+            "index": 1,
+            "reason": "Document identifier `\"jorts & jean\"` is invalid. A document identifier can be of type integer or string, only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and underscores (_), and can not be more than 511 bytes."
+          },
+          {
+            "index": 2,
+            "reason": "Document doesn't have a `id` attribute: `{\"doggo\":\"fifi\"}`."
+          }
+        ],
+        "skippedDocumentsCount": 2
+      },
+      "error": null,
+      "duration": "[duration]",
+      "enqueuedAt": "[date]",
+      "startedAt": "[date]",
+      "finishedAt": "[date]"
+    }
+    "###);
+
+    let (response, code) = index.get_all_documents(Default::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["total"], @"2");
+}
+
 #[actix_rt::test]
 async fn error_document_field_limit_reached_in_one_document() {
     let server = Server::new().await;
@@ -1,4 +1,4 @@
-use actix_web::http::header::ACCEPT_ENCODING;
+use actix_web::http::header::{ACCEPT, ACCEPT_ENCODING, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
 use actix_web::test;
 use meili_snap::*;
 use urlencoding::encode as urlencode;
@@ -83,6 +83,115 @@ async fn get_document() {
     );
 }
 
+#[actix_rt::test]
+async fn get_document_with_excluded_fields() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let documents = json!([
+        {
+            "id": 0,
+            "title": "Hello",
+            "author": { "name": "Jane", "email": "jane@example.com" },
+        }
+    ]);
+    let (task, code) = index.add_documents(documents, None).await;
+    assert_eq!(code, 202);
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.get_document(0, Some(json!({ "excludeFields": ["author.email"] }))).await;
+    assert_eq!(code, 200);
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 0,
+      "title": "Hello",
+      "author": {
+        "name": "Jane"
+      }
+    }
+    "###);
+
+    // the exclusion is applied after the selection
+    let (response, code) = index
+        .get_document(
+            0,
+            Some(json!({ "fields": ["id", "author"], "excludeFields": ["author.email", "id"] })),
+        )
+        .await;
+    assert_eq!(code, 200);
+    snapshot!(json_string!(response), @r###"
+    {
+      "author": {
+        "name": "Jane"
+      }
+    }
+    "###);
+
+    let (response, code) = index
+        .get_document_by_filter(json!({ "fields": ["author"], "excludeFields": ["author.name"] }))
+        .await;
+    assert_eq!(code, 200);
+    snapshot!(json_string!(response["results"]), @r###"
+    [
+      {
+        "author": {
+          "email": "jane@example.com"
+        }
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn get_document_with_etag() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, code) = index.add_documents(json!([{ "id": 0, "title": "Hello" }]), None).await;
+    assert_eq!(code, 202);
+    index.wait_task(task.uid()).await.succeeded();
+
+    let app = server.init_web_app().await;
+    let url = format!("/indexes/{}/documents/0", urlencode(&index.uid));
+
+    let req = test::TestRequest::get().uri(&url).to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+    let etag = res.headers().get(ETAG).expect("Expecting an ETag").clone();
+
+    // the same document with the same parameters is not sent again
+    let req = test::TestRequest::get()
+        .uri(&url)
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 304);
+    assert_eq!(res.headers().get(ETAG), Some(&etag));
+
+    // different parameters produce a different representation
+    let req = test::TestRequest::get()
+        .uri(&format!("{url}?fields=title"))
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+
+    // once the document is modified the ETag changes
+    let (task, code) = index.update_documents(json!([{ "id": 0, "title": "World" }]), None).await;
+    assert_eq!(code, 202);
+    index.wait_task(task.uid()).await.succeeded();
+
+    let req = test::TestRequest::get()
+        .uri(&url)
+        .insert_header((IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+    assert_ne!(res.headers().get(ETAG), Some(&etag));
+    let bytes = test::read_body(res).await;
+    let document: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    snapshot!(document, @r###"{"id":0,"title":"World"}"###);
+}
+
 #[actix_rt::test]
 async fn error_get_unexisting_index_all_documents() {
     let index = shared_does_not_exists_index().await;
@@ -160,6 +269,33 @@ async fn get_all_documents_no_options_with_response_compression() {
     assert_eq!(arr.len(), 20);
 }
 
+#[actix_rt::test]
+async fn get_all_documents_as_ndjson() {
+    let index = shared_index_with_test_set().await;
+
+    let app = Server::new_shared().init_web_app().await;
+    let req = test::TestRequest::get()
+        .uri(&format!("/indexes/{}/documents?limit=30&offset=5&fields=id", urlencode(&index.uid)))
+        .insert_header((ACCEPT, "application/x-ndjson"))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), 200);
+    snapshot!(res.headers().get(CONTENT_TYPE).unwrap().to_str().unwrap(), @"application/x-ndjson");
+
+    let bytes = test::read_body(res).await;
+    let documents: Vec<_> = serde_json::Deserializer::from_slice(&bytes)
+        .into_iter::<serde_json::Value>()
+        .map(|document| document.expect("Expecting valid ndjson"))
+        .collect();
+
+    assert_eq!(documents.len(), 30);
+    assert_eq!(json!({ "id": 5 }), documents[0]);
+    assert_eq!(json!({ "id": 34 }), documents[29]);
+    assert_eq!(bytes.iter().filter(|&&b| b == b'\n').count(), 30);
+}
+
 #[actix_rt::test]
 async fn test_get_all_documents_limit() {
     let index = shared_index_with_test_set().await;
@@ -81,7 +81,9 @@ async fn import_dump_v1_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -246,7 +248,9 @@ async fn import_dump_v1_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -397,7 +401,9 @@ async fn import_dump_v1_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -534,7 +540,9 @@ async fn import_dump_v2_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -683,7 +691,9 @@ async fn import_dump_v2_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -831,7 +841,9 @@ async fn import_dump_v2_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -968,7 +980,9 @@ async fn import_dump_v3_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1117,7 +1131,9 @@ async fn import_dump_v3_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1265,7 +1281,9 @@ async fn import_dump_v3_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1402,7 +1420,9 @@ async fn import_dump_v4_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1551,7 +1571,9 @@ async fn import_dump_v4_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1699,7 +1721,9 @@ async fn import_dump_v4_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###
     );
@@ -1948,7 +1972,9 @@ async fn import_dump_v6_containing_experimental_features() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###);
 
@@ -2130,7 +2156,9 @@ async fn generate_and_import_dump_containing_vectors() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "###);
 
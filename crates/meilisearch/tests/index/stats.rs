@@ -1,3 +1,5 @@
+use meili_snap::{json_string, snapshot};
+
 use crate::common::Server;
 use crate::json;
 
@@ -45,6 +47,36 @@ async fn stats() {
     assert_eq!(response["fieldDistribution"]["age"], 1);
 }
 
+#[actix_rt::test]
+async fn stats_numeric_field_bounds() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) =
+        index.update_settings_filterable_attributes(json!(["price", "color"])).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "price": 12.5, "color": "red" },
+        { "id": 2, "price": -3, "color": "blue" },
+        { "id": 3, "price": 40, "color": "green" },
+        { "id": 4, "color": "red" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.stats().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["numericFieldBounds"]), @r###"
+    {
+      "price": {
+        "min": -3.0,
+        "max": 40.0
+      }
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn error_get_stats_unexisting_index() {
     let server = Server::new().await;
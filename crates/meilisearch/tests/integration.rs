@@ -1,3 +1,4 @@
+mod aliases;
 mod auth;
 mod batches;
 mod common;
@@ -7,6 +8,7 @@ mod dumps;
 mod features;
 mod index;
 mod logs;
+mod metrics;
 mod search;
 mod settings;
 mod similar;
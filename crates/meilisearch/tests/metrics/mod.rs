@@ -0,0 +1,82 @@
+use actix_web::test;
+use meilisearch::Opt;
+use tempfile::TempDir;
+
+use crate::common::{default_settings, Server};
+use crate::json;
+
+/// Returns the metrics in the prometheus text format, the test service only parses JSON.
+///
+/// The metrics are shared by all the servers of the process, the tests must only look at
+/// their own labels.
+async fn metrics(server: &Server) -> String {
+    let app = server.service.init_web_app().await;
+    let req = test::TestRequest::get().uri("/metrics").to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+    String::from_utf8(test::read_body(res).await.to_vec()).unwrap()
+}
+
+async fn server_with_metrics(dir: &TempDir) -> Server {
+    let options = Opt { experimental_enable_metrics: true, ..default_settings(dir.path()) };
+    Server::new_with_options(options).await.unwrap()
+}
+
+#[actix_rt::test]
+async fn requests_are_labelled_with_their_index() {
+    let dir = TempDir::new().unwrap();
+    let server = server_with_metrics(&dir).await;
+    let index = server.index("metrics_index_label");
+    let (task, code) = index.add_documents(json!([{ "id": 1 }]), None).await;
+    assert_eq!(code, 202);
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (_, code) = index.search_post(json!({ "q": "hello" })).await;
+    assert_eq!(code, 200);
+    // the requests on a missing index are not labelled with its uid
+    let (_, code) =
+        server.index("metrics_missing_index_label").search_post(json!({ "q": "hello" })).await;
+    assert_eq!(code, 404);
+
+    let metrics = metrics(&server).await;
+    assert!(metrics.contains(
+        r#"meilisearch_http_requests_total{index_uid="metrics_index_label",method="POST",path="/indexes/{index_uid}/search",status="200"} 1"#
+    ));
+    assert!(metrics.contains(
+        r#"meilisearch_http_response_time_seconds_count{index_uid="metrics_index_label",method="POST",path="/indexes/{index_uid}/search"} 1"#
+    ));
+    assert!(!metrics.contains("metrics_missing_index_label"));
+}
+
+/// Returns the value of the sample whose labels start with the given ones, zero if it is missing.
+fn sample(metrics: &str, name_and_labels: &str) -> f64 {
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(name_and_labels))
+        .map_or(0.0, |value| value.trim().parse().unwrap())
+}
+
+#[actix_rt::test]
+async fn request_sizes_are_recorded() {
+    let dir = TempDir::new().unwrap();
+    let server = server_with_metrics(&dir).await;
+    let bucket = |le: &str| {
+        format!(
+            r#"meilisearch_http_request_size_bytes_bucket{{method="POST",path="/indexes/{{index_uid}}/documents",le="{le}"}} "#
+        )
+    };
+    // The other tests send small bodies, only this one sends a body between 256KiB and 1MiB.
+    let large_bodies =
+        |metrics: &str| sample(metrics, &bucket("1048576")) - sample(metrics, &bucket("262144"));
+    let before = large_bodies(&metrics(&server).await);
+
+    let documents = json!([{ "id": 1, "content": "a".repeat(300 * 1024) }]).to_string();
+    let (_, code) = server
+        .index("metrics_request_size")
+        .raw_add_documents(&documents, vec![("Content-Type", "application/json")], "")
+        .await;
+    assert_eq!(code, 202);
+
+    let after = large_bodies(&metrics(&server).await);
+    assert_eq!(after - before, 1.0);
+}
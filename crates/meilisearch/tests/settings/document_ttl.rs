@@ -0,0 +1,52 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn document_ttl() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    // the expired documents are found with the numeric facets of the field
+    let (response, code) = index
+        .update_settings(json!({ "documentTtl": { "field": "createdAt", "ttlSeconds": 3600 } }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let response = index.wait_task(response.uid()).await.failed();
+    snapshot!(json_string!(response["error"]), @r###"
+    {
+      "message": "The document time-to-live field `createdAt` must be filterable or sortable.",
+      "code": "invalid_settings_document_ttl",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_document_ttl"
+    }
+    "###);
+
+    let (response, code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["createdAt"],
+            "documentTtl": { "field": "createdAt", "ttlSeconds": 3600 }
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["documentTtl"]), @r###"
+    {
+      "field": "createdAt",
+      "ttlSeconds": 3600
+    }
+    "###);
+
+    // the field can't stop being filterable while it is used by the time-to-live
+    let (response, _code) = index.update_settings(json!({ "filterableAttributes": [] })).await;
+    index.wait_task(response.uid()).await.failed();
+
+    let (response, _code) = index.update_settings(json!({ "documentTtl": null })).await;
+    index.wait_task(response.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["documentTtl"]), @"null");
+}
@@ -164,6 +164,16 @@ test_setting_routes!(
         update_verb: put,
         default_value: "indexingTime"
     },
+    {
+        setting: prefix_disabled_attributes,
+        update_verb: put,
+        default_value: []
+    },
+    {
+        setting: document_ttl,
+        update_verb: put,
+        default_value: null
+    },
     {
         setting: proximity_precision,
         update_verb: put,
@@ -197,7 +207,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 19);
+    assert_eq!(settings.keys().len(), 21);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -229,6 +239,8 @@ async fn get_settings() {
     assert_eq!(settings["proximityPrecision"], json!("byWord"));
     assert_eq!(settings["searchCutoffMs"], json!(null));
     assert_eq!(settings["prefixSearch"], json!("indexingTime"));
+    assert_eq!(settings["prefixDisabledAttributes"], json!([]));
+    assert_eq!(settings["documentTtl"], json!(null));
     assert_eq!(settings["facetSearch"], json!(true));
 }
 
@@ -344,7 +356,9 @@ async fn secrets_are_hidden_in_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "prefixDisabledAttributes": [],
+      "documentTtl": null
     }
     "#);
 
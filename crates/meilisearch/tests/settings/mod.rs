@@ -1,4 +1,5 @@
 mod distinct;
+mod document_ttl;
 mod errors;
 mod get_settings;
 mod prefix_search_settings;
@@ -456,3 +456,50 @@ async fn default_behavior() {
         })
         .await;
 }
+
+#[actix_rt::test]
+async fn prefix_disabled_attributes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) =
+        index.update_settings(json!({ "prefixDisabledAttributes": ["b"] })).await;
+    assert_eq!("202", code.as_str(), "{:?}", response);
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["prefixDisabledAttributes"]), @r###"
+    [
+      "b"
+    ]
+    "###);
+
+    // "manythe" is only matched as a whole word in the attribute b
+    index
+        .search(json!({"q": "manythe", "attributesToRetrieve": ["id"]}), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["hits"]), @r###"
+            [
+              {
+                "id": 2
+              }
+            ]
+            "###);
+        })
+        .await;
+
+    let (response, code) = index.update_settings(json!({ "prefixDisabledAttributes": null })).await;
+    assert_eq!("202", code.as_str(), "{:?}", response);
+    index.wait_task(response.uid()).await.succeeded();
+
+    index
+        .search(json!({"q": "manythe", "attributesToRetrieve": ["id"]}), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(response["hits"].as_array().unwrap().len(), @"3");
+        })
+        .await;
+}
@@ -246,12 +246,12 @@ fn export_a_dump(
         for ret in all_tasks.iter(&rtxn)? {
             let (_, t) = ret?;
             let status = t.status;
-            let content_file = t.content_uuid();
+            let content_files = t.content_uuids();
             let mut dump_content_file = dump_tasks.push_task(&t.into())?;
 
-            // 3.1. Dump the `content_file` associated with the task if there is one and the task is not finished yet.
-            if let Some(content_file_uuid) = content_file {
-                if status == Status::Enqueued {
+            // 3.1. Dump the `content_file`s associated with the task if there are some and the task is not finished yet.
+            if status == Status::Enqueued && !content_files.is_empty() {
+                for content_file_uuid in content_files {
                     let content_file = file_store.get_update(content_file_uuid)?;
 
                     let reader =
@@ -266,9 +266,9 @@ fn export_a_dump(
                         dump_content_file
                             .push_document(&obkv_to_object(doc, &documents_batch_index)?)?;
                     }
-                    dump_content_file.flush()?;
-                    count += 1;
                 }
+                dump_content_file.flush()?;
+                count += 1;
             }
         }
         dump_tasks.flush()?;
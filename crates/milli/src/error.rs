@@ -124,6 +124,8 @@ and can not be more than 511 bytes.", .document_id.to_string()
         invalid_facets_name: BTreeSet<String>,
         valid_facets_name: BTreeSet<String>,
     },
+    #[error("Invalid histogram buckets, {0}.")]
+    InvalidHistogramBuckets(String),
     #[error(transparent)]
     InvalidGeoField(#[from] GeoError),
     #[error("Invalid vector dimensions: expected: `{}`, found: `{}`.", .expected, .found)]
@@ -211,6 +213,8 @@ and can not be more than 511 bytes.", .document_id.to_string()
     UnknownInternalDocumentId { document_id: DocumentId },
     #[error("`minWordSizeForTypos` setting is invalid. `oneTypo` and `twoTypos` fields should be between `0` and `255`, and `twoTypos` should be greater or equals to `oneTypo` but found `oneTypo: {0}` and twoTypos: {1}`.")]
     InvalidMinTypoWordLenSetting(u8, u8),
+    #[error("The document time-to-live field `{field}` must be filterable or sortable.")]
+    InvalidDocumentTtlField { field: String },
     #[error(transparent)]
     VectorEmbeddingError(#[from] crate::vector::Error),
     #[error(transparent)]
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::convert::TryInto;
 use std::fs::File;
+use std::ops::Bound;
 use std::path::Path;
 
 use heed::types::*;
@@ -18,15 +19,19 @@ use crate::heed_codec::facet::{
     FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetF64Codec, FieldDocIdFacetStringCodec,
     FieldIdCodec, OrderedF64Codec,
 };
-use crate::heed_codec::{BEU16StrCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
+use crate::heed_codec::{BEU16StrCodec, BytesRefCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
 use crate::order_by_map::OrderByMap;
 use crate::proximity::ProximityPrecision;
+use crate::search::facet::{
+    find_docids_of_facet_within_bounds, get_first_facet_value, get_last_facet_value,
+};
 use crate::vector::{ArroyWrapper, Embedding, EmbeddingConfig};
 use crate::{
-    default_criteria, CboRoaringBitmapCodec, Criterion, DocumentId, ExternalDocumentsIds,
-    FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldIdWordCountCodec,
-    FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec, Result, RoaringBitmapCodec,
-    RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
+    default_criteria, CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, Criterion, DocumentId,
+    ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldIdWordCountCodec, FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec,
+    Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32,
+    BEU64,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -63,6 +68,7 @@ pub mod main_key {
     pub const TWO_TYPOS_WORD_LEN: &str = "two-typos-word-len";
     pub const EXACT_WORDS: &str = "exact-words";
     pub const EXACT_ATTRIBUTES: &str = "exact-attributes";
+    pub const PREFIX_DISABLED_ATTRIBUTES: &str = "prefix-disabled-attributes";
     pub const MAX_VALUES_PER_FACET: &str = "max-values-per-facet";
     pub const SORT_FACET_VALUES_BY: &str = "sort-facet-values-by";
     pub const PAGINATION_MAX_TOTAL_HITS: &str = "pagination-max-total-hits";
@@ -72,6 +78,7 @@ pub mod main_key {
     pub const LOCALIZED_ATTRIBUTES_RULES: &str = "localized_attributes_rules";
     pub const FACET_SEARCH: &str = "facet_search";
     pub const PREFIX_SEARCH: &str = "prefix_search";
+    pub const DOCUMENT_TTL: &str = "document_ttl";
 }
 
 pub mod db_name {
@@ -978,6 +985,47 @@ impl Index {
         }
     }
 
+    /// Returns the smallest and the biggest numeric facet values of this field.
+    ///
+    /// The bounds are read from the first and last level 0 keys of the `facet_id_f64_docids`
+    /// database, no document is scanned. Returns `None` if the field is unknown, not faceted,
+    /// or doesn't contain any numeric value.
+    pub fn numeric_field_bounds(
+        &self,
+        rtxn: &RoTxn<'_>,
+        field: &str,
+    ) -> Result<Option<(f64, f64)>> {
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let Some(field_id) = fields_ids_map.id(field) else { return Ok(None) };
+        self.numeric_field_id_bounds(rtxn, field_id)
+    }
+
+    /// Returns the smallest and the biggest numeric facet values of this field id.
+    pub fn numeric_field_id_bounds(
+        &self,
+        rtxn: &RoTxn<'_>,
+        field_id: FieldId,
+    ) -> Result<Option<(f64, f64)>> {
+        let db = self.facet_id_f64_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>();
+        let min = get_first_facet_value::<OrderedF64Codec, _>(rtxn, db, field_id)?;
+        let max = get_last_facet_value::<OrderedF64Codec, _>(rtxn, db, field_id)?;
+        Ok(min.zip(max))
+    }
+
+    /// Returns the numeric bounds of every faceted field that contains at least one number.
+    pub fn numeric_fields_bounds(&self, rtxn: &RoTxn<'_>) -> Result<BTreeMap<String, (f64, f64)>> {
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let mut bounds = BTreeMap::new();
+        for field_id in self.faceted_fields_ids(rtxn)? {
+            if let Some(field_bounds) = self.numeric_field_id_bounds(rtxn, field_id)? {
+                if let Some(name) = fields_ids_map.name(field_id) {
+                    bounds.insert(name.to_string(), field_bounds);
+                }
+            }
+        }
+        Ok(bounds)
+    }
+
     /* distinct field */
 
     pub(crate) fn put_distinct_field(
@@ -1073,6 +1121,40 @@ impl Index {
         }
     }
 
+    /// Returns the words contained in more than `min_document_frequency` (between `0.0`
+    /// and `1.0`) of the documents, the most frequent first, at most `max` of them.
+    ///
+    /// They are candidates for the stop words, they can be reviewed and declared with
+    /// [`Settings::set_stop_words`](crate::update::Settings::set_stop_words), which reindexes
+    /// the documents. The words already declared as stop words are not indexed and are
+    /// never suggested.
+    pub fn suggest_stop_words(
+        &self,
+        rtxn: &RoTxn<'_>,
+        max: usize,
+        min_document_frequency: f64,
+    ) -> Result<Vec<String>> {
+        let number_of_documents = self.number_of_documents(rtxn)?;
+        if number_of_documents == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut frequent_words = Vec::new();
+        for result in self.word_docids.remap_data_type::<CboRoaringBitmapLenCodec>().iter(rtxn)? {
+            let (word, count) = result?;
+            if count as f64 / number_of_documents as f64 > min_document_frequency {
+                frequent_words.push((count, word.to_string()));
+            }
+        }
+
+        // the most frequent first, alphabetically for the same frequency
+        frequent_words.sort_unstable_by(|(lcount, lword), (rcount, rword)| {
+            rcount.cmp(lcount).then_with(|| lword.cmp(rword))
+        });
+        frequent_words.truncate(max);
+        Ok(frequent_words.into_iter().map(|(_, word)| word).collect())
+    }
+
     /* non separator tokens */
 
     pub(crate) fn put_non_separator_tokens(
@@ -1490,6 +1572,44 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(txn, main_key::EXACT_ATTRIBUTES)
     }
 
+    /// Returns the attributes for which the prefix of the last query word must not be expanded.
+    pub fn prefix_disabled_attributes<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeBincode<Vec<&str>>>()
+            .get(txn, main_key::PREFIX_DISABLED_ATTRIBUTES)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the list of prefix disabled attributes field ids.
+    pub fn prefix_disabled_attributes_ids(&self, txn: &RoTxn<'_>) -> Result<HashSet<FieldId>> {
+        let attrs = self.prefix_disabled_attributes(txn)?;
+        let fid_map = self.fields_ids_map(txn)?;
+        Ok(attrs.iter().filter_map(|attr| fid_map.id(attr)).collect())
+    }
+
+    /// Writes the prefix disabled attributes to the database.
+    pub(crate) fn put_prefix_disabled_attributes(
+        &self,
+        txn: &mut RwTxn<'_>,
+        attrs: &[&str],
+    ) -> Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<&[&str]>>().put(
+            txn,
+            main_key::PREFIX_DISABLED_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
+    }
+
+    /// Clears the prefix disabled attributes from the store.
+    pub(crate) fn delete_prefix_disabled_attributes(
+        &self,
+        txn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::PREFIX_DISABLED_ATTRIBUTES)
+    }
+
     pub fn max_values_per_facet(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
         self.main.remap_types::<Str, BEU64>().get(txn, main_key::MAX_VALUES_PER_FACET)
     }
@@ -1688,6 +1808,55 @@ impl Index {
         Ok(res)
     }
 
+    /// Returns the time-to-live of the documents, `None` if the documents never expire.
+    pub fn document_ttl(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<DocumentTtl>> {
+        self.main.remap_types::<Str, SerdeJson<DocumentTtl>>().get(rtxn, main_key::DOCUMENT_TTL)
+    }
+
+    pub(crate) fn put_document_ttl(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        ttl: &DocumentTtl,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<DocumentTtl>>().put(
+            wtxn,
+            main_key::DOCUMENT_TTL,
+            ttl,
+        )
+    }
+
+    pub(crate) fn delete_document_ttl(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DOCUMENT_TTL)
+    }
+
+    /// Returns the documents that expired at `now`, a unix timestamp in seconds.
+    ///
+    /// A document expired when the numeric value of the TTL field is older than
+    /// `now - ttl`. The values are read from the numeric facet database, thus only
+    /// the documents of an index whose TTL field is filterable or sortable can expire.
+    /// Returns an empty bitmap when no TTL is configured.
+    pub fn expired_documents(&self, rtxn: &RoTxn<'_>, now: f64) -> Result<RoaringBitmap> {
+        let mut docids = RoaringBitmap::new();
+        let Some(DocumentTtl { field, ttl_seconds }) = self.document_ttl(rtxn)? else {
+            return Ok(docids);
+        };
+        let Some(field_id) = self.fields_ids_map(rtxn)?.id(&field) else {
+            return Ok(docids);
+        };
+
+        let expired_before = now - ttl_seconds as f64;
+        find_docids_of_facet_within_bounds::<OrderedF64Codec>(
+            rtxn,
+            self.facet_id_f64_docids,
+            field_id,
+            &Bound::Unbounded,
+            &Bound::Excluded(expired_before),
+            None,
+            &mut docids,
+        )?;
+        Ok(docids)
+    }
+
     pub fn prefix_settings(&self, rtxn: &RoTxn<'_>) -> Result<PrefixSettings> {
         let compute_prefixes = self.prefix_search(rtxn)?.unwrap_or_default();
         Ok(PrefixSettings { compute_prefixes, max_prefix_length: 4, prefix_count_threshold: 100 })
@@ -1716,6 +1885,16 @@ pub enum PrefixSearch {
     Disabled,
 }
 
+/// The time-to-live of the documents of an index.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentTtl {
+    /// The field containing the unix timestamp, in seconds, from which the TTL is counted.
+    pub field: String,
+    /// The number of seconds after which a document expires.
+    pub ttl_seconds: u64,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(transparent)]
 struct OffsetDateTime(#[serde(with = "time::serde::rfc3339")] time::OffsetDateTime);
@@ -1728,13 +1907,15 @@ pub(crate) mod tests {
     use big_s::S;
     use bumpalo::Bump;
     use heed::{EnvOpenOptions, RwTxn};
-    use maplit::{btreemap, hashset};
+    use maplit::{btreemap, btreeset, hashset};
     use memmap2::Mmap;
     use tempfile::TempDir;
 
     use crate::constants::RESERVED_GEO_FIELD_NAME;
-    use crate::error::{Error, InternalError};
-    use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+    use crate::error::{Error, InternalError, UserError};
+    use crate::index::{
+        DocumentTtl, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+    };
     use crate::progress::Progress;
     use crate::update::new::indexer;
     use crate::update::settings::InnerIndexSettings;
@@ -2106,6 +2287,111 @@ pub(crate) mod tests {
         assert_eq!(index.min_word_len_two_typos(&txn).unwrap(), 15);
     }
 
+    #[test]
+    fn numeric_field_bounds() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price"), S("name") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "kefir", "price": 12.5 },
+                { "id": 1, "name": "tamo", "price": -3 },
+                { "id": 2, "name": "echo", "price": 42 },
+                { "id": 3, "name": "intel" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.numeric_field_bounds(&rtxn, "price").unwrap(), Some((-3.0, 42.0)));
+        // a string-only field has no numeric bounds
+        assert_eq!(index.numeric_field_bounds(&rtxn, "name").unwrap(), None);
+        // an unknown field has no bounds either
+        assert_eq!(index.numeric_field_bounds(&rtxn, "unknown").unwrap(), None);
+        assert_eq!(
+            index.numeric_fields_bounds(&rtxn).unwrap(),
+            btreemap! { S("price") => (-3.0, 42.0) }
+        );
+        drop(rtxn);
+
+        index.delete_document("2");
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.numeric_field_bounds(&rtxn, "price").unwrap(), Some((-3.0, 12.5)));
+    }
+
+    #[test]
+    fn expired_documents() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("created_at") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "created_at": 1000 },
+                { "id": 1, "created_at": 2000 },
+                { "id": 2, "created_at": 3000 },
+                { "id": 3 },
+            ]))
+            .unwrap();
+
+        // no document expires without a TTL
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.document_ttl(&rtxn).unwrap().is_none());
+        assert!(index.expired_documents(&rtxn, 10_000.0).unwrap().is_empty());
+        drop(rtxn);
+
+        // the documents expire from the numeric facet database, the field must be faceted
+        let error = index
+            .update_settings(|settings| {
+                settings.set_document_ttl(DocumentTtl { field: S("id"), ttl_seconds: 500 })
+            })
+            .unwrap_err();
+        assert!(matches!(error, Error::UserError(UserError::InvalidDocumentTtlField { .. })));
+
+        let ttl = DocumentTtl { field: S("created_at"), ttl_seconds: 500 };
+        index.update_settings(|settings| settings.set_document_ttl(ttl.clone())).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.document_ttl(&rtxn).unwrap(), Some(ttl));
+        assert!(index.expired_documents(&rtxn, 1000.0).unwrap().is_empty());
+        // a document expires strictly after its TTL
+        assert!(index.expired_documents(&rtxn, 2500.0).unwrap().into_iter().eq([0]));
+        // the documents without the field never expire
+        assert!(index.expired_documents(&rtxn, 10_000.0).unwrap().into_iter().eq([0, 1, 2]));
+        drop(rtxn);
+
+        index.update_settings(|settings| settings.reset_document_ttl()).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.expired_documents(&rtxn, 10_000.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn suggest_stop_words() {
+        let index = TempIndex::new();
+        let documents: Vec<_> = (0..10)
+            .map(|id| {
+                let text = if id % 3 == 0 { "the cat and the dog" } else { "the bird" };
+                serde_json::json!({ "id": id, "text": text })
+            })
+            .collect();
+        index.add_documents(documents!(documents)).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.suggest_stop_words(&rtxn, 10, 0.9).unwrap(), vec![S("the")]);
+        assert_eq!(index.suggest_stop_words(&rtxn, 10, 0.5).unwrap(), vec![S("the"), S("bird")]);
+        assert_eq!(index.suggest_stop_words(&rtxn, 1, 0.5).unwrap(), vec![S("the")]);
+        drop(rtxn);
+
+        // once declared, the stop words are not indexed and not suggested anymore
+        index.update_settings(|settings| settings.set_stop_words(btreeset! { S("the") })).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.suggest_stop_words(&rtxn, 10, 0.9).unwrap(), Vec::<String>::new());
+    }
+
     #[test]
     fn add_documents_and_set_searchable_fields() {
         let index = TempIndex::new();
@@ -2834,10 +3120,18 @@ pub(crate) mod tests {
         let SearchResult {
             matching_words: _,
             candidates: _,
+            total_hits: _,
             document_scores: _,
             mut documents_ids,
             degraded: _,
             used_negative_operator: _,
+            suggestions: _,
+            had_typo_correction: _,
+            matched_terms: _,
+            total_documents: _,
+            matches: _,
+            facet_distribution: _,
+            facet_distribution_exhaustive: _,
         } = search.execute().unwrap();
         let primary_key_id = index.fields_ids_map(&rtxn).unwrap().id("primary_key").unwrap();
         documents_ids.sort_unstable();
@@ -0,0 +1,208 @@
+//! Verification of the invariants shared by the databases of an index.
+//!
+//! The check is a full scan of the documents, word and facet databases, it must only
+//! be run as an administration task, on an index that is not being updated.
+
+use std::fmt;
+
+use heed::types::Bytes;
+use heed::RoTxn;
+use itertools::{merge_join_by, EitherOrBoth};
+use roaring::RoaringBitmap;
+
+use crate::index::db_name;
+use crate::{DocumentId, Index, Result};
+
+/// A discrepancy found between the databases of an index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrityError {
+    /// The document id is in the documents ids but not in the documents database.
+    MissingDocument { docid: DocumentId },
+    /// The document is stored in the documents database but not in the documents ids.
+    UnlistedDocument { docid: DocumentId },
+    /// An entry of a database references documents that don't exist.
+    DeadDocuments { database: &'static str, key: String, docids: RoaringBitmap },
+    /// The word is in the word docids database but not in the words FST.
+    WordMissingFromFst { word: String },
+    /// The word is in the words FST but not in the word docids database.
+    WordMissingFromDatabase { word: String },
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::MissingDocument { docid } => {
+                write!(f, "document {docid} is listed but missing from the documents database")
+            }
+            IntegrityError::UnlistedDocument { docid } => {
+                write!(f, "document {docid} is stored but not listed in the documents ids")
+            }
+            IntegrityError::DeadDocuments { database, key, docids } => {
+                write!(
+                    f,
+                    "entry {key} of the {database} database references the unknown documents {docids:?}"
+                )
+            }
+            IntegrityError::WordMissingFromFst { word } => {
+                write!(f, "word {word:?} is missing from the words FST")
+            }
+            IntegrityError::WordMissingFromDatabase { word } => {
+                write!(f, "word {word:?} of the words FST has no documents")
+            }
+        }
+    }
+}
+
+/// The discrepancies found by [`Index::check_integrity`].
+#[derive(Debug, Default)]
+pub struct IntegrityReport {
+    pub errors: Vec<IntegrityError>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no discrepancy was found.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl Index {
+    /// Verifies the invariants shared by the databases of this index, without modifying it.
+    ///
+    /// - every document id is both listed in the documents ids and stored in the documents database,
+    /// - the word, word field id, facet numbers, facet strings and facet exists databases
+    ///   only reference existing documents,
+    /// - the words FST contains exactly the words of the word docids database.
+    ///
+    /// This is a full scan of these databases, the cost is proportional to the size of the index.
+    pub fn check_integrity(&self, rtxn: &RoTxn<'_>) -> Result<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        let documents_ids = self.documents_ids(rtxn)?;
+
+        // 1. The documents ids and the documents database.
+        let mut stored = RoaringBitmap::new();
+        for result in self.documents.remap_data_type::<Bytes>().iter(rtxn)? {
+            let (docid, _) = result?;
+            stored.insert(docid);
+        }
+        report.errors.extend(
+            (&documents_ids - &stored)
+                .iter()
+                .map(|docid| IntegrityError::MissingDocument { docid }),
+        );
+        report.errors.extend(
+            (&stored - &documents_ids)
+                .iter()
+                .map(|docid| IntegrityError::UnlistedDocument { docid }),
+        );
+
+        // 2. The postings only reference live documents.
+        let mut check = |database: &'static str, key: String, docids: RoaringBitmap| {
+            let dead = docids - &documents_ids;
+            if !dead.is_empty() {
+                report.errors.push(IntegrityError::DeadDocuments { database, key, docids: dead });
+            }
+        };
+        for result in self.word_docids.iter(rtxn)? {
+            let (word, docids) = result?;
+            check(db_name::WORD_DOCIDS, format!("{word:?}"), docids);
+        }
+        for result in self.exact_word_docids.iter(rtxn)? {
+            let (word, docids) = result?;
+            check(db_name::EXACT_WORD_DOCIDS, format!("{word:?}"), docids);
+        }
+        for result in self.word_fid_docids.iter(rtxn)? {
+            let ((word, fid), docids) = result?;
+            check(db_name::WORD_FIELD_ID_DOCIDS, format!("({word:?}, {fid})"), docids);
+        }
+        for result in self.facet_id_exists_docids.iter(rtxn)? {
+            let (fid, docids) = result?;
+            check(db_name::FACET_ID_EXISTS_DOCIDS, format!("{fid}"), docids);
+        }
+        for result in self.facet_id_f64_docids.iter(rtxn)? {
+            let (key, value) = result?;
+            let key = format!("({}, {}, {})", key.field_id, key.level, key.left_bound);
+            check(db_name::FACET_ID_F64_DOCIDS, key, value.bitmap);
+        }
+        for result in self.facet_id_string_docids.iter(rtxn)? {
+            let (key, value) = result?;
+            let key = format!("({}, {}, {:?})", key.field_id, key.level, key.left_bound);
+            check(db_name::FACET_ID_STRING_DOCIDS, key, value.bitmap);
+        }
+
+        // 3. The words FST and the word docids database contain the same words.
+        let words_fst = self.words_fst(rtxn)?;
+        let fst_words = words_fst.stream().into_strs()?;
+        let mut db_words = Vec::new();
+        for result in self.word_docids.remap_data_type::<Bytes>().iter(rtxn)? {
+            let (word, _) = result?;
+            db_words.push(word.to_string());
+        }
+        for either in merge_join_by(db_words, fst_words, |db, fst| db.cmp(fst)) {
+            match either {
+                EitherOrBoth::Both(_, _) => (),
+                EitherOrBoth::Left(word) => {
+                    report.errors.push(IntegrityError::WordMissingFromFst { word })
+                }
+                EitherOrBoth::Right(word) => {
+                    report.errors.push(IntegrityError::WordMissingFromDatabase { word })
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+    use maplit::hashset;
+    use roaring::RoaringBitmap;
+
+    use super::IntegrityError;
+    use crate::index::tests::TempIndex;
+
+    #[test]
+    fn corrupted_index_is_reported() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("age") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "kefir", "age": 4 },
+                { "id": 1, "name": "tamo", "age": 6 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let report = index.check_integrity(&rtxn).unwrap();
+        assert!(report.is_ok(), "{report:?}");
+        drop(rtxn);
+
+        // corrupt the index by removing a document, and by
+        // adding a word that references an unknown document.
+        let mut wtxn = index.write_txn().unwrap();
+        index.documents.delete(&mut wtxn, &1).unwrap();
+        index.word_docids.put(&mut wtxn, "ghost", &RoaringBitmap::from_iter([42])).unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let report = index.check_integrity(&rtxn).unwrap();
+        assert_eq!(
+            report.errors,
+            vec![
+                IntegrityError::MissingDocument { docid: 1 },
+                IntegrityError::DeadDocuments {
+                    database: "word-docids",
+                    key: S("\"ghost\""),
+                    docids: RoaringBitmap::from_iter([42]),
+                },
+                IntegrityError::WordMissingFromFst { word: S("ghost") },
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
-use std::ops::ControlFlow;
+use std::ops::{Bound, ControlFlow, Range};
 use std::{fmt, mem};
 
 use heed::types::Bytes;
@@ -18,6 +18,8 @@ use crate::heed_codec::{BytesRefCodec, StrRefCodec};
 use crate::search::facet::facet_distribution_iter::{
     count_iterate_over_facet_distribution, lexicographically_iterate_over_facet_distribution,
 };
+use crate::search::facet::facet_range_search::find_docids_of_facet_within_bounds;
+use crate::search::facet::{facet_max_value, facet_min_value};
 use crate::{FieldId, Index, Result};
 
 /// The default number of values by facets that will
@@ -47,6 +49,20 @@ impl Display for OrderBy {
     }
 }
 
+/// How the numeric values of a facet are split into the buckets of an histogram.
+///
+/// Every bucket includes its lower edge and excludes its upper edge,
+/// except the last bucket which includes both of its edges.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistogramBuckets {
+    /// Split the values between the minimum and the maximum
+    /// of the candidates into this number of buckets of equal width.
+    Count(usize),
+    /// The strictly increasing edges of the buckets, `n` edges make `n - 1` buckets.
+    /// The values lower than the first edge or greater than the last one are not counted.
+    Edges(Vec<f64>),
+}
+
 pub struct FacetDistribution<'a> {
     facets: Option<HashMap<String, OrderBy>>,
     candidates: Option<RoaringBitmap>,
@@ -339,6 +355,93 @@ impl<'a> FacetDistribution<'a> {
         Ok(distribution)
     }
 
+    /// Count the candidates in each bucket of an histogram of the numeric values of the field.
+    ///
+    /// Every bucket is returned, even the empty ones. A document with several values
+    /// is counted once in every bucket containing one of its values.
+    pub fn compute_histogram(
+        &self,
+        field: &str,
+        buckets: HistogramBuckets,
+    ) -> Result<Vec<(Range<f64>, u64)>> {
+        let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let filterable_fields = self.index.filterable_fields(self.rtxn)?;
+        if !crate::is_faceted(field, &filterable_fields) {
+            return Err(UserError::InvalidFacetsDistribution {
+                invalid_facets_name: [field.to_string()].into(),
+                valid_facets_name: filterable_fields.into_iter().collect(),
+            }
+            .into());
+        }
+
+        let candidates = match &self.candidates {
+            Some(candidates) => candidates.clone(),
+            None => self.index.documents_ids(self.rtxn)?,
+        };
+
+        let edges = match buckets {
+            HistogramBuckets::Count(0) => {
+                return Err(UserError::InvalidHistogramBuckets(
+                    "the number of buckets must be greater than zero".to_string(),
+                )
+                .into());
+            }
+            HistogramBuckets::Count(count) => {
+                // no document has a value for this field yet
+                let Some(field_id) = fields_ids_map.id(field) else { return Ok(Vec::new()) };
+                let min = facet_min_value(self.index, self.rtxn, field_id, candidates.clone())?;
+                let max = facet_max_value(self.index, self.rtxn, field_id, candidates.clone())?;
+                let (Some(min), Some(max)) = (min, max) else { return Ok(Vec::new()) };
+                let width = (max - min) / count as f64;
+                (0..=count).map(|i| if i == count { max } else { min + width * i as f64 }).collect()
+            }
+            HistogramBuckets::Edges(edges) => {
+                if edges.len() < 2 {
+                    return Err(UserError::InvalidHistogramBuckets(
+                        "at least two edges are required".to_string(),
+                    )
+                    .into());
+                }
+                if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+                    return Err(UserError::InvalidHistogramBuckets(
+                        "the edges must be strictly increasing numbers".to_string(),
+                    )
+                    .into());
+                }
+                edges
+            }
+        };
+
+        let field_id = fields_ids_map.id(field);
+        let last_bucket = edges.len() - 2;
+        let mut histogram = Vec::with_capacity(edges.len() - 1);
+        for (i, pair) in edges.windows(2).enumerate() {
+            let mut docids = RoaringBitmap::new();
+            // when all the candidates share the same value, only the last bucket is not empty
+            let is_empty = i != last_bucket && pair[0] >= pair[1];
+            if let Some(field_id) = field_id.filter(|_| !is_empty) {
+                let left = Bound::Included(pair[0]);
+                let right = if i == last_bucket {
+                    Bound::Included(pair[1])
+                } else {
+                    Bound::Excluded(pair[1])
+                };
+                find_docids_of_facet_within_bounds::<OrderedF64Codec>(
+                    self.rtxn,
+                    self.index.facet_id_f64_docids,
+                    field_id,
+                    &left,
+                    &right,
+                    Some(&candidates),
+                    &mut docids,
+                )?;
+            }
+            histogram.push((pair[0]..pair[1], docids.len()));
+        }
+
+        Ok(histogram)
+    }
+
     pub fn execute(&self) -> Result<BTreeMap<String, IndexMap<String, u64>>> {
         let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
         let filterable_fields = self.index.filterable_fields(self.rtxn)?;
@@ -402,6 +505,7 @@ impl fmt::Debug for FacetDistribution<'_> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::iter;
 
     use big_s::S;
@@ -409,7 +513,7 @@ mod tests {
 
     use crate::documents::mmap_from_objects;
     use crate::index::tests::TempIndex;
-    use crate::{milli_snap, FacetDistribution, OrderBy};
+    use crate::{milli_snap, FacetDistribution, Filter, HistogramBuckets, OrderBy};
 
     #[test]
     fn few_candidates_few_facet_values() {
@@ -782,6 +886,97 @@ mod tests {
         milli_snap!(format!("{map:?}"), "candidates_217_777", @r###"{"colour": (217.0, 776.0)}"###);
     }
 
+    #[test]
+    fn facet_stats_narrowed_by_filter() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price"), S("brand") })
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "brand": "apple", "price": 999 },
+                { "id": 1, "brand": "apple", "price": 1299 },
+                { "id": 2, "brand": "samsung", "price": 199 },
+                { "id": 3, "brand": "samsung", "price": 1799 },
+                { "id": 4, "brand": "apple", "price": 599 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the string facets have no stats and are omitted
+        let map = FacetDistribution::new(&txn, &index)
+            .candidates(index.documents_ids(&txn).unwrap())
+            .compute_stats()
+            .unwrap();
+        assert_eq!(map, BTreeMap::from([(S("price"), (199.0, 1799.0))]));
+
+        let filter = Filter::from_str("brand = apple").unwrap().unwrap();
+        let candidates = filter.evaluate(&txn, &index).unwrap();
+        let map =
+            FacetDistribution::new(&txn, &index).candidates(candidates).compute_stats().unwrap();
+        assert_eq!(map, BTreeMap::from([(S("price"), (599.0, 1299.0))]));
+    }
+
+    #[test]
+    fn facet_histogram() {
+        let index = TempIndex::new_with_map_size(4096 * 10_000);
+
+        index
+            .update_settings(|settings| settings.set_filterable_fields(hashset! { S("price") }))
+            .unwrap();
+
+        let mut documents = vec![];
+        for i in 0..1000 {
+            let document = serde_json::json!({
+                "id": i,
+                "price": i,
+            })
+            .as_object()
+            .unwrap()
+            .clone();
+            documents.push(document);
+        }
+
+        let documents = mmap_from_objects(documents);
+        index.add_documents(documents).unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the maximum value is counted in the last bucket
+        let histogram = FacetDistribution::new(&txn, &index)
+            .compute_histogram("price", HistogramBuckets::Count(4))
+            .unwrap();
+        assert_eq!(
+            histogram,
+            vec![
+                (0.0..249.75, 250),
+                (249.75..499.5, 250),
+                (499.5..749.25, 250),
+                (749.25..999.0, 250)
+            ]
+        );
+
+        // the empty buckets are returned and the values out of the edges are ignored
+        let histogram = FacetDistribution::new(&txn, &index)
+            .candidates((150..350).collect())
+            .compute_histogram("price", HistogramBuckets::Edges(vec![0.0, 100.0, 200.0, 300.0]))
+            .unwrap();
+        assert_eq!(histogram, vec![(0.0..100.0, 0), (100.0..200.0, 50), (200.0..300.0, 101)]);
+
+        let error = FacetDistribution::new(&txn, &index)
+            .compute_histogram("price", HistogramBuckets::Edges(vec![10.0, 0.0]))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid histogram buckets, the edges must be strictly increasing numbers."
+        );
+    }
+
     #[test]
     fn facet_mixed_values() {
         let index = TempIndex::new_with_map_size(4096 * 10_000);
@@ -229,6 +229,29 @@ impl<'a> Filter<'a> {
 
 impl<'a> Filter<'a> {
     pub fn evaluate(&self, rtxn: &heed::RoTxn<'_>, index: &Index) -> Result<RoaringBitmap> {
+        self.evaluate_in_universe(rtxn, index, None)
+    }
+
+    /// Returns the documents of the universe matching the filter.
+    ///
+    /// The output is the same as intersecting the universe with [`Self::evaluate`], but
+    /// the evaluation is cheaper when the universe only contains a few documents.
+    pub fn evaluate_within(
+        &self,
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        universe: &RoaringBitmap,
+    ) -> Result<RoaringBitmap> {
+        // the evaluation may return documents outside of the universe
+        Ok(self.evaluate_in_universe(rtxn, index, Some(universe))? & universe)
+    }
+
+    fn evaluate_in_universe(
+        &self,
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        universe: Option<&RoaringBitmap>,
+    ) -> Result<RoaringBitmap> {
         // to avoid doing this for each recursive call we're going to do it ONCE ahead of time
         let filterable_fields = index.filterable_fields(rtxn)?;
         for fid in self.condition.fids(MAX_FILTER_DEPTH) {
@@ -240,7 +263,7 @@ impl<'a> Filter<'a> {
                 }))?;
             }
         }
-        self.inner_evaluate(rtxn, index, &filterable_fields, None)
+        self.inner_evaluate(rtxn, index, &filterable_fields, universe)
     }
 
     fn evaluate_operator(
@@ -1237,4 +1260,39 @@ mod tests {
         let result = filter.evaluate(&rtxn, &index).unwrap();
         assert_eq!(result, RoaringBitmap::from_iter((0..100).filter(|x| x % 10 != 0)));
     }
+
+    #[test]
+    fn not_empty() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key("id".to_owned());
+                settings.set_filterable_fields(hashset! { S("image") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0 },
+                { "id": 1, "image": null },
+                { "id": 2, "image": "" },
+                { "id": 3, "image": [] },
+                { "id": 4, "image": {} },
+                { "id": 5, "image": "kefir.png" },
+                { "id": 6, "image": ["kefir.png"] },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let evaluate = |filter: &str| {
+            Filter::from_str(filter).unwrap().unwrap().evaluate(&rtxn, &index).unwrap()
+        };
+
+        assert_eq!(evaluate("image NOT EMPTY"), RoaringBitmap::from_iter([5, 6]));
+        assert_eq!(evaluate("NOT image NOT EMPTY"), RoaringBitmap::from_iter([0, 1, 2, 3, 4]));
+        // unlike `NOT EMPTY`, these filters keep the missing or null values
+        assert_eq!(evaluate("image EXISTS"), RoaringBitmap::from_iter(1..7));
+        assert_eq!(evaluate("image IS NOT EMPTY"), RoaringBitmap::from_iter([0, 1, 5, 6]));
+    }
 }
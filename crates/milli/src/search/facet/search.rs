@@ -6,11 +6,15 @@ use charabia::normalizer::NormalizerOption;
 use charabia::{Language, Normalize, StrDetection, Token};
 use fst::automaton::{Automaton, Str};
 use fst::{IntoStreamer, Streamer};
+use heed::BytesDecode;
 use roaring::RoaringBitmap;
 use tracing::error;
 
 use crate::error::UserError;
-use crate::heed_codec::facet::{FacetGroupKey, FacetGroupValue};
+use crate::heed_codec::facet::{
+    FacetGroupKey, FacetGroupKeyCodec, FacetGroupValue, OrderedF64Codec,
+};
+use crate::heed_codec::BytesRefCodec;
 use crate::search::build_dfa;
 use crate::{DocumentId, FieldId, OrderBy, Result, Search};
 
@@ -94,11 +98,6 @@ impl<'a> SearchForFacetValues<'a> {
             None => return Ok(Vec::new()),
         };
 
-        let fst = match self.search_query.index.facet_id_string_fst.get(rtxn, &fid)? {
-            Some(fst) => fst,
-            None => return Ok(Vec::new()),
-        };
-
         let search_candidates = self.search_query.execute_for_candidates(
             self.is_hybrid
                 || self
@@ -114,40 +113,61 @@ impl<'a> SearchForFacetValues<'a> {
             OrderBy::Count => ValuesCollection::by_count(self.max_values),
         };
 
-        match self.query.as_ref() {
-            Some(query) => {
-                let query = normalize_facet_string(query, self.locales.as_deref());
-                let query = query.as_ref();
-
-                let authorize_typos = self.search_query.index.authorize_typos(rtxn)?;
-                let field_authorizes_typos =
-                    !self.search_query.index.exact_attributes_ids(rtxn)?.contains(&fid);
-
-                if authorize_typos && field_authorizes_typos {
-                    let exact_words_fst = self.search_query.index.exact_words(rtxn)?;
-                    if exact_words_fst.map_or(false, |fst| fst.contains(query)) {
-                        if fst.contains(query) {
-                            self.fetch_original_facets_using_normalized(
-                                fid,
-                                query,
-                                query,
-                                &search_candidates,
-                                &mut results,
-                            )?;
+        // A field can contain both strings and numbers, the strings are collected first.
+        if let Some(fst) = index.facet_id_string_fst.get(rtxn, &fid)? {
+            match self.query.as_ref() {
+                Some(query) => {
+                    let query = normalize_facet_string(query, self.locales.as_deref());
+                    let query = query.as_ref();
+
+                    let authorize_typos = self.search_query.index.authorize_typos(rtxn)?;
+                    let field_authorizes_typos =
+                        !self.search_query.index.exact_attributes_ids(rtxn)?.contains(&fid);
+
+                    if authorize_typos && field_authorizes_typos {
+                        let exact_words_fst = self.search_query.index.exact_words(rtxn)?;
+                        if exact_words_fst.map_or(false, |fst| fst.contains(query)) {
+                            if fst.contains(query) {
+                                self.fetch_original_facets_using_normalized(
+                                    fid,
+                                    query,
+                                    query,
+                                    &search_candidates,
+                                    &mut results,
+                                )?;
+                            }
+                        } else {
+                            let one_typo = self.search_query.index.min_word_len_one_typo(rtxn)?;
+                            let two_typos = self.search_query.index.min_word_len_two_typos(rtxn)?;
+
+                            let is_prefix = true;
+                            let automaton = if query.len() < one_typo as usize {
+                                build_dfa(query, 0, is_prefix)
+                            } else if query.len() < two_typos as usize {
+                                build_dfa(query, 1, is_prefix)
+                            } else {
+                                build_dfa(query, 2, is_prefix)
+                            };
+
+                            let mut stream = fst.search(automaton).into_stream();
+                            while let Some(facet_value) = stream.next() {
+                                let value = std::str::from_utf8(facet_value)?;
+                                if self
+                                    .fetch_original_facets_using_normalized(
+                                        fid,
+                                        value,
+                                        query,
+                                        &search_candidates,
+                                        &mut results,
+                                    )?
+                                    .is_break()
+                                {
+                                    break;
+                                }
+                            }
                         }
                     } else {
-                        let one_typo = self.search_query.index.min_word_len_one_typo(rtxn)?;
-                        let two_typos = self.search_query.index.min_word_len_two_typos(rtxn)?;
-
-                        let is_prefix = true;
-                        let automaton = if query.len() < one_typo as usize {
-                            build_dfa(query, 0, is_prefix)
-                        } else if query.len() < two_typos as usize {
-                            build_dfa(query, 1, is_prefix)
-                        } else {
-                            build_dfa(query, 2, is_prefix)
-                        };
-
+                        let automaton = Str::new(query).starts_with();
                         let mut stream = fst.search(automaton).into_stream();
                         while let Some(facet_value) = stream.next() {
                             let value = std::str::from_utf8(facet_value)?;
@@ -165,47 +185,64 @@ impl<'a> SearchForFacetValues<'a> {
                             }
                         }
                     }
-                } else {
-                    let automaton = Str::new(query).starts_with();
-                    let mut stream = fst.search(automaton).into_stream();
-                    while let Some(facet_value) = stream.next() {
-                        let value = std::str::from_utf8(facet_value)?;
-                        if self
-                            .fetch_original_facets_using_normalized(
-                                fid,
-                                value,
-                                query,
-                                &search_candidates,
-                                &mut results,
-                            )?
-                            .is_break()
-                        {
-                            break;
-                        }
-                    }
                 }
-            }
-            None => {
-                let prefix = FacetGroupKey { field_id: fid, level: 0, left_bound: "" };
-                for result in index.facet_id_string_docids.prefix_iter(rtxn, &prefix)? {
-                    let (FacetGroupKey { left_bound, .. }, FacetGroupValue { bitmap, .. }) =
-                        result?;
-                    let count = search_candidates.intersection_len(&bitmap);
-                    if count != 0 {
-                        let value = self
-                            .one_original_value_of(fid, left_bound, bitmap.min().unwrap())?
-                            .unwrap_or_else(|| left_bound.to_string());
-                        if results.insert(FacetValueHit { value, count }).is_break() {
-                            break;
+                None => {
+                    let prefix = FacetGroupKey { field_id: fid, level: 0, left_bound: "" };
+                    for result in index.facet_id_string_docids.prefix_iter(rtxn, &prefix)? {
+                        let (FacetGroupKey { left_bound, .. }, FacetGroupValue { bitmap, .. }) =
+                            result?;
+                        let count = search_candidates.intersection_len(&bitmap);
+                        if count != 0 {
+                            let value = self
+                                .one_original_value_of(fid, left_bound, bitmap.min().unwrap())?
+                                .unwrap_or_else(|| left_bound.to_string());
+                            if results.insert(FacetValueHit { value, count }).is_break() {
+                                break;
+                            }
                         }
                     }
                 }
             }
         }
 
+        self.search_in_numbers(fid, &search_candidates, &mut results)?;
+
         Ok(results.into_sorted_vec())
     }
 
+    /// Collects the numbers of the facet that start with the query once formatted as strings,
+    /// after the strings of the facet if it has both.
+    ///
+    /// Numbers are neither normalized nor typo tolerant, `1` matches `1`, `12` and `1.5`.
+    fn search_in_numbers(
+        &self,
+        fid: FieldId,
+        search_candidates: &RoaringBitmap,
+        results: &mut ValuesCollection,
+    ) -> Result<()> {
+        let index = self.search_query.index;
+        let rtxn = self.search_query.rtxn;
+
+        let prefix = FacetGroupKey { field_id: fid, level: 0, left_bound: &[][..] };
+        let database =
+            index.facet_id_f64_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>();
+        for result in database.prefix_iter(rtxn, &prefix)? {
+            let (FacetGroupKey { left_bound, .. }, FacetGroupValue { bitmap, .. }) = result?;
+            let value = OrderedF64Codec::bytes_decode(left_bound)
+                .map_err(heed::Error::Decoding)?
+                .to_string();
+            if self.query.as_ref().map_or(false, |query| !value.starts_with(query.trim())) {
+                continue;
+            }
+            let count = search_candidates.intersection_len(&bitmap);
+            if count != 0 && results.insert(FacetValueHit { value, count }).is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     fn fetch_original_facets_using_normalized(
         &self,
         fid: FieldId,
@@ -356,3 +393,101 @@ fn normalize_facet_string(facet_string: &str, locales: Option<&[Language]>) -> S
 
     token.normalize(&options).lemma.into_owned()
 }
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+    use maplit::hashset;
+
+    use super::{FacetValueHit, SearchForFacetValues};
+    use crate::index::tests::TempIndex;
+    use crate::Search;
+
+    #[test]
+    fn search_numeric_facet_values() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| settings.set_filterable_fields(hashset! { S("price") }))
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "price": 12 },
+                { "id": 1, "price": 12 },
+                { "id": 2, "price": 1.5 },
+                { "id": 3, "price": 230 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        let search = Search::new(&txn, &index);
+        let mut facet_search = SearchForFacetValues::new(S("price"), search, false);
+        let hits = facet_search.execute().unwrap();
+        assert_eq!(
+            hits,
+            vec![
+                FacetValueHit { value: S("1.5"), count: 1 },
+                FacetValueHit { value: S("12"), count: 2 },
+                FacetValueHit { value: S("230"), count: 1 },
+            ]
+        );
+
+        let hits = facet_search.query("1").execute().unwrap();
+        assert_eq!(
+            hits,
+            vec![
+                FacetValueHit { value: S("1.5"), count: 1 },
+                FacetValueHit { value: S("12"), count: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_mixed_facet_values() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| settings.set_filterable_fields(hashset! { S("size") }))
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "size": "small" },
+                { "id": 1, "size": 10 },
+                { "id": 2, "size": "large" },
+                { "id": 3, "size": 12 },
+                { "id": 4, "size": 12 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the strings are returned first, then the numbers
+        let search = Search::new(&txn, &index);
+        let mut facet_search = SearchForFacetValues::new(S("size"), search, false);
+        let hits = facet_search.execute().unwrap();
+        assert_eq!(
+            hits,
+            vec![
+                FacetValueHit { value: S("large"), count: 1 },
+                FacetValueHit { value: S("small"), count: 1 },
+                FacetValueHit { value: S("10"), count: 1 },
+                FacetValueHit { value: S("12"), count: 2 },
+            ]
+        );
+
+        let hits = facet_search.query("1").execute().unwrap();
+        assert_eq!(
+            hits,
+            vec![
+                FacetValueHit { value: S("10"), count: 1 },
+                FacetValueHit { value: S("12"), count: 2 },
+            ]
+        );
+
+        let hits = facet_search.query("sma").execute().unwrap();
+        assert_eq!(hits, vec![FacetValueHit { value: S("small"), count: 1 }]);
+    }
+}
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 use itertools::Itertools;
 use roaring::RoaringBitmap;
@@ -13,6 +14,10 @@ struct ScoreWithRatioResult {
     document_scores: Vec<(u32, ScoreWithRatio)>,
     degraded: bool,
     used_negative_operator: bool,
+    suggestions: Vec<String>,
+    had_typo_correction: bool,
+    matched_terms: Option<Vec<Vec<String>>>,
+    total_documents: u64,
 }
 
 type ScoreWithRatio = (Vec<ScoreDetails>, f32);
@@ -82,6 +87,10 @@ impl ScoreWithRatioResult {
             document_scores,
             degraded: results.degraded,
             used_negative_operator: results.used_negative_operator,
+            suggestions: results.suggestions,
+            had_typo_correction: results.had_typo_correction,
+            matched_terms: results.matched_terms,
+            total_documents: results.total_documents,
         }
     }
 
@@ -106,6 +115,19 @@ impl ScoreWithRatioResult {
             vector_results.document_scores.len() + keyword_results.document_scores.len(),
         );
 
+        // only the keyword search matches query terms, the documents
+        // that were only found by the semantic search matched none of them.
+        let mut keyword_matched_terms: Option<HashMap<_, _>> =
+            keyword_results.matched_terms.map(|matched_terms| {
+                keyword_results
+                    .document_scores
+                    .iter()
+                    .map(|(docid, _)| *docid)
+                    .zip(matched_terms)
+                    .collect()
+            });
+        let mut matched_terms = keyword_matched_terms.as_ref().map(|_| Vec::with_capacity(length));
+
         let mut documents_seen = RoaringBitmap::new();
         for ((docid, (main_score, _sub_score)), source) in vector_results
             .document_scores
@@ -132,19 +154,33 @@ impl ScoreWithRatioResult {
                 semantic_hit_count += 1;
             }
             documents_ids.push(docid);
+            if let (Some(keyword_matched_terms), Some(matched_terms)) =
+                (&mut keyword_matched_terms, &mut matched_terms)
+            {
+                matched_terms.push(keyword_matched_terms.remove(&docid).unwrap_or_default());
+            }
             // TODO: pass both scores to documents_score in some way?
             document_scores.push(main_score);
         }
 
+        let candidates = vector_results.candidates | keyword_results.candidates;
         (
             SearchResult {
                 matching_words: keyword_results.matching_words,
-                candidates: vector_results.candidates | keyword_results.candidates,
+                total_hits: candidates.len(),
+                candidates,
                 documents_ids,
                 document_scores,
                 degraded: vector_results.degraded | keyword_results.degraded,
                 used_negative_operator: vector_results.used_negative_operator
                     | keyword_results.used_negative_operator,
+                suggestions: keyword_results.suggestions,
+                had_typo_correction: keyword_results.had_typo_correction,
+                matched_terms,
+                total_documents: keyword_results.total_documents,
+                matches: None,
+                facet_distribution: None,
+                facet_distribution_exhaustive: true,
             },
             semantic_hit_count,
         )
@@ -154,27 +190,61 @@ impl ScoreWithRatioResult {
 impl<'a> Search<'a> {
     #[tracing::instrument(level = "trace", skip_all, target = "search::hybrid")]
     pub fn execute_hybrid(&self, semantic_ratio: f32) -> Result<(SearchResult, Option<u32>)> {
+        let (mut result, semantic_hit_count) = self.execute_hybrid_searches(semantic_ratio)?;
+
+        // the matches are computed once, on the documents kept after the merge
+        let matching_words = std::mem::take(&mut result.matching_words);
+        let (matching_words, matches) =
+            self.documents_matches(matching_words, &result.documents_ids)?;
+        result.matching_words = matching_words;
+        result.matches = matches;
+
+        // and so is the facet distribution, on the candidates of both searches
+        let (facet_distribution, facet_distribution_exhaustive) =
+            self.facet_distribution(&result.candidates)?;
+        result.facet_distribution = facet_distribution;
+        result.facet_distribution_exhaustive = facet_distribution_exhaustive;
+        result.total_hits = self.total_hits(&result.candidates);
+
+        Ok((result, semantic_hit_count))
+    }
+
+    fn execute_hybrid_searches(&self, semantic_ratio: f32) -> Result<(SearchResult, Option<u32>)> {
         // TODO: find classier way to achieve that than to reset vector and query params
         // create separate keyword and semantic searches
         let mut search = Search {
             query: self.query.clone(),
             filter: self.filter.clone(),
+            filter_strategy: self.filter_strategy,
             offset: 0,
             limit: self.limit + self.offset,
             sort_criteria: self.sort_criteria.clone(),
             distinct: self.distinct.clone(),
+            distinct_representative: self.distinct_representative.clone(),
+            distinct_content: self.distinct_content.clone(),
             searchable_attributes: self.searchable_attributes,
             geo_strategy: self.geo_strategy,
             terms_matching_strategy: self.terms_matching_strategy,
             scoring_strategy: ScoringStrategy::Detailed,
             words_limit: self.words_limit,
             exhaustive_number_hits: self.exhaustive_number_hits,
+            max_total_hits: self.max_total_hits,
             rtxn: self.rtxn,
             index: self.index,
+            settings: self.settings,
             semantic: self.semantic.clone(),
             time_budget: self.time_budget.clone(),
             ranking_score_threshold: self.ranking_score_threshold,
             locales: self.locales.clone(),
+            suggestions_threshold: self.suggestions_threshold,
+            match_numeric_facets: self.match_numeric_facets,
+            min_word_len_for_typo: self.min_word_len_for_typo,
+            typo_budget: self.typo_budget,
+            boolean_operators: self.boolean_operators,
+            matched_terms: self.matched_terms,
+            typo_correction: self.typo_correction,
+            matches_attributes: None,
+            facets: None,
         };
 
         let semantic = search.semantic.take();
@@ -259,10 +329,18 @@ fn return_keyword_results(
     SearchResult {
         matching_words,
         candidates,
+        total_hits,
         mut documents_ids,
         mut document_scores,
         degraded,
         used_negative_operator,
+        suggestions,
+        had_typo_correction,
+        mut matched_terms,
+        total_documents,
+        matches,
+        facet_distribution,
+        facet_distribution_exhaustive,
     }: SearchResult,
 ) -> (SearchResult, Option<u32>) {
     let (documents_ids, document_scores) = if offset >= documents_ids.len() ||
@@ -281,14 +359,31 @@ fn return_keyword_results(
         document_scores.truncate(limit);
         (documents_ids, document_scores)
     };
+    if let Some(matched_terms) = &mut matched_terms {
+        if offset >= matched_terms.len() {
+            matched_terms.clear();
+        } else {
+            // PANICS: offset < len
+            matched_terms.rotate_left(offset);
+            matched_terms.truncate(limit);
+        }
+    }
     (
         SearchResult {
             matching_words,
             candidates,
+            total_hits,
             documents_ids,
             document_scores,
             degraded,
             used_negative_operator,
+            suggestions,
+            had_typo_correction,
+            matched_terms,
+            total_documents,
+            matches,
+            facet_distribution,
+            facet_distribution_exhaustive,
         },
         Some(0),
     )
@@ -1,19 +1,27 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
-use charabia::Language;
+use charabia::{Language, TokenizerBuilder};
 use levenshtein_automata::{LevenshteinAutomatonBuilder as LevBuilder, DFA};
 use once_cell::sync::Lazy;
+use ordered_float::OrderedFloat;
 use roaring::bitmap::RoaringBitmap;
 
-pub use self::facet::{FacetDistribution, Filter, OrderBy, DEFAULT_VALUES_PER_FACET};
-pub use self::new::matches::{FormatOptions, MatchBounds, MatcherBuilder, MatchingWords};
-use self::new::{execute_vector_search, PartialSearchResult};
+pub use self::facet::{
+    FacetDistribution, Filter, HistogramBuckets, OrderBy, DEFAULT_VALUES_PER_FACET,
+};
+pub use self::new::matches::{CropUnit, FormatOptions, MatchBounds, MatcherBuilder, MatchingWords};
+use self::new::{
+    execute_vector_search, matched_terms, query_suggestions, PartialSearchResult, RankedCallback,
+};
 use crate::score_details::{ScoreDetails, ScoringStrategy};
 use crate::vector::Embedder;
 use crate::{
     execute_search, filtered_universe, AscDesc, DefaultSearchLogger, DocumentId, Error, Index,
-    Result, SearchContext, TimeBudget, UserError,
+    Result, SearchContext, SearchSettings, TimeBudget, UserError,
 };
 
 // Building these factories is not free.
@@ -21,6 +29,9 @@ static LEVDIST0: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(0, true));
 static LEVDIST1: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(1, true));
 static LEVDIST2: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(2, true));
 
+/// The number of documents ranked at once by [`Search::for_each_ranked`] for a vector search.
+const VECTOR_RANKED_CHUNK_SIZE: usize = 1000;
+
 pub mod facet;
 mod fst_utils;
 pub mod hybrid;
@@ -35,26 +46,41 @@ pub struct SemanticSearch {
     quantized: bool,
 }
 
+#[derive(Clone)]
 pub struct Search<'a> {
     query: Option<String>,
     // this should be linked to the String in the query
     filter: Option<Filter<'a>>,
+    filter_strategy: FilterStrategy,
     offset: usize,
     limit: usize,
     sort_criteria: Option<Vec<AscDesc>>,
     distinct: Option<String>,
+    distinct_representative: Option<String>,
+    distinct_content: Option<Vec<String>>,
     searchable_attributes: Option<&'a [String]>,
     geo_strategy: new::GeoSortStrategy,
     terms_matching_strategy: TermsMatchingStrategy,
     scoring_strategy: ScoringStrategy,
     words_limit: usize,
     exhaustive_number_hits: bool,
+    max_total_hits: Option<usize>,
     rtxn: &'a heed::RoTxn<'a>,
     index: &'a Index,
+    settings: Option<&'a SearchSettings<'a>>,
     semantic: Option<SemanticSearch>,
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
     locales: Option<Vec<Language>>,
+    suggestions_threshold: Option<u64>,
+    match_numeric_facets: bool,
+    min_word_len_for_typo: Option<(u8, u8)>,
+    typo_budget: bool,
+    boolean_operators: bool,
+    matched_terms: bool,
+    typo_correction: bool,
+    matches_attributes: Option<(Vec<String>, FormatOptions)>,
+    facets: Option<Vec<String>>,
 }
 
 impl<'a> Search<'a> {
@@ -62,22 +88,36 @@ impl<'a> Search<'a> {
         Search {
             query: None,
             filter: None,
+            filter_strategy: FilterStrategy::default(),
             offset: 0,
             limit: 20,
             sort_criteria: None,
             distinct: None,
+            distinct_representative: None,
+            distinct_content: None,
             searchable_attributes: None,
             geo_strategy: new::GeoSortStrategy::default(),
             terms_matching_strategy: TermsMatchingStrategy::default(),
             scoring_strategy: Default::default(),
             exhaustive_number_hits: false,
+            max_total_hits: None,
             words_limit: 10,
             rtxn,
             index,
+            settings: None,
             semantic: None,
             locales: None,
             time_budget: TimeBudget::max(),
             ranking_score_threshold: None,
+            suggestions_threshold: None,
+            match_numeric_facets: false,
+            min_word_len_for_typo: None,
+            typo_budget: false,
+            boolean_operators: false,
+            matched_terms: false,
+            typo_correction: false,
+            matches_attributes: None,
+            facets: None,
         }
     }
 
@@ -97,6 +137,13 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Uses settings of the index read beforehand with the same transaction, instead of
+    /// reading them again, to save time when running many searches.
+    pub fn settings(&mut self, settings: &'a SearchSettings<'a>) -> &mut Search<'a> {
+        self.settings = Some(settings);
+        self
+    }
+
     pub fn offset(&mut self, offset: usize) -> &mut Search<'a> {
         self.offset = offset;
         self
@@ -117,6 +164,26 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Among the equally ranked documents sharing a distinct value, keeps the one with the
+    /// highest value for this field instead of the first one, e.g. prefers the `inStock: true`
+    /// variant of a product.
+    ///
+    /// A better ranked document is always kept, whatever its value. The field must be filterable.
+    pub fn distinct_representative(&mut self, field: String) -> &mut Search<'a> {
+        self.distinct_representative = Some(field);
+        self
+    }
+
+    /// Collapses the documents whose values for the given fields are exactly the same,
+    /// only the best ranked of them is returned.
+    ///
+    /// The values are compared on the fly when the documents are ranked, the fields don't
+    /// need to be filterable. A document that has none of the fields is never collapsed.
+    pub fn distinct_content(&mut self, fields: Vec<String>) -> &mut Search<'a> {
+        self.distinct_content = Some(fields);
+        self
+    }
+
     pub fn searchable_attributes(&mut self, searchable: &'a [String]) -> &mut Search<'a> {
         self.searchable_attributes = Some(searchable);
         self
@@ -142,6 +209,13 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Chooses whether the filter is evaluated before or after the query candidates,
+    /// see [`FilterStrategy`].
+    pub fn filter_strategy(&mut self, strategy: FilterStrategy) -> &mut Search<'a> {
+        self.filter_strategy = strategy;
+        self
+    }
+
     #[cfg(test)]
     pub fn geo_sort_strategy(&mut self, strategy: new::GeoSortStrategy) -> &mut Search<'a> {
         self.geo_strategy = strategy;
@@ -155,6 +229,17 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Bounds the number of hits reported in [`SearchResult::total_hits`].
+    ///
+    /// The candidates themselves are not truncated: with [`Self::exhaustive_number_hits`]
+    /// they are all deduplicated by the distinct attribute and the distinct content, the
+    /// facet distribution is still computed on all of them, and the documents past the
+    /// maximum can still be retrieved with the offset and limit.
+    pub fn max_total_hits(&mut self, max_total_hits: usize) -> &mut Search<'a> {
+        self.max_total_hits = Some(max_total_hits);
+        self
+    }
+
     pub fn time_budget(&mut self, time_budget: TimeBudget) -> &mut Search<'a> {
         self.time_budget = time_budget;
         self
@@ -170,9 +255,102 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Suggests alternative queries, with the misspelled words corrected,
+    /// when less than `threshold` documents match the query.
+    ///
+    /// Suggestions are disabled by default.
+    pub fn suggestions(&mut self, threshold: u64) -> &mut Search<'a> {
+        self.suggestions_threshold = Some(threshold);
+        self
+    }
+
+    /// Makes the query words only made of digits also match the documents
+    /// having this exact number as a value of any filterable field.
+    ///
+    /// The numbers are compared with a strict equality, `2023` matches `2023`
+    /// and `2023.0` but neither `20230` nor `2023.5`.
+    pub fn match_numeric_facets(&mut self, match_numeric_facets: bool) -> &mut Search<'a> {
+        self.match_numeric_facets = match_numeric_facets;
+        self
+    }
+
+    /// Overrides the minimal word lengths of the index typo tolerance settings:
+    /// the query words of at least `one_typo` bytes can have one typo and the ones
+    /// of at least `two_typos` bytes can have two typos, shorter words must match exactly.
+    ///
+    /// `one_typo` must be lower than or equal to `two_typos`.
+    pub fn min_word_len_for_typo(&mut self, one_typo: u8, two_typos: u8) -> &mut Search<'a> {
+        self.min_word_len_for_typo = Some((one_typo, two_typos));
+        self
+    }
+
+    /// Bounds the total number of typos of the documents matching a long query,
+    /// see [`new::query_typo_budget`] for the number of typos allowed.
+    ///
+    /// This reduces the noise of the queries of many words, on which a typo on
+    /// every word matches unrelated documents. It is disabled by default.
+    pub fn typo_budget(&mut self, typo_budget: bool) -> &mut Search<'a> {
+        self.typo_budget = typo_budget;
+        self
+    }
+
+    /// Interprets the uppercase `AND` and `OR` words and the parentheses of the query as
+    /// boolean operators, e.g. `(red OR blue) shoes`.
+    ///
+    /// This is disabled by default so that the existing queries containing these words
+    /// keep matching the same documents.
+    pub fn boolean_operators(&mut self, boolean_operators: bool) -> &mut Search<'a> {
+        self.boolean_operators = boolean_operators;
+        self
+    }
+
+    /// Computes, for each returned document, the words of the query that matched it,
+    /// see [`SearchResult::matched_terms`].
+    ///
+    /// This is disabled by default as it costs an additional docids lookup for every
+    /// derivation of every query word, restricted to the returned documents.
+    pub fn matched_terms(&mut self, matched_terms: bool) -> &mut Search<'a> {
+        self.matched_terms = matched_terms;
+        self
+    }
+
+    /// Reports whether the returned documents matched the query through a typo
+    /// correction, see [`SearchResult::had_typo_correction`].
+    ///
+    /// This is disabled by default as it costs an additional docids lookup
+    /// for every word of the query.
+    pub fn typo_correction(&mut self, typo_correction: bool) -> &mut Search<'a> {
+        self.typo_correction = typo_correction;
+        self
+    }
+
+    /// Computes the bounds of the matches of the query in the given attributes
+    /// of each returned document, see [`SearchResult::matches`].
+    ///
+    /// When the format options crop the attributes, only the matches kept in the
+    /// cropped text are returned.
+    pub fn compute_matches(
+        &mut self,
+        attributes: Vec<String>,
+        format_options: FormatOptions,
+    ) -> &mut Search<'a> {
+        self.matches_attributes = Some((attributes, format_options));
+        self
+    }
+
+    /// Counts the matching documents for each value of the given facets,
+    /// see [`SearchResult::facet_distribution`].
+    ///
+    /// The facets must be filterable and at most [`DEFAULT_VALUES_PER_FACET`]
+    /// values are counted for each of them.
+    pub fn compute_facets(&mut self, facets: Vec<String>) -> &mut Search<'a> {
+        self.facets = Some(facets);
+        self
+    }
+
     pub fn execute_for_candidates(&self, has_vector_search: bool) -> Result<RoaringBitmap> {
         if has_vector_search {
-            let ctx = SearchContext::new(self.index, self.rtxn)?;
+            let ctx = self.new_search_context()?;
             filtered_universe(ctx.index, ctx.txn, &self.filter)
         } else {
             Ok(self.execute()?.candidates)
@@ -180,13 +358,135 @@ impl<'a> Search<'a> {
     }
 
     pub fn execute(&self) -> Result<SearchResult> {
-        let mut ctx = SearchContext::new(self.index, self.rtxn)?;
+        let mut ctx = self.search_context()?;
+
+        let PartialSearchResult {
+            located_query_terms,
+            candidates,
+            documents_ids,
+            document_scores,
+            degraded,
+            used_negative_operator,
+            had_typo_correction,
+        } = self.execute_partial(&mut ctx, self.offset, self.limit, None)?;
+
+        let suggestions = match (&located_query_terms, self.suggestions_threshold) {
+            (Some(located_query_terms), Some(threshold)) if candidates.len() < threshold => {
+                query_suggestions(&mut ctx, located_query_terms)?
+            }
+            _ => Vec::new(),
+        };
+
+        let matched_terms = match (&located_query_terms, self.matched_terms) {
+            (Some(located_query_terms), true) => {
+                Some(matched_terms(&mut ctx, located_query_terms, &documents_ids)?)
+            }
+            (None, true) => Some(vec![Vec::new(); documents_ids.len()]),
+            (_, false) => None,
+        };
+
+        let total_documents = ctx.index.number_of_documents(ctx.txn)?;
+
+        // consume context and located_query_terms to build MatchingWords.
+        let matching_words = match located_query_terms {
+            Some(located_query_terms) => MatchingWords::new(ctx, located_query_terms),
+            None => MatchingWords::default(),
+        };
+        let (matching_words, matches) = self.documents_matches(matching_words, &documents_ids)?;
+        let (facet_distribution, facet_distribution_exhaustive) =
+            self.facet_distribution(&candidates)?;
+        let total_hits = self.total_hits(&candidates);
+
+        Ok(SearchResult {
+            matching_words,
+            total_hits,
+            candidates,
+            document_scores,
+            documents_ids,
+            degraded,
+            used_negative_operator,
+            suggestions,
+            had_typo_correction,
+            matched_terms,
+            total_documents,
+            matches,
+            facet_distribution,
+            facet_distribution_exhaustive,
+        })
+    }
+
+    /// Calls `f` with the ids of the documents matching the search in ranking order,
+    /// until it returns [`ControlFlow::Break`].
+    ///
+    /// Unlike [`Self::execute`], the offset and the limit are ignored and the ranked ids
+    /// are not collected: only the buckets of the ranking rules are kept in memory.
+    /// A vector search retrieves its nearest documents all at once, it is instead
+    /// executed page after page of [`VECTOR_RANKED_CHUNK_SIZE`] documents.
+    pub fn for_each_ranked(&self, f: impl FnMut(DocumentId) -> ControlFlow<()>) -> Result<()> {
+        self.for_each_ranked_in_chunks(VECTOR_RANKED_CHUNK_SIZE, f)
+    }
+
+    fn for_each_ranked_in_chunks(
+        &self,
+        chunk_size: usize,
+        mut f: impl FnMut(DocumentId) -> ControlFlow<()>,
+    ) -> Result<()> {
+        let mut ctx = self.search_context()?;
+        if let Some(SemanticSearch { vector: Some(_), .. }) = &self.semantic {
+            let mut offset = 0;
+            loop {
+                let PartialSearchResult { documents_ids, .. } =
+                    self.execute_partial(&mut ctx, offset, chunk_size, None)?;
+                for &docid in &documents_ids {
+                    if f(docid).is_break() {
+                        return Ok(());
+                    }
+                }
+                if documents_ids.len() < chunk_size {
+                    return Ok(());
+                }
+                offset += chunk_size;
+            }
+        }
+
+        let ranked_callback: RankedCallback<'_> = &mut f;
+        self.execute_partial(&mut ctx, 0, usize::MAX, Some(ranked_callback))?;
+        Ok(())
+    }
+
+    fn new_search_context(&self) -> Result<SearchContext<'a>> {
+        match self.settings {
+            Some(settings) => {
+                Ok(SearchContext::with_settings(self.index, self.rtxn, Cow::Borrowed(settings)))
+            }
+            None => SearchContext::new(self.index, self.rtxn),
+        }
+    }
+
+    /// Creates the search context with the options of the search.
+    fn search_context(&self) -> Result<SearchContext<'a>> {
+        let mut ctx = self.new_search_context()?;
+        ctx.match_numeric_facets = self.match_numeric_facets;
+
+        if let Some((one_typo, two_typos)) = self.min_word_len_for_typo {
+            if one_typo > two_typos {
+                return Err(UserError::InvalidMinTypoWordLenSetting(one_typo, two_typos).into());
+            }
+            ctx.min_word_len_for_typo = Some((one_typo, two_typos));
+        }
+        ctx.typo_budget = self.typo_budget;
+        ctx.boolean_operators = self.boolean_operators;
+        ctx.report_typo_correction = self.typo_correction;
 
         if let Some(searchable_attributes) = self.searchable_attributes {
             ctx.attributes_to_search_on(searchable_attributes)?;
         }
 
-        if let Some(distinct) = &self.distinct {
+        if let Some(fields) = &self.distinct_content {
+            ctx.distinct_content(fields)?;
+        }
+
+        for distinct in [&self.distinct, &self.distinct_representative].into_iter().flatten() {
             let filterable_fields = ctx.index.filterable_fields(ctx.txn)?;
             if !crate::is_faceted(distinct, &filterable_fields) {
                 let (valid_fields, hidden_fields) =
@@ -199,68 +499,154 @@ impl<'a> Search<'a> {
             }
         }
 
-        let universe = filtered_universe(ctx.index, ctx.txn, &self.filter)?;
-        let PartialSearchResult {
-            located_query_terms,
-            candidates,
-            documents_ids,
-            document_scores,
-            degraded,
-            used_negative_operator,
-        } = match self.semantic.as_ref() {
+        if let Some(field) = &self.distinct_representative {
+            ctx.distinct_representative(field)?;
+        }
+
+        Ok(ctx)
+    }
+
+    /// Executes the keyword or vector search, collecting the ranked documents
+    /// or giving them to the ranked callback.
+    fn execute_partial(
+        &self,
+        ctx: &mut SearchContext<'a>,
+        offset: usize,
+        limit: usize,
+        ranked_callback: Option<RankedCallback<'_>>,
+    ) -> Result<PartialSearchResult> {
+        match self.semantic.as_ref() {
             Some(SemanticSearch { vector: Some(vector), embedder_name, embedder, quantized }) => {
+                let universe = filtered_universe(ctx.index, ctx.txn, &self.filter)?;
                 execute_vector_search(
-                    &mut ctx,
+                    ctx,
                     vector,
                     self.scoring_strategy,
                     universe,
                     &self.sort_criteria,
                     &self.distinct,
                     self.geo_strategy,
-                    self.offset,
-                    self.limit,
+                    offset,
+                    limit,
                     embedder_name,
                     embedder,
                     *quantized,
                     self.time_budget.clone(),
                     self.ranking_score_threshold,
-                )?
+                    ranked_callback,
+                )
+            }
+            _ => {
+                let universe = ctx.index.documents_ids(ctx.txn)?;
+                execute_search(
+                    ctx,
+                    self.query.as_deref(),
+                    self.terms_matching_strategy,
+                    self.scoring_strategy,
+                    self.exhaustive_number_hits,
+                    universe,
+                    &self.filter,
+                    self.filter_strategy,
+                    &self.sort_criteria,
+                    &self.distinct,
+                    self.geo_strategy,
+                    offset,
+                    limit,
+                    Some(self.words_limit),
+                    &mut DefaultSearchLogger,
+                    &mut DefaultSearchLogger,
+                    self.time_budget.clone(),
+                    self.ranking_score_threshold,
+                    self.locales.as_ref(),
+                    ranked_callback,
+                )
             }
-            _ => execute_search(
-                &mut ctx,
-                self.query.as_deref(),
-                self.terms_matching_strategy,
-                self.scoring_strategy,
-                self.exhaustive_number_hits,
-                universe,
-                &self.sort_criteria,
-                &self.distinct,
-                self.geo_strategy,
-                self.offset,
-                self.limit,
-                Some(self.words_limit),
-                &mut DefaultSearchLogger,
-                &mut DefaultSearchLogger,
-                self.time_budget.clone(),
-                self.ranking_score_threshold,
-                self.locales.as_ref(),
-            )?,
+        }
+    }
+
+    /// Computes the matches of the attributes requested with [`Self::compute_matches`]
+    /// in the given documents, reusing the matching words of the query.
+    #[allow(clippy::type_complexity)]
+    fn documents_matches(
+        &self,
+        matching_words: MatchingWords,
+        documents_ids: &[DocumentId],
+    ) -> Result<(MatchingWords, Option<Vec<BTreeMap<String, Vec<MatchBounds>>>>)> {
+        let Some((attributes, format_options)) = &self.matches_attributes else {
+            return Ok((matching_words, None));
         };
 
-        // consume context and located_query_terms to build MatchingWords.
-        let matching_words = match located_query_terms {
-            Some(located_query_terms) => MatchingWords::new(ctx, located_query_terms),
-            None => MatchingWords::default(),
+        let dictionary = self.index.dictionary(self.rtxn)?;
+        let dictionary: Option<Vec<_>> =
+            dictionary.as_ref().map(|x| x.iter().map(String::as_str).collect());
+        let separators = self.index.allowed_separators(self.rtxn)?;
+        let separators: Option<Vec<_>> =
+            separators.as_ref().map(|x| x.iter().map(String::as_str).collect());
+
+        let mut tokenizer_builder = TokenizerBuilder::default();
+        tokenizer_builder.create_char_map(true);
+        if let Some(separators) = &separators {
+            tokenizer_builder.separators(separators);
+        }
+        if let Some(dictionary) = &dictionary {
+            tokenizer_builder.words_dict(dictionary);
+        }
+
+        let builder = MatcherBuilder::new(matching_words, tokenizer_builder.into_tokenizer());
+        let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let matches = documents_ids
+            .iter()
+            .map(|&docid| {
+                let document = self.index.document(self.rtxn, docid)?;
+                builder.document_matches(
+                    document,
+                    &fields_ids_map,
+                    attributes,
+                    *format_options,
+                    self.locales.as_deref(),
+                )
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((builder.into_matching_words(), Some(matches)))
+    }
+
+    /// Counts the given candidates up to [`Self::max_total_hits`].
+    fn total_hits(&self, candidates: &RoaringBitmap) -> u64 {
+        match self.max_total_hits {
+            Some(max) => candidates.len().min(max as u64),
+            None => candidates.len(),
+        }
+    }
+
+    /// Computes the distribution of the facets requested with [`Self::compute_facets`]
+    /// over the given candidates, and whether no facet value was left out.
+    #[allow(clippy::type_complexity)]
+    fn facet_distribution(
+        &self,
+        candidates: &RoaringBitmap,
+    ) -> Result<(Option<BTreeMap<String, BTreeMap<String, u64>>>, bool)> {
+        let Some(facets) = &self.facets else {
+            return Ok((None, true));
         };
 
-        Ok(SearchResult {
-            matching_words,
-            candidates,
-            document_scores,
-            documents_ids,
-            degraded,
-            used_negative_operator,
-        })
+        // one more value is fetched to know if some were left out
+        let distribution = FacetDistribution::new(self.rtxn, self.index)
+            .facets(facets.iter().map(|facet| (facet, OrderBy::default())))
+            .max_values_per_facet(DEFAULT_VALUES_PER_FACET + 1)
+            .candidates(candidates.clone())
+            .execute()?;
+
+        let mut exhaustive = true;
+        let distribution = distribution
+            .into_iter()
+            .map(|(facet, values)| {
+                exhaustive &= values.len() <= DEFAULT_VALUES_PER_FACET;
+                (facet, values.into_iter().take(DEFAULT_VALUES_PER_FACET).collect())
+            })
+            .collect();
+
+        Ok((Some(distribution), exhaustive))
     }
 }
 
@@ -269,35 +655,53 @@ impl fmt::Debug for Search<'_> {
         let Search {
             query,
             filter,
+            filter_strategy,
             offset,
             limit,
             sort_criteria,
             distinct,
+            distinct_representative,
+            distinct_content,
             searchable_attributes,
             geo_strategy: _,
             terms_matching_strategy,
             scoring_strategy,
             words_limit,
             exhaustive_number_hits,
+            max_total_hits,
             rtxn: _,
             index: _,
+            settings: _,
             semantic,
             time_budget,
             ranking_score_threshold,
             locales,
+            suggestions_threshold,
+            match_numeric_facets,
+            min_word_len_for_typo,
+            typo_budget,
+            boolean_operators,
+            matched_terms,
+            typo_correction,
+            matches_attributes,
+            facets,
         } = self;
         f.debug_struct("Search")
             .field("query", query)
             .field("vector", &"[...]")
             .field("filter", filter)
+            .field("filter_strategy", filter_strategy)
             .field("offset", offset)
             .field("limit", limit)
             .field("sort_criteria", sort_criteria)
             .field("distinct", distinct)
+            .field("distinct_representative", distinct_representative)
+            .field("distinct_content", distinct_content)
             .field("searchable_attributes", searchable_attributes)
             .field("terms_matching_strategy", terms_matching_strategy)
             .field("scoring_strategy", scoring_strategy)
             .field("exhaustive_number_hits", exhaustive_number_hits)
+            .field("max_total_hits", max_total_hits)
             .field("words_limit", words_limit)
             .field(
                 "semantic.embedder_name",
@@ -306,6 +710,15 @@ impl fmt::Debug for Search<'_> {
             .field("time_budget", time_budget)
             .field("ranking_score_threshold", ranking_score_threshold)
             .field("locales", locales)
+            .field("suggestions_threshold", suggestions_threshold)
+            .field("match_numeric_facets", match_numeric_facets)
+            .field("min_word_len_for_typo", min_word_len_for_typo)
+            .field("typo_budget", typo_budget)
+            .field("boolean_operators", boolean_operators)
+            .field("matched_terms", matched_terms)
+            .field("typo_correction", typo_correction)
+            .field("matches_attributes", matches_attributes)
+            .field("facets", facets)
             .finish()
     }
 }
@@ -313,21 +726,90 @@ impl fmt::Debug for Search<'_> {
 #[derive(Default, Debug)]
 pub struct SearchResult {
     pub matching_words: MatchingWords,
+    /// The documents matching the search.
     pub candidates: RoaringBitmap,
+    /// The number of candidates, bounded by [`Search::max_total_hits`] and which must
+    /// then be read as "at least" this number of documents when the bound is reached.
+    pub total_hits: u64,
     pub documents_ids: Vec<DocumentId>,
     pub document_scores: Vec<Vec<ScoreDetails>>,
     pub degraded: bool,
     pub used_negative_operator: bool,
+    /// Alternative queries with the misspelled words corrected, see [`Search::suggestions`].
+    pub suggestions: Vec<String>,
+    /// Whether any of the returned documents matched the query through a typo correction,
+    /// only computed when [`Search::typo_correction`] is enabled.
+    pub had_typo_correction: bool,
+    /// The words of the query that matched each document, aligned with `documents_ids`,
+    /// only computed when [`Search::matched_terms`] is enabled.
+    pub matched_terms: Option<Vec<Vec<String>>>,
+    /// The number of documents in the index, whether they match the search or not.
+    pub total_documents: u64,
+    /// The bounds of the matches in the attributes of each document, aligned with
+    /// `documents_ids`, only computed when [`Search::compute_matches`] is enabled.
+    pub matches: Option<Vec<BTreeMap<String, Vec<MatchBounds>>>>,
+    /// The number of candidates for each value of the facets, only computed when
+    /// [`Search::compute_facets`] is enabled.
+    pub facet_distribution: Option<BTreeMap<String, BTreeMap<String, u64>>>,
+    /// Whether the facet distribution counts every value of the facets, it is `false`
+    /// when a facet has more than [`DEFAULT_VALUES_PER_FACET`] values.
+    pub facet_distribution_exhaustive: bool,
+}
+
+impl SearchResult {
+    /// Computes the pagination of these results for the given offset and limit,
+    /// the ones given to [`Search::offset`] and [`Search::limit`].
+    ///
+    /// The pagination is computed from the [`Self::total_hits`], it is only exact
+    /// when [`Search::exhaustive_number_hits`] is enabled and must be read as an
+    /// estimation otherwise.
+    pub fn pagination(&self, offset: usize, limit: usize) -> Pagination {
+        Pagination::new(self.total_hits as usize, offset, limit)
+    }
+}
+
+/// The pages of a search result, see [`SearchResult::pagination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    pub total_hits: usize,
+    /// The number of pages of `limit` documents, zero when the limit is zero.
+    pub total_pages: usize,
+    /// The page of the first returned document, starting at one, zero when the limit is zero.
+    pub current_page: usize,
+    pub has_next_page: bool,
+}
+
+impl Pagination {
+    pub fn new(total_hits: usize, offset: usize, limit: usize) -> Pagination {
+        // If the limit is 0, then pages can't be computed.
+        let total_pages = total_hits.div_ceil(limit.max(1));
+        let (total_pages, current_page) = match offset.checked_div(limit) {
+            Some(page) => (total_pages, page + 1),
+            None => (0, 0),
+        };
+        let has_next_page = limit != 0 && offset.saturating_add(limit) < total_hits;
+        Pagination { total_hits, total_pages, current_page, has_next_page }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TermsMatchingStrategy {
     // remove last word first
     Last,
+    // remove first word first
+    First,
+    // remove shortest word first, see `QueryGraph::removal_order_for_terms_matching_strategy_size`
+    Size,
     // all words are mandatory
     All,
     // remove more frequent word first
     Frequency,
+    // remove the word matching the most documents per character first, see
+    // `QueryGraph::removal_order_for_terms_matching_strategy_frequency_weighted`
+    FrequencyWeighted,
+    // at least one of the words whose idf is at least `min_idf` must match, see
+    // `QueryGraph::rare_terms_docids`, the words are removed like with `Frequency`
+    RequireRare { min_idf: OrderedFloat<f64> },
 }
 
 impl Default for TermsMatchingStrategy {
@@ -336,6 +818,24 @@ impl Default for TermsMatchingStrategy {
     }
 }
 
+/// The order in which the filter and the query candidates are evaluated.
+///
+/// The search returns the same documents, with the same scores, whatever the strategy.
+/// Only the cost of the evaluation changes: the filter is cheaper to evaluate within
+/// a small set of query candidates, and the query is cheaper to resolve within a small
+/// set of filtered documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterStrategy {
+    /// Evaluate the filter on the whole index, then resolve the query within the filtered documents.
+    #[default]
+    FilterFirst,
+    /// Resolve the query on the whole index, then evaluate the filter within the query candidates.
+    QueryFirst,
+    /// Resolve the query first when its rarest word is contained
+    /// by a small fraction of the documents, evaluate the filter first otherwise.
+    Auto,
+}
+
 fn get_first(s: &str) -> &str {
     match s.chars().next() {
         Some(c) => &s[..c.len_utf8()],
@@ -362,6 +862,142 @@ mod test {
     #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    fn pagination() {
+        let pagination = Pagination::new(45, 0, 20);
+        assert_eq!(
+            pagination,
+            Pagination { total_hits: 45, total_pages: 3, current_page: 1, has_next_page: true }
+        );
+        let pagination = Pagination::new(45, 40, 20);
+        assert_eq!(
+            pagination,
+            Pagination { total_hits: 45, total_pages: 3, current_page: 3, has_next_page: false }
+        );
+        let pagination = Pagination::new(40, 20, 20);
+        assert_eq!(
+            pagination,
+            Pagination { total_hits: 40, total_pages: 2, current_page: 2, has_next_page: false }
+        );
+        let pagination = Pagination::new(0, 0, 20);
+        assert_eq!(
+            pagination,
+            Pagination { total_hits: 0, total_pages: 0, current_page: 1, has_next_page: false }
+        );
+        let pagination = Pagination::new(45, 0, 0);
+        assert_eq!(
+            pagination,
+            Pagination { total_hits: 45, total_pages: 0, current_page: 0, has_next_page: false }
+        );
+    }
+
+    #[test]
+    fn for_each_ranked() {
+        use crate::index::tests::TempIndex;
+
+        let index = TempIndex::new();
+        let documents: Vec<_> = (0..50)
+            .map(|id| {
+                let text = if id % 3 == 0 { "hello world" } else { "hello" };
+                serde_json::json!({ "id": id, "text": text })
+            })
+            .collect();
+        index.add_documents(documents!(documents)).unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("hello world");
+        search.terms_matching_strategy(TermsMatchingStrategy::Last);
+        search.limit(50);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids.len(), 50);
+
+        // the offset and the limit are ignored
+        search.offset(10);
+        search.limit(5);
+        let mut ranked = Vec::new();
+        search
+            .for_each_ranked(|docid| {
+                ranked.push(docid);
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(ranked, documents_ids);
+
+        let mut ranked = Vec::new();
+        search
+            .for_each_ranked(|docid| {
+                ranked.push(docid);
+                if ranked.len() == 3 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .unwrap();
+        assert_eq!(ranked, documents_ids[..3]);
+    }
+
+    #[test]
+    fn for_each_ranked_vector() {
+        use big_s::S;
+
+        use crate::index::tests::TempIndex;
+        use crate::update::Setting;
+        use crate::vector::settings::{EmbedderSource, EmbeddingSettings};
+        use crate::vector::Embedder;
+
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                let embedder = EmbeddingSettings {
+                    source: Setting::Set(EmbedderSource::UserProvided),
+                    dimensions: Setting::Set(2),
+                    ..Default::default()
+                };
+                let embedders = BTreeMap::from([(S("manual"), Setting::Set(embedder))]);
+                settings.set_embedder_settings(embedders);
+            })
+            .unwrap();
+        let documents: Vec<_> = (0..10)
+            .map(|id| serde_json::json!({ "id": id, "_vectors": { "manual": [1.0, id as f32] } }))
+            .collect();
+        index.add_documents(documents!(documents)).unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let config = index.embedding_configs(&txn).unwrap().pop().unwrap();
+        let embedder = Arc::new(Embedder::new(config.config.embedder_options).unwrap());
+        let mut search = Search::new(&txn, &index);
+        search.semantic(config.name, embedder, false, Some(vec![1.0, 0.0]));
+        search.limit(10);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids.len(), 10);
+
+        // the chunks of 3 documents are ranked like the whole search
+        search.limit(2);
+        let mut ranked = Vec::new();
+        search
+            .for_each_ranked_in_chunks(3, |docid| {
+                ranked.push(docid);
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(ranked, documents_ids);
+
+        let mut ranked = Vec::new();
+        search
+            .for_each_ranked_in_chunks(3, |docid| {
+                ranked.push(docid);
+                if ranked.len() == 4 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .unwrap();
+        assert_eq!(ranked, documents_ids[..4]);
+    }
+
     #[cfg(feature = "japanese")]
     #[cfg(not(feature = "chinese-pinyin"))]
     #[test]
@@ -410,4 +1046,90 @@ mod test {
 
         assert_eq!(documents_ids, vec![1]);
     }
+
+    #[test]
+    fn total_documents() {
+        use crate::index::tests::TempIndex;
+
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox" },
+                { "id": 1, "title": "the lazy dog" },
+                { "id": 2, "title": "a quick dog" },
+            ]))
+            .unwrap();
+        index.delete_documents(vec!["1".to_owned()]);
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+
+        search.query("quick");
+        let SearchResult { documents_ids, total_documents, .. } = search.execute().unwrap();
+
+        assert_eq!(documents_ids.len(), 2);
+        assert_eq!(total_documents, 2);
+        assert_eq!(total_documents, index.number_of_documents(&txn).unwrap());
+    }
+
+    #[test]
+    fn compute_matches() {
+        use big_s::S;
+
+        use crate::index::tests::TempIndex;
+
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox", "tags": ["fast", "fox"], "age": 4 },
+                { "id": 1, "title": "the lazy dog", "tags": ["slow"], "age": 10 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+
+        search.query("fox");
+        let SearchResult { documents_ids, matches, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0]);
+        assert!(matches.is_none());
+
+        let attributes = vec![S("title"), S("tags"), S("age"), S("unknown")];
+        search.compute_matches(attributes.clone(), FormatOptions::default());
+        let SearchResult { documents_ids, matches, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0]);
+        assert_eq!(
+            matches,
+            Some(vec![BTreeMap::from([
+                (S("tags"), vec![MatchBounds { start: 0, length: 3, indices: Some(vec![1]) }]),
+                (S("title"), vec![MatchBounds { start: 16, length: 3, indices: None }]),
+            ])])
+        );
+
+        // only the matches kept in the cropped text are returned
+        search.query("quick fox");
+        search.compute_matches(attributes.clone(), FormatOptions::default());
+        let SearchResult { matches, .. } = search.execute().unwrap();
+        assert_eq!(
+            matches.unwrap()[0][&S("title")],
+            vec![
+                MatchBounds { start: 4, length: 5, indices: None },
+                MatchBounds { start: 16, length: 3, indices: None },
+            ]
+        );
+
+        let format_options =
+            FormatOptions { highlight: true, crop: Some(1), crop_unit: CropUnit::Words };
+        search.compute_matches(attributes, format_options);
+        let SearchResult { matches, .. } = search.execute().unwrap();
+        assert_eq!(
+            matches,
+            Some(vec![BTreeMap::from([
+                (S("tags"), vec![MatchBounds { start: 0, length: 3, indices: Some(vec![1]) }]),
+                (S("title"), vec![MatchBounds { start: 4, length: 5, indices: None }]),
+            ])])
+        );
+    }
 }
@@ -1,11 +1,16 @@
+use std::borrow::Cow;
+use std::ops::ControlFlow;
+
 use roaring::RoaringBitmap;
 
 use super::logger::SearchLogger;
 use super::ranking_rules::{BoxRankingRule, RankingRuleQueryTrait};
 use super::SearchContext;
 use crate::score_details::{ScoreDetails, ScoringStrategy};
-use crate::search::new::distinct::{apply_distinct_rule, distinct_single_docid, DistinctOutput};
-use crate::{Result, TimeBudget};
+use crate::search::new::distinct::{
+    apply_distinct_rule, distinct_single_docid, ContentDistinct, DistinctOutput,
+};
+use crate::{DocumentId, Result, TimeBudget};
 
 pub struct BucketSortOutput {
     pub docids: Vec<u32>,
@@ -15,6 +20,10 @@ pub struct BucketSortOutput {
     pub degraded: bool,
 }
 
+/// Receives the ranked document ids one at a time instead of collecting them
+/// in the [`BucketSortOutput`], see [`crate::Search::for_each_ranked`].
+pub type RankedCallback<'f> = &'f mut dyn FnMut(DocumentId) -> ControlFlow<()>;
+
 // TODO: would probably be good to regroup some of these inside of a struct?
 #[allow(clippy::too_many_arguments)]
 #[tracing::instrument(level = "trace", skip_all, target = "search::bucket_sort")]
@@ -30,6 +39,7 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
     logger: &mut dyn SearchLogger<Q>,
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
+    mut ranked_callback: Option<RankedCallback<'_>>,
 ) -> Result<BucketSortOutput> {
     logger.initial_query(query);
     logger.ranking_rules(&ranking_rules);
@@ -46,6 +56,8 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
         None
     };
 
+    let mut content_distinct = ctx.distinct_content_fids.clone().map(ContentDistinct::new);
+
     if universe.len() < from as u64 {
         return Ok(BucketSortOutput {
             docids: vec![],
@@ -55,10 +67,22 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
         });
     }
     if ranking_rules.is_empty() {
-        if let Some(distinct_fid) = distinct_fid {
-            let mut excluded = RoaringBitmap::new();
+        if distinct_fid.is_some() || content_distinct.is_some() {
+            // all the documents are equally ranked, the representatives must be elected
+            // among all of them before collecting the results
+            let candidates = match (distinct_fid, ctx.distinct_representative_fid) {
+                (Some(distinct_fid), Some(_)) => {
+                    Cow::Owned(apply_distinct_rule(ctx, distinct_fid, universe)?.remaining)
+                }
+                _ => Cow::Borrowed(universe),
+            };
+
+            let mut excluded = match &candidates {
+                Cow::Owned(representatives) => universe - representatives,
+                Cow::Borrowed(_) => RoaringBitmap::new(),
+            };
             let mut results = vec![];
-            for docid in universe.iter() {
+            for docid in candidates.iter() {
                 if results.len() >= from + length {
                     break;
                 }
@@ -66,8 +90,23 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                     continue;
                 }
 
-                distinct_single_docid(ctx.index, ctx.txn, distinct_fid, docid, &mut excluded)?;
-                results.push(docid);
+                if let Some(content_distinct) = &mut content_distinct {
+                    if !content_distinct.insert(ctx.index, ctx.txn, docid)? {
+                        excluded.insert(docid);
+                        continue;
+                    }
+                }
+                if let Some(distinct_fid) = distinct_fid {
+                    distinct_single_docid(ctx.index, ctx.txn, distinct_fid, docid, &mut excluded)?;
+                }
+                match ranked_callback.as_deref_mut() {
+                    Some(callback) => {
+                        if callback(docid).is_break() {
+                            break;
+                        }
+                    }
+                    None => results.push(docid),
+                }
             }
 
             let mut all_candidates = universe - excluded;
@@ -87,6 +126,18 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                 all_candidates,
                 degraded: false,
             });
+        } else if let Some(callback) = ranked_callback {
+            for docid in universe.iter().skip(from).take(length) {
+                if callback(docid).is_break() {
+                    break;
+                }
+            }
+            return Ok(BucketSortOutput {
+                docids: vec![],
+                scores: vec![],
+                all_candidates: universe.clone(),
+                degraded: false,
+            });
         } else {
             let docids: Vec<u32> = universe.iter().skip(from).take(length).collect();
             return Ok(BucketSortOutput {
@@ -147,7 +198,7 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
 
     macro_rules! maybe_add_to_results {
         ($candidates:expr) => {
-            maybe_add_to_results(
+            let flow = maybe_add_to_results(
                 ctx,
                 from,
                 length,
@@ -160,9 +211,20 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
                 cur_ranking_rule_index,
                 &mut cur_offset,
                 distinct_fid,
+                &mut content_distinct,
                 &ranking_rule_scores,
+                &mut ranked_callback,
                 $candidates,
             )?;
+            // the callback doesn't want more documents
+            if flow.is_break() {
+                return Ok(BucketSortOutput {
+                    docids: valid_docids,
+                    scores: valid_scores,
+                    all_candidates,
+                    degraded: false,
+                });
+            }
         };
     }
 
@@ -282,8 +344,10 @@ pub fn bucket_sort<'ctx, Q: RankingRuleQueryTrait>(
     })
 }
 
-/// Add the candidates to the results. Take `distinct`, `from`, `length`, and `cur_offset`
-/// into account and inform the logger.
+/// Add the candidates to the results, or give them to the ranked callback. Take `distinct`,
+/// `content_distinct`, `from`, `length`, and `cur_offset` into account and inform the logger.
+///
+/// Returns [`ControlFlow::Break`] when the ranked callback doesn't want more documents.
 #[allow(clippy::too_many_arguments)]
 fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
     ctx: &mut SearchContext<'ctx>,
@@ -303,9 +367,11 @@ fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
     cur_offset: &mut usize,
 
     distinct_fid: Option<u16>,
+    content_distinct: &mut Option<ContentDistinct>,
     ranking_rule_scores: &[ScoreDetails],
+    ranked_callback: &mut Option<RankedCallback<'_>>,
     candidates: RoaringBitmap,
-) -> Result<()> {
+) -> Result<ControlFlow<()>> {
     // First apply the distinct rule on the candidates, reducing the universes if necessary
     let candidates = if let Some(distinct_fid) = distinct_fid {
         let DistinctOutput { remaining, excluded } =
@@ -318,11 +384,34 @@ fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
     } else {
         candidates.clone()
     };
+    // Then collapse the candidates whose content was already seen
+    let candidates = if let Some(content_distinct) = content_distinct {
+        let DistinctOutput { remaining, excluded } =
+            content_distinct.apply(ctx.index, ctx.txn, &candidates)?;
+        for universe in ranking_rule_universes.iter_mut() {
+            *universe -= &excluded;
+        }
+        *all_candidates -= &excluded;
+        remaining
+    } else {
+        candidates
+    };
     *all_candidates |= &candidates;
 
     // if the candidates are empty, there is nothing to do;
     if candidates.is_empty() {
-        return Ok(());
+        return Ok(ControlFlow::Continue(()));
+    }
+
+    // the documents are given to the callback in place of being collected
+    if let Some(callback) = ranked_callback {
+        *cur_offset += candidates.len() as usize;
+        for docid in candidates.iter().take(length - valid_docids.len()) {
+            if callback(docid).is_break() {
+                return Ok(ControlFlow::Break(()));
+            }
+        }
+        return Ok(ControlFlow::Continue(()));
     }
 
     // if we still haven't reached the first document to return
@@ -362,5 +451,5 @@ fn maybe_add_to_results<'ctx, Q: RankingRuleQueryTrait>(
     }
 
     *cur_offset += candidates.len() as usize;
-    Ok(())
+    Ok(ControlFlow::Continue(()))
 }
@@ -10,6 +10,7 @@ use roaring::RoaringBitmap;
 
 use super::interner::Interned;
 use super::Word;
+use crate::heed_codec::facet::FacetGroupKey;
 use crate::heed_codec::{BytesDecodeOwned, StrBEU16Codec};
 use crate::proximity::ProximityPrecision;
 use crate::update::MergeCboRoaringBitmaps;
@@ -45,6 +46,8 @@ pub struct DatabaseCache<'ctx> {
     pub word_prefix_fid_docids: FxHashMap<(Interned<String>, u16), Option<Cow<'ctx, [u8]>>>,
     pub word_fids: FxHashMap<Interned<String>, Vec<u16>>,
     pub word_prefix_fids: FxHashMap<Interned<String>, Vec<u16>>,
+
+    pub number_facet_docids: FxHashMap<Interned<String>, RoaringBitmap>,
 }
 impl<'ctx> DatabaseCache<'ctx> {
     fn get_value<'v, K1, KC>(
@@ -551,6 +554,43 @@ impl<'ctx> SearchContext<'ctx> {
         )
     }
 
+    /// Returns the documents that contain the number represented by this all-digit word
+    /// as a numeric facet value of any of the filterable fields.
+    ///
+    /// Numbers are compared with an exact equality, a word that isn't made of digits only
+    /// never matches any facet value.
+    pub fn get_number_facet_docids(
+        &mut self,
+        universe: Option<&RoaringBitmap>,
+        word: Interned<String>,
+    ) -> Result<RoaringBitmap> {
+        let docids = match self.db_cache.number_facet_docids.entry(word) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let word = self.word_interner.get(word);
+                let mut docids = RoaringBitmap::new();
+                if !word.is_empty() && word.bytes().all(|b| b.is_ascii_digit()) {
+                    if let Ok(number) = word.parse::<f64>() {
+                        for field_id in self.index.filterable_fields_ids(self.txn)? {
+                            let key = FacetGroupKey { field_id, level: 0, left_bound: number };
+                            if let Some(value) =
+                                self.index.facet_id_f64_docids.get(self.txn, &key)?
+                            {
+                                docids |= value.bitmap;
+                            }
+                        }
+                    }
+                }
+                entry.insert(docids)
+            }
+        };
+
+        match universe {
+            Some(universe) => Ok(&*docids & universe),
+            None => Ok(docids.clone()),
+        }
+    }
+
     pub fn get_db_word_prefix_fid_docids(
         &mut self,
         universe: Option<&RoaringBitmap>,
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use heed::types::{Bytes, Str, Unit};
 use heed::{Database, RoPrefix, RoTxn};
 use roaring::RoaringBitmap;
@@ -9,7 +11,8 @@ use crate::heed_codec::facet::{
     FacetGroupKey, FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetCodec,
 };
 use crate::heed_codec::BytesRefCodec;
-use crate::{Index, Result, SearchContext};
+use crate::search::facet::descending_facet_sort;
+use crate::{DocumentId, FieldId, Index, Result, SearchContext};
 
 pub struct DistinctOutput {
     pub remaining: RoaringBitmap,
@@ -22,11 +25,21 @@ pub struct DistinctOutput {
 ///   is considered unique.
 /// - `excluded`: the set of document ids that contain a value for the given field that occurs
 ///   in the given candidates.
+///
+/// The kept document is the one with the smallest id, or the one with the highest value for
+/// the [`SearchContext::distinct_representative_fid`] if any, see [`representative_order`].
 pub fn apply_distinct_rule(
     ctx: &mut SearchContext<'_>,
     field_id: u16,
     candidates: &RoaringBitmap,
 ) -> Result<DistinctOutput> {
+    let candidates = match ctx.distinct_representative_fid {
+        Some(representative_fid) => itertools::Either::Left(
+            representative_order(ctx.index, ctx.txn, representative_fid, candidates)?.into_iter(),
+        ),
+        None => itertools::Either::Right(candidates.iter()),
+    };
+
     let mut excluded = RoaringBitmap::new();
     let mut remaining = RoaringBitmap::new();
     for docid in candidates {
@@ -34,11 +47,42 @@ pub fn apply_distinct_rule(
             continue;
         }
         distinct_single_docid(ctx.index, ctx.txn, field_id, docid, &mut excluded)?;
-        remaining.push(docid);
+        remaining.insert(docid);
     }
     Ok(DistinctOutput { remaining, excluded })
 }
 
+/// Returns the candidates from the highest value of the given field to the lowest.
+///
+/// The numbers come before the strings, which include the booleans so that `true` comes
+/// before `false`, and the candidates without any value come last. The candidates with the
+/// same value are in ascending id order.
+pub fn representative_order(
+    index: &Index,
+    txn: &RoTxn<'_>,
+    field_id: FieldId,
+    candidates: &RoaringBitmap,
+) -> Result<Vec<DocumentId>> {
+    let number_db = index.facet_id_f64_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>();
+    let string_db =
+        index.facet_id_string_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>();
+
+    let mut ordered = RoaringBitmap::new();
+    let mut order = Vec::with_capacity(candidates.len() as usize);
+    let numbers = descending_facet_sort(txn, number_db, field_id, candidates.clone())?;
+    let strings = descending_facet_sort(txn, string_db, field_id, candidates.clone())?;
+    for result in numbers.chain(strings) {
+        let (docids, _) = result?;
+        // a document with several values is ordered by the highest one
+        let docids = docids - &ordered;
+        order.extend(&docids);
+        ordered |= docids;
+    }
+    order.extend(&(candidates - ordered));
+
+    Ok(order)
+}
+
 /// Apply the distinct rule defined by [`apply_distinct_rule`] for a single document id.
 pub fn distinct_single_docid(
     index: &Index,
@@ -69,6 +113,60 @@ pub fn distinct_single_docid(
     Ok(())
 }
 
+/// Collapses the documents whose values for the given fields are identical,
+/// keeping the first document seen for each content.
+///
+/// The content is built on the fly from the stored values of the fields, two documents
+/// are collapsed only if these values are exactly the same, there is no fuzzy matching.
+/// The contents are kept whole, not hashed, so that two different contents are never
+/// collapsed. A document that has none of the fields is considered unique.
+pub struct ContentDistinct {
+    fields_ids: Vec<FieldId>,
+    seen: HashSet<Vec<u8>>,
+}
+
+impl ContentDistinct {
+    pub fn new(fields_ids: Vec<FieldId>) -> Self {
+        ContentDistinct { fields_ids, seen: HashSet::new() }
+    }
+
+    /// Returns `false` if a document with the same content was already seen.
+    pub fn insert(&mut self, index: &Index, txn: &RoTxn<'_>, docid: DocumentId) -> Result<bool> {
+        let document = index.document(txn, docid)?;
+        let mut content = Vec::new();
+        for &fid in &self.fields_ids {
+            if let Some(value) = document.get(fid) {
+                // the length delimits the values of the consecutive fields
+                content.extend_from_slice(&fid.to_be_bytes());
+                content.extend_from_slice(&(value.len() as u32).to_be_bytes());
+                content.extend_from_slice(value);
+            }
+        }
+
+        Ok(content.is_empty() || self.seen.insert(content))
+    }
+
+    /// Return a [`DistinctOutput`] where the `remaining` documents are the candidates
+    /// whose content was not seen before, the other candidates are `excluded`.
+    pub fn apply(
+        &mut self,
+        index: &Index,
+        txn: &RoTxn<'_>,
+        candidates: &RoaringBitmap,
+    ) -> Result<DistinctOutput> {
+        let mut excluded = RoaringBitmap::new();
+        let mut remaining = RoaringBitmap::new();
+        for docid in candidates {
+            if self.insert(index, txn, docid)? {
+                remaining.push(docid);
+            } else {
+                excluded.push(docid);
+            }
+        }
+        Ok(DistinctOutput { remaining, excluded })
+    }
+}
+
 /// Return all the docids containing the given value in the given field
 fn facet_value_docids(
     database: Database<FacetGroupKeyCodec<BytesRefCodec>, FacetGroupValueCodec>,
@@ -151,9 +151,18 @@ impl<'ctx, G: RankingRuleGraphTrait> RankingRule<'ctx, QueryGraph> for GraphBase
                 // With the `1` that `next_max_cost` is initialized with, this gets counted twice.
                 .saturating_sub(1) as u64;
             match terms_matching_strategy {
-                TermsMatchingStrategy::Last => {
-                    let removal_order =
-                        query_graph.removal_order_for_terms_matching_strategy_last(ctx);
+                TermsMatchingStrategy::Last
+                | TermsMatchingStrategy::First
+                | TermsMatchingStrategy::Size => {
+                    let removal_order = match terms_matching_strategy {
+                        TermsMatchingStrategy::First => {
+                            query_graph.removal_order_for_terms_matching_strategy_first(ctx)
+                        }
+                        TermsMatchingStrategy::Size => {
+                            query_graph.removal_order_for_terms_matching_strategy_size(ctx)
+                        }
+                        _ => query_graph.removal_order_for_terms_matching_strategy_last(ctx),
+                    };
                     let mut forbidden_nodes =
                         SmallBitmap::for_interned_values_in(&query_graph.nodes);
                     let mut costs = query_graph.nodes.map(|_| None);
@@ -166,9 +175,20 @@ impl<'ctx, G: RankingRuleGraphTrait> RankingRule<'ctx, QueryGraph> for GraphBase
                     }
                     costs
                 }
-                TermsMatchingStrategy::Frequency => {
-                    let removal_order =
-                        query_graph.removal_order_for_terms_matching_strategy_frequency(ctx)?;
+                TermsMatchingStrategy::Frequency
+                | TermsMatchingStrategy::FrequencyWeighted
+                | TermsMatchingStrategy::RequireRare { .. } => {
+                    let removal_order = match terms_matching_strategy {
+                        TermsMatchingStrategy::FrequencyWeighted => query_graph
+                            .removal_order_for_terms_matching_strategy_frequency_weighted(ctx)?,
+                        TermsMatchingStrategy::RequireRare { min_idf } => query_graph
+                            .removal_order_for_terms_matching_strategy_require_rare(
+                                ctx, min_idf.0,
+                            )?,
+                        _ => {
+                            query_graph.removal_order_for_terms_matching_strategy_frequency(ctx)?
+                        }
+                    };
                     let mut forbidden_nodes =
                         SmallBitmap::for_interned_values_in(&query_graph.nodes);
                     let mut costs = query_graph.nodes.map(|_| None);
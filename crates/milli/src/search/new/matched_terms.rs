@@ -0,0 +1,65 @@
+use roaring::RoaringBitmap;
+
+use super::query_term::{LocatedQueryTerm, QueryTermSubset};
+use super::resolve_query_graph::compute_query_term_subset_docids;
+use super::SearchContext;
+use crate::{DocumentId, Result};
+
+/// Returns, for each of the given documents, the words of the query that matched it.
+///
+/// A word of the query matches a document when the document contains any of its derivations:
+/// the word itself, its typo corrections, its synonyms or, for the last word, its prefixes.
+/// A document matching an ngram made of several words of the query, or a split of one of
+/// them, is considered matched by every word of the ngram. Phrases are returned between
+/// double quotes and the words are ordered by their position in the query.
+///
+/// The documents ids of every query term are fetched once and restricted to the given
+/// documents, which costs one word docids lookup for every derivation of every query term.
+pub fn matched_terms(
+    ctx: &mut SearchContext<'_>,
+    located_query_terms: &[LocatedQueryTerm],
+    documents_ids: &[DocumentId],
+) -> Result<Vec<Vec<String>>> {
+    let universe: RoaringBitmap = documents_ids.iter().copied().collect();
+
+    // the ngrams are appended after the original terms, we only name the original ones
+    let mut words = Vec::new();
+    for term in located_query_terms {
+        let query_term = ctx.term_interner.get(term.value);
+        if query_term.is_ngram() {
+            continue;
+        }
+        let word = match query_term.original_phrase() {
+            Some(phrase) => format!("\"{}\"", phrase.description(ctx)),
+            None => query_term.original_word(ctx),
+        };
+        words.push((*term.positions.start(), word));
+    }
+    words.sort_unstable_by_key(|(position, _)| *position);
+
+    let mut matched = vec![RoaringBitmap::new(); words.len()];
+    for term in located_query_terms {
+        let subset = QueryTermSubset::full(term.value);
+        let docids = compute_query_term_subset_docids(ctx, Some(&universe), &subset)?;
+        if docids.is_empty() {
+            continue;
+        }
+        for ((position, _), matched) in words.iter().zip(&mut matched) {
+            if term.positions.contains(position) {
+                *matched |= &docids;
+            }
+        }
+    }
+
+    Ok(documents_ids
+        .iter()
+        .map(|docid| {
+            words
+                .iter()
+                .zip(&matched)
+                .filter(|(_, matched)| matched.contains(*docid))
+                .map(|((_, word), _)| word.clone())
+                .collect()
+        })
+        .collect())
+}
@@ -5,6 +5,7 @@ mod simple_token_kind;
 
 use std::borrow::Cow;
 use std::cmp::{max, min};
+use std::collections::BTreeMap;
 
 use charabia::{Language, SeparatorKind, Token, Tokenizer};
 use either::Either;
@@ -12,9 +13,12 @@ pub use matching_words::MatchingWords;
 use matching_words::{MatchType, PartialMatch};
 use r#match::{Match, MatchPosition};
 use serde::Serialize;
+use serde_json::Value;
 use simple_token_kind::SimpleTokenKind;
 use utoipa::ToSchema;
 
+use crate::{FieldsIdsMap, InternalError, Result};
+
 const DEFAULT_CROP_MARKER: &str = "…";
 const DEFAULT_HIGHLIGHT_PREFIX: &str = "<em>";
 const DEFAULT_HIGHLIGHT_SUFFIX: &str = "</em>";
@@ -83,17 +87,92 @@ impl<'m> MatcherBuilder<'m> {
             locales,
         }
     }
+
+    /// Returns the bounds of the matches in the given top-level attributes of a document.
+    ///
+    /// Only the strings are matched, either the value of the attribute or the elements of an
+    /// array, the latter being identified by their index. The attributes without match are omitted.
+    /// The matches are the ones kept in the text formatted with the given options,
+    /// see [`Matcher::formatted_matches`].
+    pub fn document_matches(
+        &self,
+        document: &obkv::KvReaderU16,
+        fields_ids_map: &FieldsIdsMap,
+        attributes: &[String],
+        format_options: FormatOptions,
+        locales: Option<&[Language]>,
+    ) -> Result<BTreeMap<String, Vec<MatchBounds>>> {
+        let mut matches = BTreeMap::new();
+        for attribute in attributes {
+            let Some(value) = fields_ids_map.id(attribute).and_then(|fid| document.get(fid)) else {
+                continue;
+            };
+            let value: Value = serde_json::from_slice(value).map_err(InternalError::SerdeJson)?;
+
+            let mut bounds = Vec::new();
+            match value {
+                Value::String(text) => {
+                    bounds.extend(self.build(&text, locales).formatted_matches(&[], format_options))
+                }
+                Value::Array(values) => {
+                    for (index, value) in values.iter().enumerate() {
+                        if let Value::String(text) = value {
+                            let mut matcher = self.build(text, locales);
+                            bounds.extend(matcher.formatted_matches(&[index], format_options));
+                        }
+                    }
+                }
+                _ => (),
+            }
+
+            if !bounds.is_empty() {
+                matches.insert(attribute.clone(), bounds);
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Returns the matching words used to build the matchers.
+    pub fn into_matching_words(self) -> MatchingWords {
+        self.matching_words
+    }
+}
+
+/// The unit in which the crop length of the [`FormatOptions`] is counted.
+///
+/// Whatever the unit, a text that is not longer than the crop length is returned whole.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum CropUnit {
+    /// Words, the separators between them are not counted.
+    #[default]
+    Words,
+    /// UTF-8 bytes, the cropped text can start or end in the middle
+    /// of a word but never in the middle of a character.
+    Bytes,
+    /// Characters, the cropped text is only made of whole tokens,
+    /// unless the match alone is longer than the crop length.
+    Chars,
 }
 
 #[derive(Copy, Clone, Default, Debug)]
 pub struct FormatOptions {
+    /// Surround every match with the highlight prefix and suffix.
     pub highlight: bool,
+    /// The number of units to keep around the best matches, `None` or `Some(0)`
+    /// returns the whole text, however long it is.
     pub crop: Option<usize>,
+    /// The unit in which the crop length is counted.
+    pub crop_unit: CropUnit,
 }
 
 impl FormatOptions {
     pub fn merge(self, other: Self) -> Self {
-        Self { highlight: self.highlight || other.highlight, crop: self.crop.or(other.crop) }
+        let crop_unit = if self.crop.is_some() { self.crop_unit } else { other.crop_unit };
+        Self {
+            highlight: self.highlight || other.highlight,
+            crop: self.crop.or(other.crop),
+            crop_unit,
+        }
     }
 
     pub fn should_format(&self) -> bool {
@@ -241,6 +320,47 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
         }
     }
 
+    /// Returns boundaries of the words that match the query and are kept in the text
+    /// formatted with the given options.
+    ///
+    /// Only the crop removes matches, the ones out of the crop window, and the bounds
+    /// are always relative to the original text.
+    pub fn formatted_matches(
+        &mut self,
+        array_indices: &[usize],
+        format_options: FormatOptions,
+    ) -> Vec<MatchBounds> {
+        let bounds = self.matches(array_indices);
+        match &self.matches {
+            Some((tokens, matches)) if format_options.crop.is_some_and(|size| size > 0) => {
+                let [crop_byte_start, crop_byte_end] =
+                    self.crop_window(tokens, matches, format_options);
+                bounds
+                    .into_iter()
+                    .filter(|m| m.start >= crop_byte_start && m.start < crop_byte_end)
+                    .collect()
+            }
+            _ => bounds,
+        }
+    }
+
+    /// Returns the bounds in byte index of the text kept with the given format options.
+    fn crop_window(
+        &self,
+        tokens: &[Token<'_>],
+        matches: &[Match],
+        format_options: FormatOptions,
+    ) -> [usize; 2] {
+        match format_options.crop {
+            Some(crop_size) if crop_size > 0 => match format_options.crop_unit {
+                CropUnit::Words => self.crop_bounds(tokens, matches, crop_size),
+                CropUnit::Bytes => self.crop_bounds_in_bytes(tokens, matches, crop_size, false),
+                CropUnit::Chars => self.crop_bounds_in_chars(tokens, matches, crop_size),
+            },
+            _ => [0, self.text.len()],
+        }
+    }
+
     /// Returns the bounds in byte index of the crop window.
     fn crop_bounds(&self, tokens: &[Token<'_>], matches: &[Match], crop_size: usize) -> [usize; 2] {
         let (
@@ -380,6 +500,110 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
         [crop_byte_start, crop_byte_end]
     }
 
+    /// Returns the bounds in byte index of a crop window of at most `crop_size` characters.
+    ///
+    /// The window starts on the first match and only contains whole tokens, tokens are
+    /// alternatively added before and after it while they fit in the window. A match,
+    /// or a first token, longer than the window is cut at `crop_size` characters.
+    fn crop_bounds_in_chars(
+        &self,
+        tokens: &[Token<'_>],
+        matches: &[Match],
+        crop_size: usize,
+    ) -> [usize; 2] {
+        let char_count =
+            |token: &Token<'_>| self.text[token.byte_start..token.byte_end].chars().count();
+
+        let (mut first, mut last) = match matches.first() {
+            Some(m) => (m.get_first_token_pos(), m.get_last_token_pos()),
+            None => match tokens.first() {
+                Some(token) if char_count(token) <= crop_size => (0, 0),
+                _ => return self.crop_bounds_in_bytes(tokens, matches, crop_size, true),
+            },
+        };
+
+        let mut size: usize = tokens[first..=last].iter().map(char_count).sum();
+        if size > crop_size {
+            // the match itself doesn't fit in the window, it is cut.
+            return self.crop_bounds_in_bytes(tokens, matches, crop_size, true);
+        }
+
+        loop {
+            let mut grown = false;
+            if let Some(before) = first.checked_sub(1) {
+                let count = char_count(&tokens[before]);
+                if size + count <= crop_size {
+                    first = before;
+                    size += count;
+                    grown = true;
+                }
+            }
+            if let Some(after) = tokens.get(last + 1) {
+                let count = char_count(after);
+                if size + count <= crop_size {
+                    last += 1;
+                    size += count;
+                    grown = true;
+                }
+            }
+            if !grown {
+                break;
+            }
+        }
+
+        [tokens[first].byte_start, tokens[last].byte_end]
+    }
+
+    /// Returns the bounds in byte index of a crop window of `crop_size` bytes,
+    /// or characters when `in_chars` is `true`, around the first match.
+    ///
+    /// The bounds are moved inside the window to never split a character.
+    fn crop_bounds_in_bytes(
+        &self,
+        tokens: &[Token<'_>],
+        matches: &[Match],
+        crop_size: usize,
+        in_chars: bool,
+    ) -> [usize; 2] {
+        let (match_start, match_end) = match matches.first() {
+            Some(m) => (
+                tokens[m.get_first_token_pos()].byte_start,
+                tokens[m.get_last_token_pos()].byte_end,
+            ),
+            None => (0, 0),
+        };
+
+        // the byte index of every character and of the end of the text, when counting characters.
+        let offsets: Option<Vec<usize>> = in_chars
+            .then(|| self.text.char_indices().map(|(i, _)| i).chain([self.text.len()]).collect());
+        let position = |byte_index: usize| match &offsets {
+            Some(offsets) => offsets.partition_point(|&i| i < byte_index),
+            None => byte_index,
+        };
+        let byte_index = |position: usize| match &offsets {
+            Some(offsets) => offsets[position],
+            None => position,
+        };
+        let (match_start, match_end) = (position(match_start), position(match_end));
+        let total = position(self.text.len());
+
+        // center the match in the window, or keep its start when it is too long.
+        let before = crop_size.saturating_sub(match_end - match_start) / 2;
+        let mut start = match_start - min(before, match_start);
+        let end = min(start + crop_size, total);
+        start = min(start, end.saturating_sub(crop_size));
+
+        let [mut start, mut end] = [byte_index(start), byte_index(end)];
+        while !self.text.is_char_boundary(start) {
+            start += 1;
+        }
+        while !self.text.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        [start, max(start, end)]
+    }
+
     // Returns the formatted version of the original text.
     pub fn format(&mut self, format_options: FormatOptions) -> Cow<'t, str> {
         if !format_options.highlight && format_options.crop.is_none() {
@@ -389,12 +613,8 @@ impl<'t, 'tokenizer> Matcher<'t, 'tokenizer, '_, '_> {
             match &self.matches {
                 Some((tokens, matches)) => {
                     // If the text has to be cropped, crop around the best interval.
-                    let [crop_byte_start, crop_byte_end] = match format_options.crop {
-                        Some(crop_size) if crop_size > 0 => {
-                            self.crop_bounds(tokens, matches, crop_size)
-                        }
-                        _ => [0, self.text.len()],
-                    };
+                    let [crop_byte_start, crop_byte_end] =
+                        self.crop_window(tokens, matches, format_options);
 
                     let mut formatted = Vec::new();
 
@@ -500,6 +720,8 @@ mod tests {
                 false,
                 universe,
                 &None,
+                crate::search::FilterStrategy::default(),
+                &None,
                 &None,
                 crate::search::new::GeoSortStrategy::default(),
                 0,
@@ -510,6 +732,7 @@ mod tests {
                 TimeBudget::max(),
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -529,7 +752,7 @@ mod tests {
         let rtxn = temp_index.read_txn().unwrap();
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "split the world");
 
-        let format_options = FormatOptions { highlight: false, crop: None };
+        let format_options = FormatOptions { highlight: false, crop: None, ..Default::default() };
 
         // Text without any match.
         let text = "A quick brown fox can not jump 32 feet, right? Brr, it is cold!";
@@ -556,7 +779,7 @@ mod tests {
         let rtxn = temp_index.read_txn().unwrap();
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "split the world");
 
-        let format_options = FormatOptions { highlight: true, crop: None };
+        let format_options = FormatOptions { highlight: true, crop: None, ..Default::default() };
 
         // empty text.
         let text = "";
@@ -598,7 +821,7 @@ mod tests {
         let temp_index = temp_index_with_documents();
         let rtxn = temp_index.read_txn().unwrap();
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world");
-        let format_options = FormatOptions { highlight: true, crop: None };
+        let format_options = FormatOptions { highlight: true, crop: None, ..Default::default() };
 
         // Text containing prefix match.
         let text = "Ŵôřlḑôle";
@@ -619,7 +842,7 @@ mod tests {
         );
 
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "westfali");
-        let format_options = FormatOptions { highlight: true, crop: None };
+        let format_options = FormatOptions { highlight: true, crop: None, ..Default::default() };
 
         // Text containing unicode match.
         let text = "Westfália";
@@ -631,13 +854,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn highlight_long_text_without_crop() {
+        let temp_index = temp_index_with_documents();
+        let rtxn = temp_index.read_txn().unwrap();
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "split the world");
+        let format_options = FormatOptions { highlight: true, crop: None, ..Default::default() };
+
+        // a text far longer than the default crop length is returned in full,
+        // without crop marker, and every one of its matches is highlighted.
+        let sentence = "Emily Henry: The Love That Split The World. ";
+        let text = sentence.repeat(100);
+        let mut matcher = builder.build(&text, None);
+        let formatted = matcher.format(format_options);
+
+        let highlighted =
+            "Emily Henry: <em>The</em> Love That <em>Split</em> <em>The</em> <em>World</em>. ";
+        assert_eq!(formatted, highlighted.repeat(100));
+        assert!(!formatted.contains('…'));
+    }
+
     #[test]
     fn format_crop() {
         let temp_index = temp_index_with_documents();
         let rtxn = temp_index.read_txn().unwrap();
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "split the world");
 
-        let format_options = FormatOptions { highlight: false, crop: Some(10) };
+        let format_options =
+            FormatOptions { highlight: false, crop: Some(10), ..Default::default() };
 
         // empty text.
         let text = "";
@@ -734,7 +978,8 @@ mod tests {
         let rtxn = temp_index.read_txn().unwrap();
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "split the world");
 
-        let format_options = FormatOptions { highlight: true, crop: Some(10) };
+        let format_options =
+            FormatOptions { highlight: true, crop: Some(10), ..Default::default() };
 
         // empty text.
         let text = "";
@@ -803,7 +1048,8 @@ mod tests {
 
         let rtxn = temp_index.read_txn().unwrap();
 
-        let format_options = FormatOptions { highlight: true, crop: Some(10) };
+        let format_options =
+            FormatOptions { highlight: true, crop: Some(10), ..Default::default() };
 
         let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "\"the world\"");
         let mut matcher = builder.build(text, None);
@@ -878,6 +1124,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_crop_units() {
+        let temp_index = temp_index_with_documents();
+        let rtxn = temp_index.read_txn().unwrap();
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, "world");
+
+        // every `é` is one character made of two bytes.
+        let text = "éé éé world éé éé";
+        let format = |crop, crop_unit| {
+            let format_options = FormatOptions { highlight: false, crop: Some(crop), crop_unit };
+            builder.build(text, None).format(format_options).into_owned()
+        };
+
+        insta::assert_snapshot!(format(1, CropUnit::Words), @"…world…");
+
+        // the crop window is centered on the match and never splits a character.
+        insta::assert_snapshot!(format(8, CropUnit::Bytes), @"… world …");
+        insta::assert_snapshot!(format(11, CropUnit::Bytes), @"…é world é…");
+
+        // only whole tokens are kept, unless the match itself is longer than the crop.
+        insta::assert_snapshot!(format(9, CropUnit::Chars), @"…éé world …");
+        insta::assert_snapshot!(format(3, CropUnit::Chars), @"…wor…");
+
+        // a crop longer than the text returns the whole text, without markers.
+        insta::assert_snapshot!(format(100, CropUnit::Words), @"éé éé world éé éé");
+        insta::assert_snapshot!(format(100, CropUnit::Bytes), @"éé éé world éé éé");
+        insta::assert_snapshot!(format(100, CropUnit::Chars), @"éé éé world éé éé");
+    }
+
     #[test]
     fn smaller_crop_size() {
         //! testing: https://github.com/meilisearch/specifications/pull/120#discussion_r836536295
@@ -888,7 +1163,8 @@ mod tests {
         let text = "void void split the world void void.";
 
         // set a smaller crop size
-        let format_options = FormatOptions { highlight: false, crop: Some(2) };
+        let format_options =
+            FormatOptions { highlight: false, crop: Some(2), ..Default::default() };
         let mut matcher = builder.build(text, None);
         // because crop size < query size, partially format matches.
         insta::assert_snapshot!(
@@ -897,7 +1173,8 @@ mod tests {
         );
 
         // set a smaller crop size
-        let format_options = FormatOptions { highlight: false, crop: Some(1) };
+        let format_options =
+            FormatOptions { highlight: false, crop: Some(1), ..Default::default() };
         let mut matcher = builder.build(text, None);
         // because crop size < query size, partially format matches.
         insta::assert_snapshot!(
@@ -906,7 +1183,8 @@ mod tests {
         );
 
         // set  crop size to 0
-        let format_options = FormatOptions { highlight: false, crop: Some(0) };
+        let format_options =
+            FormatOptions { highlight: false, crop: Some(0), ..Default::default() };
         let mut matcher = builder.build(text, None);
         // because crop size is 0, crop is ignored.
         insta::assert_snapshot!(
@@ -924,7 +1202,7 @@ mod tests {
         builder.highlight_prefix("_".to_string());
         builder.highlight_suffix("_".to_string());
 
-        let format_options = FormatOptions { highlight: true, crop: None };
+        let format_options = FormatOptions { highlight: true, crop: None, ..Default::default() };
 
         let text = "the do or die can't be he do and or isn't he";
         let mut matcher = builder.build(text, None);
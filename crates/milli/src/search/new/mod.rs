@@ -6,6 +6,7 @@ mod graph_based_ranking_rule;
 mod interner;
 mod limits;
 mod logger;
+mod matched_terms;
 pub mod matches;
 mod query_graph;
 mod query_term;
@@ -13,6 +14,7 @@ mod ranking_rule_graph;
 mod ranking_rules;
 mod resolve_query_graph;
 mod small_bitmap;
+mod suggestions;
 
 mod exact_attribute;
 mod sort;
@@ -21,8 +23,10 @@ mod vector_sort;
 #[cfg(test)]
 mod tests;
 
+use std::borrow::Cow;
 use std::collections::HashSet;
 
+pub use bucket_sort::RankedCallback;
 use bucket_sort::{bucket_sort, BucketSortOutput};
 use charabia::{Language, TokenizerBuilder};
 use db_cache::DatabaseCache;
@@ -32,16 +36,22 @@ use heed::RoTxn;
 use interner::{DedupInterner, Interner};
 pub use logger::visual::VisualSearchLogger;
 pub use logger::{DefaultSearchLogger, SearchLogger};
-use query_graph::{QueryGraph, QueryNode};
+pub use matched_terms::matched_terms;
+use query_graph::{QueryGraph, QueryNode, QueryNodeData};
 use query_term::{
     located_query_terms_from_tokens, ExtractedTokens, LocatedQueryTerm, Phrase, QueryTerm,
 };
 use ranking_rules::{
     BoxRankingRule, PlaceholderQuery, RankingRule, RankingRuleOutput, RankingRuleQueryTrait,
 };
-use resolve_query_graph::{compute_query_graph_docids, PhraseDocIdsCache};
+use resolve_query_graph::{
+    compute_operator_expression_docids, compute_query_graph_docids,
+    compute_query_graph_docids_within_typo_budget, compute_query_term_subset_docids,
+    PhraseDocIdsCache,
+};
 use roaring::RoaringBitmap;
 use sort::Sort;
+pub use suggestions::{query_suggestions, MAX_QUERY_SUGGESTIONS};
 
 use self::distinct::facet_string_values;
 use self::geo_sort::GeoSort;
@@ -53,7 +63,8 @@ use crate::constants::RESERVED_GEO_FIELD_NAME;
 use crate::index::PrefixSearch;
 use crate::localized_attributes_rules::LocalizedFieldIds;
 use crate::score_details::{ScoreDetails, ScoringStrategy};
-use crate::search::new::distinct::apply_distinct_rule;
+use crate::search::new::distinct::{apply_distinct_rule, ContentDistinct};
+use crate::search::FilterStrategy;
 use crate::vector::Embedder;
 use crate::{
     AscDesc, DocumentId, FieldId, Filter, Index, Member, Result, TermsMatchingStrategy, TimeBudget,
@@ -64,6 +75,7 @@ use crate::{
 pub struct SearchContext<'ctx> {
     pub index: &'ctx Index,
     pub txn: &'ctx RoTxn<'ctx>,
+    pub settings: Cow<'ctx, SearchSettings<'ctx>>,
     pub db_cache: DatabaseCache<'ctx>,
     pub word_interner: DedupInterner<String>,
     pub phrase_interner: DedupInterner<Phrase>,
@@ -71,28 +83,79 @@ pub struct SearchContext<'ctx> {
     pub phrase_docids: PhraseDocIdsCache,
     pub restricted_fids: Option<RestrictedFids>,
     pub prefix_search: PrefixSearch,
+    /// The attributes in which the prefix of the last query word is not expanded.
+    pub prefix_disabled_fids: Vec<FieldId>,
+    /// The searched attributes in which it is expanded, see [`Self::prefix_enabled_fids`].
+    prefix_enabled_fids: Option<Vec<FieldId>>,
+    /// Whether all-digit query words also match the equal numeric facet values.
+    pub match_numeric_facets: bool,
+    /// Overrides the minimal word lengths for one and two typos of the index settings.
+    pub min_word_len_for_typo: Option<(u8, u8)>,
+    /// Whether the total number of typos of a query is bounded by its number of words,
+    /// see [`query_typo_budget`].
+    pub typo_budget: bool,
+    /// Whether the `AND` and `OR` words and the parentheses of the query are boolean operators.
+    pub boolean_operators: bool,
+    /// Whether to report if the returned documents only matched the query through
+    /// typo derivations, see [`crate::SearchResult::had_typo_correction`].
+    pub report_typo_correction: bool,
+    /// The fields whose values are hashed to collapse the identical documents, if any.
+    pub distinct_content_fids: Option<Vec<FieldId>>,
+    /// The field whose highest value elects the document kept among the documents of a
+    /// bucket sharing a distinct value, if any.
+    pub distinct_representative_fid: Option<FieldId>,
 }
 
-impl<'ctx> SearchContext<'ctx> {
-    pub fn new(index: &'ctx Index, txn: &'ctx RoTxn<'ctx>) -> Result<Self> {
-        let searchable_fids = index.searchable_fields_and_weights(txn)?;
-        let exact_attributes_ids = index.exact_attributes_ids(txn)?;
-
-        let mut exact = Vec::new();
-        let mut tolerant = Vec::new();
-        for (_name, fid, weight) in searchable_fids {
-            if exact_attributes_ids.contains(&fid) {
-                exact.push((fid, weight));
-            } else {
-                tolerant.push((fid, weight));
-            }
-        }
+/// The settings of an index that every search reads, regardless of its query.
+///
+/// They can be read once and shared by the searches made with the same transaction,
+/// see [`crate::Search::settings`].
+#[derive(Clone)]
+pub struct SearchSettings<'t> {
+    prefix_search: PrefixSearch,
+    /// Sorted to be binary searched.
+    prefix_disabled_fids: Vec<FieldId>,
+    criteria: Vec<crate::Criterion>,
+    sortable_fields: HashSet<String>,
+    stop_words: Option<fst::Set<&'t [u8]>>,
+}
 
+impl<'t> SearchSettings<'t> {
+    pub fn new(index: &Index, txn: &'t RoTxn<'t>) -> Result<Self> {
         let prefix_search = index.prefix_search(txn)?.unwrap_or_default();
+        let mut prefix_disabled_fids: Vec<_> =
+            index.prefix_disabled_attributes_ids(txn)?.into_iter().collect();
+        prefix_disabled_fids.sort_unstable();
 
         Ok(Self {
+            prefix_search,
+            prefix_disabled_fids,
+            criteria: index.criteria(txn)?,
+            sortable_fields: index.sortable_fields(txn)?,
+            stop_words: index.stop_words(txn)?,
+        })
+    }
+}
+
+impl<'ctx> SearchContext<'ctx> {
+    pub fn new(index: &'ctx Index, txn: &'ctx RoTxn<'ctx>) -> Result<Self> {
+        let settings = SearchSettings::new(index, txn)?;
+        Ok(Self::with_settings(index, txn, Cow::Owned(settings)))
+    }
+
+    /// Creates a search context that doesn't read the settings of the index again.
+    pub fn with_settings(
+        index: &'ctx Index,
+        txn: &'ctx RoTxn<'ctx>,
+        settings: Cow<'ctx, SearchSettings<'ctx>>,
+    ) -> Self {
+        let prefix_search = settings.prefix_search;
+        let prefix_disabled_fids = settings.prefix_disabled_fids.clone();
+
+        Self {
             index,
             txn,
+            settings,
             db_cache: <_>::default(),
             word_interner: <_>::default(),
             phrase_interner: <_>::default(),
@@ -100,13 +163,76 @@ impl<'ctx> SearchContext<'ctx> {
             phrase_docids: <_>::default(),
             restricted_fids: None,
             prefix_search,
-        })
+            prefix_disabled_fids,
+            prefix_enabled_fids: None,
+            match_numeric_facets: false,
+            min_word_len_for_typo: None,
+            typo_budget: false,
+            boolean_operators: false,
+            report_typo_correction: false,
+            distinct_content_fids: None,
+            distinct_representative_fid: None,
+        }
+    }
+
+    /// Returns the minimal word lengths for one and two typos, the ones
+    /// given to the search if any, otherwise the ones of the index settings.
+    pub fn min_word_len_typos(&self) -> Result<(u8, u8)> {
+        match self.min_word_len_for_typo {
+            Some(lengths) => Ok(lengths),
+            None => Ok((
+                self.index.min_word_len_one_typo(self.txn)?,
+                self.index.min_word_len_two_typos(self.txn)?,
+            )),
+        }
     }
 
     pub fn is_prefix_search_allowed(&self) -> bool {
         self.prefix_search != PrefixSearch::Disabled
     }
 
+    /// Returns the searched fields ids in which the prefix of the last query word can be expanded.
+    ///
+    /// They are only computed the first time, the searched fields don't change during a search.
+    pub fn prefix_enabled_fids(&mut self) -> Result<Vec<FieldId>> {
+        if let Some(prefix_enabled_fids) = &self.prefix_enabled_fids {
+            return Ok(prefix_enabled_fids.clone());
+        }
+
+        let searched_fids = match &self.restricted_fids {
+            Some(restricted_fids) => restricted_fids
+                .tolerant
+                .iter()
+                .chain(restricted_fids.exact.iter())
+                .map(|(fid, _)| *fid)
+                .collect(),
+            None => self.index.searchable_fields_ids(self.txn)?,
+        };
+        let prefix_enabled_fids: Vec<_> = searched_fids
+            .into_iter()
+            .filter(|fid| self.prefix_disabled_fids.binary_search(fid).is_err())
+            .collect();
+        self.prefix_enabled_fids = Some(prefix_enabled_fids.clone());
+        Ok(prefix_enabled_fids)
+    }
+
+    /// Collapses the documents whose values for the given fields are identical,
+    /// the fields that don't exist in the index are ignored.
+    pub fn distinct_content(&mut self, fields: &[String]) -> Result<()> {
+        let fields_ids_map = self.index.fields_ids_map(self.txn)?;
+        let fids = fields.iter().filter_map(|field| fields_ids_map.id(field)).collect();
+        self.distinct_content_fids = Some(fids);
+        Ok(())
+    }
+
+    /// Keeps the document with the highest value for the given field among the equally ranked
+    /// documents sharing a distinct value, the field is ignored if it doesn't exist in the index.
+    pub fn distinct_representative(&mut self, field: &str) -> Result<()> {
+        let fields_ids_map = self.index.fields_ids_map(self.txn)?;
+        self.distinct_representative_fid = fields_ids_map.id(field);
+        Ok(())
+    }
+
     pub fn attributes_to_search_on(
         &mut self,
         attributes_to_search_on: &'ctx [String],
@@ -160,6 +286,7 @@ impl<'ctx> SearchContext<'ctx> {
         } else {
             self.restricted_fids = Some(restricted_fids);
         }
+        self.prefix_enabled_fids = None;
 
         Ok(())
     }
@@ -198,6 +325,7 @@ fn resolve_maximally_reduced_query_graph(
     universe: &RoaringBitmap,
     query_graph: &QueryGraph,
     matching_strategy: TermsMatchingStrategy,
+    typo_budget: Option<u8>,
     logger: &mut dyn SearchLogger<QueryGraph>,
 ) -> Result<RoaringBitmap> {
     let mut graph = query_graph.clone();
@@ -208,17 +336,42 @@ fn resolve_maximally_reduced_query_graph(
             .iter()
             .flat_map(|x| x.iter())
             .collect(),
+        TermsMatchingStrategy::First => query_graph
+            .removal_order_for_terms_matching_strategy_first(ctx)
+            .iter()
+            .flat_map(|x| x.iter())
+            .collect(),
+        TermsMatchingStrategy::Size => query_graph
+            .removal_order_for_terms_matching_strategy_size(ctx)
+            .iter()
+            .flat_map(|x| x.iter())
+            .collect(),
         TermsMatchingStrategy::Frequency => query_graph
             .removal_order_for_terms_matching_strategy_frequency(ctx)?
             .iter()
             .flat_map(|x| x.iter())
             .collect(),
+        TermsMatchingStrategy::RequireRare { min_idf } => query_graph
+            .removal_order_for_terms_matching_strategy_require_rare(ctx, min_idf.0)?
+            .iter()
+            .flat_map(|x| x.iter())
+            .collect(),
+        TermsMatchingStrategy::FrequencyWeighted => query_graph
+            .removal_order_for_terms_matching_strategy_frequency_weighted(ctx)?
+            .iter()
+            .flat_map(|x| x.iter())
+            .collect(),
         TermsMatchingStrategy::All => vec![],
     };
     graph.remove_nodes_keep_edges(&nodes_to_remove);
 
     logger.query_for_initial_universe(&graph);
-    let docids = compute_query_graph_docids(ctx, &graph, universe)?;
+    let docids = match typo_budget {
+        Some(typo_budget) => {
+            compute_query_graph_docids_within_typo_budget(ctx, &graph, universe, typo_budget)?
+        }
+        None => compute_query_graph_docids(ctx, &graph, universe)?,
+    };
 
     Ok(docids)
 }
@@ -229,6 +382,7 @@ fn resolve_universe(
     initial_universe: &RoaringBitmap,
     query_graph: &QueryGraph,
     matching_strategy: TermsMatchingStrategy,
+    typo_budget: Option<u8>,
     logger: &mut dyn SearchLogger<QueryGraph>,
 ) -> Result<RoaringBitmap> {
     resolve_maximally_reduced_query_graph(
@@ -236,10 +390,49 @@ fn resolve_universe(
         initial_universe,
         query_graph,
         matching_strategy,
+        typo_budget,
         logger,
     )
 }
 
+/// Returns the maximum number of typos allowed in a whole query of `words_count` words,
+/// or `None` when the typos are only limited by the length of each word.
+///
+/// The queries of one or two words keep the typos allowed for each word, the
+/// longer queries are allowed a single typo in total.
+pub fn query_typo_budget(words_count: usize) -> Option<u8> {
+    match words_count {
+        0..=2 => None,
+        _ => Some(1),
+    }
+}
+
+/// Returns `true` if the rarest word of the query is contained by less than
+/// a tenth of the universe, meaning that the query candidates are few enough
+/// for the filter to be cheaper to evaluate within them.
+///
+/// Only the exact original words are considered, the prefix word and the phrases
+/// are ignored. The word docids are cached and reused when resolving the query.
+fn query_is_selective(
+    ctx: &mut SearchContext<'_>,
+    query_terms: &[LocatedQueryTerm],
+    universe: &RoaringBitmap,
+) -> Result<bool> {
+    let mut rarest = None;
+    for located_term in query_terms {
+        let term = ctx.term_interner.get(located_term.value);
+        if term.is_ngram() || term.is_prefix() || term.original_phrase().is_some() {
+            continue;
+        }
+        let word = term.original_word(ctx);
+        let word = ctx.word_interner.insert(word);
+        let count = ctx.word_docids(Some(universe), Word::Original(word))?.map_or(0, |d| d.len());
+        rarest = Some(rarest.map_or(count, |rarest: u64| rarest.min(count)));
+    }
+
+    Ok(rarest.map_or(false, |rarest| rarest * 10 < universe.len()))
+}
+
 #[tracing::instrument(level = "trace", skip_all, target = "search::query")]
 fn resolve_negative_words(
     ctx: &mut SearchContext<'_>,
@@ -270,7 +463,40 @@ fn resolve_negative_phrases(
     Ok(negative_bitmap)
 }
 
+/// Returns `true` if any of the given documents matched a term of the query
+/// only through one of its typo derivations, split words included.
+#[tracing::instrument(level = "trace", skip_all, target = "search::query")]
+fn matched_with_typos(
+    ctx: &mut SearchContext<'_>,
+    query_graph: &QueryGraph,
+    documents_ids: &[DocumentId],
+) -> Result<bool> {
+    let documents: RoaringBitmap = documents_ids.iter().copied().collect();
+    if documents.is_empty() {
+        return Ok(false);
+    }
+
+    for (_, node) in query_graph.nodes.iter() {
+        let QueryNodeData::Term(term) = &node.data else { continue };
+        let mut zero_typo_subset = term.term_subset.clone();
+        zero_typo_subset.clear_one_typo_subset();
+        zero_typo_subset.clear_two_typo_subset();
+
+        let docids = compute_query_term_subset_docids(ctx, Some(&documents), &term.term_subset)?;
+        let zero_typo_docids =
+            compute_query_term_subset_docids(ctx, Some(&documents), &zero_typo_subset)?;
+        if !(docids - zero_typo_docids).is_empty() {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
 /// Return the list of initialised ranking rules to be used for a placeholder search.
+///
+/// The relevancy ranking rules need a query and are skipped, only the sort rules are kept.
+/// Without any sort rule, the documents are returned in the order of their internal ids.
 fn get_ranking_rules_for_placeholder_search<'ctx>(
     ctx: &SearchContext<'ctx>,
     sort_criteria: &Option<Vec<AscDesc>>,
@@ -280,7 +506,7 @@ fn get_ranking_rules_for_placeholder_search<'ctx>(
     let mut sorted_fields = HashSet::new();
     let mut geo_sorted = false;
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.settings.criteria.clone();
     for rr in settings_ranking_rules {
         match rr {
             // These rules need a query to have an effect; ignore them in placeholder search
@@ -342,7 +568,7 @@ fn get_ranking_rules_for_vector<'ctx>(
     let mut vector = false;
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
 
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.settings.criteria.clone();
     for rr in settings_ranking_rules {
         match rr {
             crate::Criterion::Words
@@ -422,7 +648,7 @@ fn get_ranking_rules_for_query_graph_search<'ctx>(
     }
 
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, QueryGraph>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.settings.criteria.clone();
     for rr in settings_ranking_rules {
         // Add Words before any of: typo, proximity, attribute
         match rr {
@@ -592,6 +818,7 @@ pub fn execute_vector_search(
     quantized: bool,
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
+    ranked_callback: Option<RankedCallback<'_>>,
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
@@ -624,6 +851,7 @@ pub fn execute_vector_search(
         placeholder_search_logger,
         time_budget,
         ranking_score_threshold,
+        ranked_callback,
     )?;
 
     Ok(PartialSearchResult {
@@ -633,6 +861,7 @@ pub fn execute_vector_search(
         located_query_terms: None,
         degraded,
         used_negative_operator: false,
+        had_typo_correction: false,
     })
 }
 
@@ -645,6 +874,8 @@ pub fn execute_search(
     scoring_strategy: ScoringStrategy,
     exhaustive_number_hits: bool,
     mut universe: RoaringBitmap,
+    filter: &Option<Filter<'_>>,
+    filter_strategy: FilterStrategy,
     sort_criteria: &Option<Vec<AscDesc>>,
     distinct: &Option<String>,
     geo_strategy: geo_sort::Strategy,
@@ -656,10 +887,12 @@ pub fn execute_search(
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
     locales: Option<&Vec<Language>>,
+    ranked_callback: Option<RankedCallback<'_>>,
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
     let mut used_negative_operator = false;
+    let mut had_typo_correction = false;
     let mut located_query_terms = None;
     let query_terms = if let Some(query) = query {
         let span = tracing::trace_span!(target: "search::tokens", "tokenizer_builder");
@@ -668,7 +901,7 @@ pub fn execute_search(
         // We make sure that the analyzer is aware of the stop words
         // this ensures that the query builder is able to properly remove them.
         let mut tokbuilder = TokenizerBuilder::new();
-        let stop_words = ctx.index.stop_words(ctx.txn)?;
+        let stop_words = ctx.settings.stop_words.clone();
         if let Some(ref stop_words) = stop_words {
             tokbuilder.stop_words(stop_words);
         }
@@ -734,8 +967,13 @@ pub fn execute_search(
         let tokens = tokenizer.tokenize(query);
         drop(entered);
 
-        let ExtractedTokens { query_terms, negative_words, negative_phrases } =
-            located_query_terms_from_tokens(ctx, tokens, words_limit)?;
+        let ExtractedTokens {
+            query_terms,
+            negative_words,
+            negative_phrases,
+            operator_groups,
+            operator_expressions,
+        } = located_query_terms_from_tokens(ctx, query, tokens, words_limit)?;
         used_negative_operator = !negative_words.is_empty() || !negative_phrases.is_empty();
 
         let ignored_documents = resolve_negative_words(ctx, Some(&universe), &negative_words)?;
@@ -748,14 +986,39 @@ pub fn execute_search(
             // Do a placeholder search instead
             None
         } else {
-            Some(query_terms)
+            Some((query_terms, operator_groups, operator_expressions))
         }
     } else {
         None
     };
 
-    let bucket_sort_output = if let Some(query_terms) = query_terms {
-        let (graph, new_located_query_terms) = QueryGraph::from_query(ctx, &query_terms)?;
+    // The documents are the same whatever the order of evaluation of the filter and the query,
+    // a placeholder search always evaluates the filter first as there is no query to resolve.
+    let filter_after_query = match (filter, &query_terms, filter_strategy) {
+        (None, _, _) | (_, None, _) | (_, _, FilterStrategy::FilterFirst) => false,
+        (_, Some(_), FilterStrategy::QueryFirst) => true,
+        (_, Some((query_terms, _, _)), FilterStrategy::Auto) => {
+            query_is_selective(ctx, query_terms, &universe)?
+        }
+    };
+    if let (Some(filter), false) = (filter, filter_after_query) {
+        universe &= filter.evaluate(ctx.txn, ctx.index)?;
+    }
+
+    let bucket_sort_output = if let Some((query_terms, operator_groups, operator_expressions)) =
+        query_terms
+    {
+        for expression in &operator_expressions {
+            universe =
+                compute_operator_expression_docids(ctx, &universe, &query_terms, expression)?;
+        }
+        let (graph, new_located_query_terms) =
+            QueryGraph::from_query(ctx, &query_terms, &operator_groups)?;
+        if let TermsMatchingStrategy::RequireRare { min_idf } = terms_matching_strategy {
+            if let Some(rare_terms_docids) = graph.rare_terms_docids(ctx, min_idf.0)? {
+                universe &= rare_terms_docids;
+            }
+        }
         located_query_terms = Some(new_located_query_terms);
 
         let ranking_rules = get_ranking_rules_for_query_graph_search(
@@ -765,10 +1028,20 @@ pub fn execute_search(
             terms_matching_strategy,
         )?;
 
-        universe &=
-            resolve_universe(ctx, &universe, &graph, terms_matching_strategy, query_graph_logger)?;
+        let typo_budget = if ctx.typo_budget { query_typo_budget(query_terms.len()) } else { None };
+        universe &= resolve_universe(
+            ctx,
+            &universe,
+            &graph,
+            terms_matching_strategy,
+            typo_budget,
+            query_graph_logger,
+        )?;
+        if let (Some(filter), true) = (filter, filter_after_query) {
+            universe = filter.evaluate_within(ctx.txn, ctx.index, &universe)?;
+        }
 
-        bucket_sort(
+        let output = bucket_sort(
             ctx,
             ranking_rules,
             &graph,
@@ -780,7 +1053,12 @@ pub fn execute_search(
             query_graph_logger,
             time_budget,
             ranking_score_threshold,
-        )?
+            ranked_callback,
+        )?;
+        if ctx.report_typo_correction {
+            had_typo_correction = matched_with_typos(ctx, &graph, &output.docids)?;
+        }
+        output
     } else {
         let ranking_rules =
             get_ranking_rules_for_placeholder_search(ctx, sort_criteria, geo_strategy)?;
@@ -796,6 +1074,7 @@ pub fn execute_search(
             placeholder_search_logger,
             time_budget,
             ranking_score_threshold,
+            ranked_callback,
         )?
     };
 
@@ -803,7 +1082,7 @@ pub fn execute_search(
     let fields_ids_map = ctx.index.fields_ids_map(ctx.txn)?;
 
     // The candidates is the universe unless the exhaustive number of hits
-    // is requested and a distinct attribute is set.
+    // is requested and a distinct attribute or distinct content is set.
     if exhaustive_number_hits {
         let distinct_field = match distinct.as_deref() {
             Some(distinct) => Some(distinct),
@@ -815,6 +1094,11 @@ pub fn execute_search(
                 all_candidates = apply_distinct_rule(ctx, distinct_fid, &all_candidates)?.remaining;
             }
         }
+
+        if let Some(fids) = ctx.distinct_content_fids.clone() {
+            let mut content_distinct = ContentDistinct::new(fids);
+            all_candidates = content_distinct.apply(ctx.index, ctx.txn, &all_candidates)?.remaining;
+        }
     }
 
     Ok(PartialSearchResult {
@@ -824,6 +1108,7 @@ pub fn execute_search(
         located_query_terms,
         degraded,
         used_negative_operator,
+        had_typo_correction,
     })
 }
 
@@ -843,17 +1128,17 @@ fn check_sort_criteria(
 
     // We check that the sort ranking rule exists and throw an
     // error if we try to use it and that it doesn't.
-    let sort_ranking_rule_missing = !ctx.index.criteria(ctx.txn)?.contains(&crate::Criterion::Sort);
+    let sort_ranking_rule_missing = !ctx.settings.criteria.contains(&crate::Criterion::Sort);
     if sort_ranking_rule_missing {
         return Err(UserError::SortRankingRuleMissing.into());
     }
 
     // We check that we are allowed to use the sort criteria, we check
     // that they are declared in the sortable fields.
-    let sortable_fields = ctx.index.sortable_fields(ctx.txn)?;
+    let sortable_fields = &ctx.settings.sortable_fields;
     for asc_desc in sort_criteria {
         match asc_desc.member() {
-            Member::Field(ref field) if !crate::is_faceted(field, &sortable_fields) => {
+            Member::Field(ref field) if !crate::is_faceted(field, sortable_fields) => {
                 let (valid_fields, hidden_fields) =
                     ctx.index.remove_hidden_fields(ctx.txn, sortable_fields)?;
 
@@ -890,4 +1175,5 @@ pub struct PartialSearchResult {
 
     pub degraded: bool,
     pub used_negative_operator: bool,
+    pub had_typo_correction: bool,
 }
@@ -7,7 +7,8 @@ use roaring::RoaringBitmap;
 
 use super::interner::{FixedSizeInterner, Interned};
 use super::query_term::{
-    self, number_of_typos_allowed, LocatedQueryTerm, LocatedQueryTermSubset, QueryTermSubset,
+    self, number_of_typos_allowed, BooleanOperator, LocatedQueryTerm, LocatedQueryTermSubset,
+    OperatorGroup, QueryTermSubset,
 };
 use super::small_bitmap::SmallBitmap;
 use super::SearchContext;
@@ -92,15 +93,33 @@ pub struct QueryGraph {
 impl QueryGraph {
     /// Build the query graph from the parsed user search query, return an updated list of the located query terms
     /// which contains ngrams.
+    ///
+    /// The terms of an `OR` group are all given the term ids of the whole group, making them
+    /// parallel alternatives in the graph. The grouped terms are never part of an ngram and
+    /// are mandatory, unless their group is part of an expression the graph cannot represent.
     pub fn from_query(
         ctx: &mut SearchContext<'_>,
         // The terms here must be consecutive
         terms: &[LocatedQueryTerm],
+        operator_groups: &[OperatorGroup],
     ) -> Result<(QueryGraph, Vec<LocatedQueryTerm>)> {
         let mut new_located_query_terms = terms.to_vec();
 
         let nbr_typos = number_of_typos_allowed(ctx)?;
 
+        let mut term_ids: Vec<_> = (0..terms.len()).map(|idx| idx as u8..=idx as u8).collect();
+        let mut grouped = vec![false; terms.len()];
+        let mut mandatory = vec![false; terms.len()];
+        for group in operator_groups {
+            for idx in group.terms.clone() {
+                grouped[idx] = true;
+                mandatory[idx] |= group.mandatory;
+                if group.operator == BooleanOperator::Or {
+                    term_ids[idx] = *group.terms.start() as u8..=*group.terms.end() as u8;
+                }
+            }
+        }
+
         let mut nodes_data: Vec<QueryNodeData> = vec![QueryNodeData::Start, QueryNodeData::End];
         let root_node = 0;
         let end_node = 1;
@@ -113,17 +132,22 @@ impl QueryGraph {
         for term_idx in 0..original_terms_len {
             let mut new_nodes = vec![];
 
+            let mut term_subset = QueryTermSubset::full(terms[term_idx].value);
+            // the attribute-scoped terms are required, they are never removed
+            if mandatory[term_idx] || term_subset.scoped_field_id(ctx).is_some() {
+                term_subset.make_mandatory();
+            }
             let new_node_idx = add_node(
                 &mut nodes_data,
                 QueryNodeData::Term(LocatedQueryTermSubset {
-                    term_subset: QueryTermSubset::full(terms[term_idx].value),
+                    term_subset,
                     positions: terms[term_idx].positions.clone(),
-                    term_ids: term_idx as u8..=term_idx as u8,
+                    term_ids: term_ids[term_idx].clone(),
                 }),
             );
             new_nodes.push(new_node_idx);
 
-            if !prev1.is_empty() {
+            if !prev1.is_empty() && !grouped[term_idx - 1..=term_idx].contains(&true) {
                 if let Some(ngram) =
                     query_term::make_ngram(ctx, &terms[term_idx - 1..=term_idx], &nbr_typos)?
                 {
@@ -139,7 +163,7 @@ impl QueryGraph {
                     new_nodes.push(ngram_idx);
                 }
             }
-            if !prev2.is_empty() {
+            if !prev2.is_empty() && !grouped[term_idx - 2..=term_idx].contains(&true) {
                 if let Some(ngram) =
                     query_term::make_ngram(ctx, &terms[term_idx - 2..=term_idx], &nbr_typos)?
                 {
@@ -335,31 +359,184 @@ impl QueryGraph {
         Ok(self.removal_order_for_terms_matching_strategy(ctx, cost_of_term_idx))
     }
 
-    pub fn removal_order_for_terms_matching_strategy_last(
+    /// Returns the removal order of the terms, weighting their frequency by their length.
+    ///
+    /// The score of a term is its number of matching documents divided by its number of
+    /// characters, the terms are removed from the highest score to the lowest. A short and
+    /// frequent word, like an article, is thus removed before a longer word that is slightly
+    /// more frequent. The terms with the same score are removed from the last to the first.
+    pub fn removal_order_for_terms_matching_strategy_frequency_weighted(
         &self,
-        ctx: &SearchContext<'_>,
-    ) -> Vec<SmallBitmap<QueryNode>> {
-        let (first_term_idx, last_term_idx) = {
-            let mut first_term_idx = u8::MAX;
-            let mut last_term_idx = 0u8;
-            for (_, node) in self.nodes.iter() {
-                match &node.data {
-                    QueryNodeData::Term(t) => {
-                        if *t.term_ids.end() > last_term_idx {
-                            last_term_idx = *t.term_ids.end();
-                        }
-                        if *t.term_ids.start() < first_term_idx {
-                            first_term_idx = *t.term_ids.start();
-                        }
+        ctx: &mut SearchContext<'_>,
+    ) -> Result<Vec<SmallBitmap<QueryNode>>> {
+        // lookup frequency and length for each term
+        let mut term_docids: BTreeMap<u8, RoaringBitmap> = Default::default();
+        let mut term_length: BTreeMap<u8, usize> = Default::default();
+        for (_, node) in self.nodes.iter() {
+            match &node.data {
+                QueryNodeData::Term(t) => {
+                    let docids = compute_query_term_subset_docids(ctx, None, &t.term_subset)?;
+                    for id in t.term_ids.clone() {
+                        term_docids
+                            .entry(id)
+                            .and_modify(|curr| *curr |= &docids)
+                            .or_insert_with(|| docids.clone());
+                    }
+                    // ngrams span several terms, only the original words give a length
+                    if t.term_ids.start() == t.term_ids.end() {
+                        let length = t.term_subset.original_word(ctx).chars().count();
+                        term_length.insert(*t.term_ids.start(), length);
                     }
-                    QueryNodeData::Deleted | QueryNodeData::Start | QueryNodeData::End => continue,
                 }
+                QueryNodeData::Deleted | QueryNodeData::Start | QueryNodeData::End => continue,
             }
-            (first_term_idx, last_term_idx)
+        }
+
+        let mut term_with_score: Vec<(u8, f64)> = term_docids
+            .into_iter()
+            .map(|(idx, docids)| {
+                let frequency = match docids.len() {
+                    0 => u64::MAX,
+                    frequency => frequency,
+                };
+                let length = term_length.get(&idx).map_or(1, |length| (*length).max(1));
+                (idx, frequency as f64 / length as f64)
+            })
+            .collect();
+        term_with_score.sort_by(|(lidx, lscore), (ridx, rscore)| {
+            rscore.total_cmp(lscore).then_with(|| ridx.cmp(lidx))
+        });
+        let term_weight: BTreeMap<u8, u16> = term_with_score
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (idx, _))| (idx, rank as u16 + 1))
+            .collect();
+        let cost_of_term_idx = move |term_idx: u8| *term_weight.get(&term_idx).unwrap();
+        Ok(self.removal_order_for_terms_matching_strategy(ctx, cost_of_term_idx))
+    }
+
+    /// Returns the documents containing at least one of the rare terms, the terms whose inverse
+    /// document frequency is at least `min_idf`, or `None` if none of the terms is rare.
+    ///
+    /// The idf of a term is `ln(N / n)`, where `N` is the number of documents in the index
+    /// and `n` the number of documents containing its word, one of its typo derivations or,
+    /// for the last word, one of its prefixes. A term matching no document is not considered
+    /// rare, and the documents matching an ngram made of a rare term are returned too.
+    pub fn rare_terms_docids(
+        &self,
+        ctx: &mut SearchContext<'_>,
+        min_idf: f64,
+    ) -> Result<Option<RoaringBitmap>> {
+        let documents_count = ctx.index.number_of_documents(ctx.txn)?;
+        let mut rare_term_ids = Vec::new();
+        for (_, node) in self.nodes.iter() {
+            let QueryNodeData::Term(t) = &node.data else { continue };
+            if t.term_ids.start() != t.term_ids.end() {
+                continue;
+            }
+            let frequency = compute_query_term_subset_docids(ctx, None, &t.term_subset)?.len();
+            if frequency != 0 && (documents_count as f64 / frequency as f64).ln() >= min_idf {
+                rare_term_ids.push(*t.term_ids.start());
+            }
+        }
+        if rare_term_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let mut docids = RoaringBitmap::new();
+        for (_, node) in self.nodes.iter() {
+            let QueryNodeData::Term(t) = &node.data else { continue };
+            if t.term_ids.clone().any(|id| rare_term_ids.contains(&id)) {
+                docids |= compute_query_term_subset_docids(ctx, None, &t.term_subset)?;
+            }
+        }
+        Ok(Some(docids))
+    }
+
+    /// Returns the removal order of the terms for the `RequireRare` strategy.
+    ///
+    /// The most frequent terms are removed first, like with the `Frequency` strategy. When one
+    /// of the terms is rare, the last term can be removed too, as the universe is then restricted
+    /// to the documents containing at least one of the rare terms, see [`Self::rare_terms_docids`].
+    pub fn removal_order_for_terms_matching_strategy_require_rare(
+        &self,
+        ctx: &mut SearchContext<'_>,
+        min_idf: f64,
+    ) -> Result<Vec<SmallBitmap<QueryNode>>> {
+        let mut removal_order = self.removal_order_for_terms_matching_strategy_frequency(ctx)?;
+        if self.rare_terms_docids(ctx, min_idf)?.is_none() {
+            return Ok(removal_order);
+        }
+
+        let mut last_terms = SmallBitmap::for_interned_values_in(&self.nodes);
+        for (node_id, node) in self.nodes.iter() {
+            let QueryNodeData::Term(t) = &node.data else { continue };
+            if t.term_subset.original_phrase(ctx).is_none() && !t.term_subset.is_mandatory() {
+                last_terms.insert(node_id);
+            }
+        }
+        for nodes in &removal_order {
+            last_terms.subtract(nodes);
+        }
+        if !last_terms.is_empty() {
+            removal_order.push(last_terms);
+        }
+        Ok(removal_order)
+    }
+
+    /// Returns the removal order of the terms, from the shortest to the longest.
+    ///
+    /// The length of a term is the number of characters of its original word. The terms
+    /// with the same length are removed from the last to the first.
+    pub fn removal_order_for_terms_matching_strategy_size(
+        &self,
+        ctx: &SearchContext<'_>,
+    ) -> Vec<SmallBitmap<QueryNode>> {
+        let mut term_length: BTreeMap<u8, usize> = Default::default();
+        for (_, node) in self.nodes.iter() {
+            let QueryNodeData::Term(t) = &node.data else { continue };
+            // ngrams span several terms, only the original words give a length
+            if t.term_ids.start() == t.term_ids.end() {
+                let length = t.term_subset.original_word(ctx).chars().count();
+                term_length.insert(*t.term_ids.start(), length);
+            }
+        }
+
+        let mut term_with_length: Vec<(u8, usize)> = term_length.into_iter().collect();
+        term_with_length.sort_by(|(lidx, llength), (ridx, rlength)| {
+            llength.cmp(rlength).then_with(|| ridx.cmp(lidx))
+        });
+        let term_weight: BTreeMap<u8, u16> = term_with_length
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (idx, _))| (idx, rank as u16 + 1))
+            .collect();
+        let cost_of_term_idx = move |term_idx: u8| term_weight.get(&term_idx).copied().unwrap_or(1);
+        self.removal_order_for_terms_matching_strategy(ctx, cost_of_term_idx)
+    }
+
+    pub fn removal_order_for_terms_matching_strategy_first(
+        &self,
+        ctx: &SearchContext<'_>,
+    ) -> Vec<SmallBitmap<QueryNode>> {
+        let Some((first_term_idx, _)) = self.first_and_last_term_idx() else {
+            return vec![];
+        };
+
+        let cost_of_term_idx = |term_idx: u8| {
+            let rank = 1 + term_idx - first_term_idx;
+            rank as u16
         };
-        if first_term_idx >= last_term_idx {
+        self.removal_order_for_terms_matching_strategy(ctx, cost_of_term_idx)
+    }
+
+    pub fn removal_order_for_terms_matching_strategy_last(
+        &self,
+        ctx: &SearchContext<'_>,
+    ) -> Vec<SmallBitmap<QueryNode>> {
+        let Some((_, last_term_idx)) = self.first_and_last_term_idx() else {
             return vec![];
-        }
+        };
 
         let cost_of_term_idx = |term_idx: u8| {
             let rank = 1 + last_term_idx - term_idx;
@@ -368,6 +545,26 @@ impl QueryGraph {
         self.removal_order_for_terms_matching_strategy(ctx, cost_of_term_idx)
     }
 
+    /// Returns the indexes of the first and last terms, or `None` if there are less than two.
+    fn first_and_last_term_idx(&self) -> Option<(u8, u8)> {
+        let mut first_term_idx = u8::MAX;
+        let mut last_term_idx = 0u8;
+        for (_, node) in self.nodes.iter() {
+            match &node.data {
+                QueryNodeData::Term(t) => {
+                    if *t.term_ids.end() > last_term_idx {
+                        last_term_idx = *t.term_ids.end();
+                    }
+                    if *t.term_ids.start() < first_term_idx {
+                        first_term_idx = *t.term_ids.start();
+                    }
+                }
+                QueryNodeData::Deleted | QueryNodeData::Start | QueryNodeData::End => continue,
+            }
+        }
+        (first_term_idx < last_term_idx).then_some((first_term_idx, last_term_idx))
+    }
+
     pub fn removal_order_for_terms_matching_strategy(
         &self,
         ctx: &SearchContext<'_>,
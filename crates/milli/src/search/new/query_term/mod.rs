@@ -10,13 +10,14 @@ use std::ops::RangeInclusive;
 use either::Either;
 pub use ntypo_subset::NTypoTermSubset;
 pub use parse_query::{
-    located_query_terms_from_tokens, make_ngram, number_of_typos_allowed, ExtractedTokens,
+    located_query_terms_from_tokens, make_ngram, number_of_typos_allowed, BooleanOperator,
+    ExtractedTokens, OperatorExpression, OperatorGroup,
 };
 pub use phrase::Phrase;
 
 use super::interner::{DedupInterner, Interned};
 use super::{limits, SearchContext, Word};
-use crate::Result;
+use crate::{FieldId, Result};
 
 /// A set of word derivations attached to a location in the search query.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -51,6 +52,8 @@ pub struct QueryTerm {
     one_typo: Lazy<OneTypoTerm>,
     // May not be computed yet
     two_typo: Lazy<TwoTypoTerm>,
+    /// The attribute in which the term must match, if it was written `attribute:term`
+    scoped_field_id: Option<FieldId>,
 }
 
 // SubTerms will be in a dedup interner
@@ -125,6 +128,15 @@ impl QueryTermSubset {
     pub fn is_mandatory(&self) -> bool {
         self.mandatory
     }
+    pub fn is_prefix(&self, ctx: &SearchContext<'_>) -> bool {
+        ctx.term_interner.get(self.original).is_prefix
+    }
+    pub fn original_word(&self, ctx: &SearchContext<'_>) -> String {
+        ctx.term_interner.get(self.original).original_word(ctx)
+    }
+    pub fn scoped_field_id(&self, ctx: &SearchContext<'_>) -> Option<FieldId> {
+        ctx.term_interner.get(self.original).scoped_field_id
+    }
     pub fn make_mandatory(&mut self) {
         self.mandatory = true;
     }
@@ -142,6 +154,19 @@ impl QueryTermSubset {
         }
     }
 
+    /// Returns the word typed by the user if this term is a single word that
+    /// still accepts zero-typo matches, whether or not it exists in the index.
+    pub fn original_single_word(&self, ctx: &SearchContext<'_>) -> Option<Interned<String>> {
+        let full_query_term = ctx.term_interner.get(self.original);
+        if full_query_term.ngram_words.is_some()
+            || full_query_term.zero_typo.phrase.is_some()
+            || matches!(self.zero_typo_subset, NTypoTermSubset::Nothing)
+        {
+            return None;
+        }
+        Some(full_query_term.original)
+    }
+
     pub fn empty(for_term: Interned<QueryTerm>) -> Self {
         Self {
             original: for_term,
@@ -477,6 +502,9 @@ impl QueryTerm {
     pub fn is_prefix(&self) -> bool {
         self.is_prefix
     }
+    pub fn is_ngram(&self) -> bool {
+        self.ngram_words.is_some()
+    }
     pub fn original_word(&self, ctx: &SearchContext<'_>) -> String {
         ctx.word_interner.get(self.original).clone()
     }
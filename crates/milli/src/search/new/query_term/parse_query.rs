@@ -1,13 +1,14 @@
 use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
 
 use charabia::normalizer::NormalizedTokenIter;
-use charabia::{SeparatorKind, TokenKind};
+use charabia::{SeparatorKind, Token, TokenKind};
 
 use super::compute_derivations::partially_initialized_term_from_word;
 use super::{LocatedQueryTerm, ZeroTypoTerm};
 use crate::search::new::query_term::{Lazy, Phrase, QueryTerm};
 use crate::search::new::Word;
-use crate::{Result, SearchContext, MAX_WORD_LENGTH};
+use crate::{FieldId, Result, SearchContext, MAX_WORD_LENGTH};
 
 #[derive(Clone)]
 /// Extraction of the content of a query.
@@ -18,13 +19,57 @@ pub struct ExtractedTokens {
     pub negative_words: Vec<Word>,
     /// The phrases that must not appear in the results.
     pub negative_phrases: Vec<LocatedQueryTerm>,
+    /// The query terms bound together by an explicit boolean operator.
+    pub operator_groups: Vec<OperatorGroup>,
+    /// The boolean expressions the query graph cannot represent, the documents must match all of them.
+    pub operator_expressions: Vec<OperatorExpression>,
+}
+
+/// A boolean operator written in uppercase between two terms of the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperator {
+    /// Both operands must match.
+    And,
+    /// At least one of the operands must match.
+    Or,
+}
+
+/// A range of query terms bound together by a boolean operator.
+///
+/// The grouped terms cannot be removed by the terms matching strategy. The terms of an
+/// `OR` group are alternatives of each other, the terms of an `AND` group must all match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorGroup {
+    pub operator: BooleanOperator,
+    /// The indexes of the grouped terms in the extracted query terms.
+    pub terms: RangeInclusive<usize>,
+    /// `false` when the group is part of an [`OperatorExpression`], which selects the matching documents instead.
+    pub mandatory: bool,
+}
+
+/// A boolean expression over the query terms.
+///
+/// An `OR` between operands that are not single terms or groups of alternatives cannot be
+/// represented in the query graph, the documents are then filtered with the whole expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperatorExpression {
+    /// The index of a term in the extracted query terms.
+    Term(usize),
+    /// All the operands must match.
+    And(Vec<OperatorExpression>),
+    /// At least one of the operands must match.
+    Or(Vec<OperatorExpression>),
 }
 
 /// Convert the tokenised search query into a list of located query terms.
+///
+/// The original `query` is used to recognize the boolean operators, which must be
+/// written in uppercase, as the tokens are normalized.
 #[tracing::instrument(level = "trace", skip_all, target = "search::query")]
 pub fn located_query_terms_from_tokens(
     ctx: &mut SearchContext<'_>,
-    query: NormalizedTokenIter<'_, '_, '_, '_>,
+    query: &str,
+    tokens: NormalizedTokenIter<'_, '_, '_, '_>,
     words_limit: Option<usize>,
 ) -> Result<ExtractedTokens> {
     let nbr_typos = number_of_typos_allowed(ctx)?;
@@ -38,13 +83,15 @@ pub fn located_query_terms_from_tokens(
     let mut negative_next_token = false;
     let mut negative_words = Vec::new();
     let mut negative_phrases = Vec::new();
+    let mut operators = OperatorGroupsBuilder::new();
+    let mut scope: Option<AttributeScope> = None;
 
     let parts_limit = words_limit.unwrap_or(usize::MAX);
 
     // start with the last position as we will wrap around to position 0 at the beginning of the loop below.
     let mut position = u16::MAX;
 
-    let mut peekable = query.take(super::limits::MAX_TOKEN_COUNT).peekable();
+    let mut peekable = tokens.take(super::limits::MAX_TOKEN_COUNT).peekable();
     while let Some(token) = peekable.next() {
         if token.lemma().is_empty() {
             continue;
@@ -52,8 +99,48 @@ pub fn located_query_terms_from_tokens(
 
         // early return if word limit is exceeded
         if query_terms.len() >= parts_limit {
-            return Ok(ExtractedTokens { query_terms, negative_words, negative_phrases });
+            let (operator_groups, operator_expressions) = operators.finish(query_terms.len());
+            return Ok(ExtractedTokens {
+                query_terms,
+                negative_words,
+                negative_phrases,
+                operator_groups,
+                operator_expressions,
+            });
+        }
+
+        let terms_count = query_terms.len();
+        let operator = boolean_operator(query, &token).filter(|_| {
+            ctx.boolean_operators
+                && phrase.is_none()
+                && !negative_next_token
+                && operators.expects_operator()
+        });
+        if let Some(operator) = operator {
+            // the operator is not a term of the query
+            operators.set_operator(operator);
+            encountered_whitespace = false;
+            continue;
+        }
+
+        let starts_scope = scope.is_none()
+            && phrase.is_none()
+            && !negative_next_token
+            && encountered_whitespace
+            && matches!(token.kind, TokenKind::Word | TokenKind::StopWord);
+        if starts_scope {
+            scope = attribute_scope(ctx, query, &token)?;
         }
+        // the attribute and the colon of a scoped term are not searched,
+        // the scope only applies to the token that follows them.
+        let scoped_field_id = match scope.take() {
+            Some(s) if token.byte_start < s.term_start => {
+                scope = Some(s);
+                continue;
+            }
+            Some(s) => Some(s.field_id),
+            None => None,
+        };
 
         match token.kind {
             TokenKind::Word | TokenKind::StopWord => {
@@ -74,13 +161,14 @@ pub fn located_query_terms_from_tokens(
                     match token.kind {
                         TokenKind::Word => {
                             let word = token.lemma();
-                            let term = partially_initialized_term_from_word(
+                            let mut term = partially_initialized_term_from_word(
                                 ctx,
                                 word,
                                 nbr_typos(word),
                                 false,
                                 false,
                             )?;
+                            term.scoped_field_id = scoped_field_id;
                             let located_term = LocatedQueryTerm {
                                 value: ctx.term_interner.push(term),
                                 positions: position..=position,
@@ -91,21 +179,26 @@ pub fn located_query_terms_from_tokens(
                     }
                 } else {
                     let word = token.lemma();
-                    let term = partially_initialized_term_from_word(
+                    let mut term = partially_initialized_term_from_word(
                         ctx,
                         word,
                         nbr_typos(word),
                         allow_prefix_search,
                         false,
                     )?;
+                    term.scoped_field_id = scoped_field_id;
                     let located_term = LocatedQueryTerm {
                         value: ctx.term_interner.push(term),
                         positions: position..=position,
                     };
                     query_terms.push(located_term);
                 }
+
+                (terms_count..query_terms.len()).for_each(|i| operators.push_term(i));
             }
             TokenKind::Separator(separator_kind) => {
+                let outside_phrase = phrase.is_none();
+
                 // add penalty for hard separators
                 if let SeparatorKind::Hard = separator_kind {
                     position = position.wrapping_add(7);
@@ -166,8 +259,25 @@ pub fn located_query_terms_from_tokens(
                     }
                 };
 
-                negative_next_token =
-                    phrase.is_none() && token.lemma() == "-" && encountered_whitespace;
+                (terms_count..query_terms.len()).for_each(|i| operators.push_term(i));
+
+                // Parentheses delimit the operands of the boolean operators,
+                // they are ignored when they are part of a phrase.
+                if ctx.boolean_operators && (outside_phrase || phrase.is_none()) {
+                    for c in token.lemma().chars() {
+                        match c {
+                            '(' => operators.open_parenthesis(query_terms.len()),
+                            ')' => operators.close_parenthesis(query_terms.len()),
+                            _ => (),
+                        }
+                    }
+                }
+
+                // A `-` at the start of the query is literal, it doesn't negate the first word.
+                negative_next_token = phrase.is_none()
+                    && token.lemma() == "-"
+                    && encountered_whitespace
+                    && !query[..token.byte_start].trim().is_empty();
             }
             _ => (),
         }
@@ -183,20 +293,253 @@ pub fn located_query_terms_from_tokens(
             if negative_phrase {
                 negative_phrases.push(located_query_term);
             } else {
+                operators.push_term(query_terms.len());
                 query_terms.push(located_query_term);
             }
         }
     }
 
-    Ok(ExtractedTokens { query_terms, negative_words, negative_phrases })
+    let (operator_groups, operator_expressions) = operators.finish(query_terms.len());
+    Ok(ExtractedTokens {
+        query_terms,
+        negative_words,
+        negative_phrases,
+        operator_groups,
+        operator_expressions,
+    })
+}
+
+/// Returns the boolean operator written by the token, if it is followed by an operand.
+///
+/// An operator that ends the query or a parenthesized group is searched as a regular word.
+fn boolean_operator(query: &str, token: &Token<'_>) -> Option<BooleanOperator> {
+    let operator = match query.get(token.byte_start..token.byte_end)? {
+        "AND" => BooleanOperator::And,
+        "OR" => BooleanOperator::Or,
+        _ => return None,
+    };
+    let rest = query.get(token.byte_end..)?.trim_start();
+    (!rest.is_empty() && !rest.starts_with(')')).then_some(operator)
+}
+
+/// The attribute in which the next term of the query must match.
+struct AttributeScope {
+    field_id: FieldId,
+    /// The byte offset of the scoped term in the query.
+    term_start: usize,
+}
+
+/// Returns the scope of the term written `attribute:term` starting with the token.
+///
+/// The attribute must be one of the searched attributes, the searchable ones restricted to the
+/// attributes to search on. Otherwise, the token is searched as a regular word, so that queries
+/// like `10:30` or `price:10` are not affected.
+fn attribute_scope(
+    ctx: &SearchContext<'_>,
+    query: &str,
+    token: &Token<'_>,
+) -> Result<Option<AttributeScope>> {
+    let Some(rest) = query.get(token.byte_start..) else { return Ok(None) };
+    let chunk = rest.split(char::is_whitespace).next().unwrap_or_default();
+    let Some((attribute, term)) = chunk.split_once(':') else { return Ok(None) };
+    if attribute.is_empty() || term.is_empty() {
+        return Ok(None);
+    }
+
+    let fields_ids_map = ctx.index.fields_ids_map(ctx.txn)?;
+    let Some(field_id) = fields_ids_map.id(attribute) else { return Ok(None) };
+    let searched = match &ctx.restricted_fids {
+        Some(restricted_fids) => restricted_fids.contains(&field_id),
+        None => ctx.index.searchable_fields_ids(ctx.txn)?.contains(&field_id),
+    };
+    if !searched {
+        return Ok(None);
+    }
+
+    Ok(Some(AttributeScope { field_id, term_start: token.byte_start + attribute.len() + 1 }))
+}
+
+/// A term or a parenthesized group of terms on which a boolean operator can apply.
+struct Operand {
+    terms: RangeInclusive<usize>,
+    /// `true` if the operand is a single term or a group of alternative terms.
+    alternatives: bool,
+    /// `true` if the operand contains an `OR` that the query graph cannot represent.
+    disjunction: bool,
+    expression: OperatorExpression,
+}
+
+/// The operands of a parenthesized group, or of the whole query.
+struct OperatorLevel {
+    /// The index of the first term of the level.
+    start: usize,
+    /// The operands of a parenthesized group no operator applies to anymore.
+    operands: Vec<Operand>,
+    last_operand: Option<Operand>,
+    pending_operator: Option<BooleanOperator>,
+}
+
+impl OperatorLevel {
+    fn new(start: usize) -> Self {
+        OperatorLevel { start, operands: Vec::new(), last_operand: None, pending_operator: None }
+    }
+}
+
+/// Builds the operator groups of a query.
+///
+/// The operators apply from left to right to the term or the parenthesized group on
+/// each of their sides. An `OR` between operands that are not single terms or groups
+/// of alternatives cannot be represented in the query graph, it is returned as an
+/// [`OperatorExpression`] and its terms are not mandatory in the query graph.
+struct OperatorGroupsBuilder {
+    levels: Vec<OperatorLevel>,
+    groups: Vec<OperatorGroup>,
+    expressions: Vec<OperatorExpression>,
+}
+
+impl OperatorGroupsBuilder {
+    fn new() -> Self {
+        OperatorGroupsBuilder {
+            levels: vec![OperatorLevel::new(0)],
+            groups: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+
+    fn current_level(&mut self) -> &mut OperatorLevel {
+        // the level of the whole query is never popped
+        self.levels.last_mut().unwrap()
+    }
+
+    fn expects_operator(&self) -> bool {
+        self.levels
+            .last()
+            .map_or(false, |level| level.last_operand.is_some() && level.pending_operator.is_none())
+    }
+
+    fn set_operator(&mut self, operator: BooleanOperator) {
+        self.current_level().pending_operator = Some(operator);
+    }
+
+    fn push_term(&mut self, term_idx: usize) {
+        self.push_operand(Operand {
+            terms: term_idx..=term_idx,
+            alternatives: true,
+            disjunction: false,
+            expression: OperatorExpression::Term(term_idx),
+        });
+    }
+
+    fn push_operand(&mut self, operand: Operand) {
+        let level = self.current_level();
+        let operand = match (level.pending_operator.take(), level.last_operand.take()) {
+            (Some(operator), Some(left)) => self.combine(operator, left, operand),
+            (None, Some(previous)) => {
+                self.end_operand(previous);
+                operand
+            }
+            (_, None) => operand,
+        };
+        self.current_level().last_operand = Some(operand);
+    }
+
+    /// Called when no operator can apply to the operand anymore.
+    fn end_operand(&mut self, operand: Operand) {
+        if self.levels.len() > 1 {
+            self.current_level().operands.push(operand);
+        } else if operand.disjunction {
+            // the expression decides which documents match its terms
+            let terms = operand.terms;
+            self.groups.retain(|group| {
+                group.operator == BooleanOperator::Or || !terms.contains(group.terms.start())
+            });
+            for group in &mut self.groups {
+                if terms.contains(group.terms.start()) {
+                    group.mandatory = false;
+                }
+            }
+            self.expressions.push(operand.expression);
+        }
+    }
+
+    fn combine(&mut self, operator: BooleanOperator, left: Operand, right: Operand) -> Operand {
+        let terms = *left.terms.start()..=*right.terms.end();
+        let disjunction = left.disjunction || right.disjunction;
+        match operator {
+            BooleanOperator::Or if left.alternatives && right.alternatives => {
+                // the new group replaces the groups of alternatives of its operands
+                self.groups.retain(|group| {
+                    group.operator != BooleanOperator::Or || !terms.contains(group.terms.start())
+                });
+                self.groups.push(OperatorGroup { operator, terms: terms.clone(), mandatory: true });
+                let expression = OperatorExpression::Or(vec![left.expression, right.expression]);
+                Operand { terms, alternatives: true, disjunction, expression }
+            }
+            BooleanOperator::Or => {
+                let expression = OperatorExpression::Or(vec![left.expression, right.expression]);
+                Operand { terms, alternatives: false, disjunction: true, expression }
+            }
+            BooleanOperator::And => {
+                // an `AND` containing a disjunction is only part of its expression
+                if !disjunction {
+                    self.groups.push(OperatorGroup {
+                        operator,
+                        terms: terms.clone(),
+                        mandatory: true,
+                    });
+                }
+                let expression = OperatorExpression::And(vec![left.expression, right.expression]);
+                Operand { terms, alternatives: false, disjunction, expression }
+            }
+        }
+    }
+
+    fn open_parenthesis(&mut self, terms_count: usize) {
+        self.levels.push(OperatorLevel::new(terms_count));
+    }
+
+    fn close_parenthesis(&mut self, terms_count: usize) {
+        if self.levels.len() == 1 {
+            return;
+        }
+        let mut level = self.levels.pop().unwrap();
+        if terms_count > level.start {
+            let terms = level.start..=terms_count - 1;
+            let operand = match level.last_operand.take() {
+                Some(operand) if operand.terms == terms => operand,
+                last_operand => {
+                    // the juxtaposed operands of a group must all match in an expression
+                    let operands: Vec<_> = level.operands.into_iter().chain(last_operand).collect();
+                    let disjunction = operands.iter().any(|operand| operand.disjunction);
+                    let expression = OperatorExpression::And(
+                        operands.into_iter().map(|operand| operand.expression).collect(),
+                    );
+                    Operand { terms, alternatives: false, disjunction, expression }
+                }
+            };
+            self.push_operand(operand);
+        }
+    }
+
+    /// Returns the operator groups and the expressions that the query graph cannot represent.
+    ///
+    /// The parenthesized groups that are still open are closed at the end of the query.
+    fn finish(mut self, terms_count: usize) -> (Vec<OperatorGroup>, Vec<OperatorExpression>) {
+        while self.levels.len() > 1 {
+            self.close_parenthesis(terms_count);
+        }
+        if let Some(operand) = self.current_level().last_operand.take() {
+            self.end_operand(operand);
+        }
+        (self.groups, self.expressions)
+    }
 }
 
 pub fn number_of_typos_allowed<'ctx>(
     ctx: &SearchContext<'ctx>,
 ) -> Result<impl Fn(&str) -> u8 + 'ctx> {
     let authorize_typos = ctx.index.authorize_typos(ctx.txn)?;
-    let min_len_one_typo = ctx.index.min_word_len_one_typo(ctx.txn)?;
-    let min_len_two_typos = ctx.index.min_word_len_two_typos(ctx.txn)?;
+    let (min_len_one_typo, min_len_two_typos) = ctx.min_word_len_typos()?;
 
     let exact_words = ctx.index.exact_words(ctx.txn)?;
 
@@ -221,7 +564,8 @@ pub fn make_ngram(
 ) -> Result<Option<LocatedQueryTerm>> {
     assert!(!terms.is_empty());
     for t in terms {
-        if ctx.term_interner.get(t.value).zero_typo.phrase.is_some() {
+        let term = ctx.term_interner.get(t.value);
+        if term.zero_typo.phrase.is_some() || term.scoped_field_id.is_some() {
             return Ok(None);
         }
     }
@@ -275,6 +619,7 @@ pub fn make_ngram(
         zero_typo: term.zero_typo,
         one_typo: Lazy::Uninit,
         two_typo: Lazy::Uninit,
+        scoped_field_id: None,
     };
 
     let term = LocatedQueryTerm { value: ctx.term_interner.push(term), positions: start..=end };
@@ -339,6 +684,7 @@ impl PhraseBuilder {
                     },
                     one_typo: Lazy::Uninit,
                     two_typo: Lazy::Uninit,
+                    scoped_field_id: None,
                 }
             }),
             positions: self.start..=self.end,
@@ -375,7 +721,7 @@ mod tests {
         let mut ctx = SearchContext::new(&index, &rtxn)?;
         // panics with `attempt to add with overflow` before <https://github.com/meilisearch/meilisearch/issues/3785>
         let ExtractedTokens { query_terms, .. } =
-            located_query_terms_from_tokens(&mut ctx, tokens, None)?;
+            located_query_terms_from_tokens(&mut ctx, ".", tokens, None)?;
         assert!(query_terms.is_empty());
 
         Ok(())
@@ -5,7 +5,10 @@ use super::{ComputedCondition, RankingRuleGraphTrait};
 use crate::score_details::{Rank, ScoreDetails};
 use crate::search::new::interner::{DedupInterner, Interned};
 use crate::search::new::query_term::LocatedQueryTermSubset;
-use crate::search::new::resolve_query_graph::compute_query_term_subset_docids_within_field_id;
+use crate::search::new::resolve_query_graph::{
+    compute_query_term_subset_docids_within_field_id,
+    compute_query_term_subset_numeric_facet_docids,
+};
 use crate::search::new::SearchContext;
 use crate::{FieldId, InternalError, Result};
 
@@ -36,7 +39,8 @@ impl RankingRuleGraphTrait for FidGraph {
                 fid,
             )?
         } else {
-            RoaringBitmap::new()
+            // numeric facet matches aren't in any attribute, they rank with the max weight
+            compute_query_term_subset_numeric_facet_docids(ctx, Some(universe), &term.term_subset)?
         };
 
         Ok(ComputedCondition {
@@ -91,7 +95,7 @@ impl RankingRuleGraphTrait for FidGraph {
         let max_weight: Option<u16> = weights_map.max_weight();
 
         if let Some(max_weight) = max_weight {
-            if !all_fields.contains(&max_weight) {
+            if !all_fields.contains(&max_weight) || ctx.match_numeric_facets {
                 edges.push((
                     max_weight as u32 * term.term_ids.len() as u32, // TODO improve the fid score i.e. fid^10.
                     conditions_interner.insert(FidCondition {
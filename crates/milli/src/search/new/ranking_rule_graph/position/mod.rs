@@ -5,7 +5,10 @@ use super::{ComputedCondition, RankingRuleGraphTrait};
 use crate::score_details::{Rank, ScoreDetails};
 use crate::search::new::interner::{DedupInterner, Interned};
 use crate::search::new::query_term::LocatedQueryTermSubset;
-use crate::search::new::resolve_query_graph::compute_query_term_subset_docids_within_position;
+use crate::search::new::resolve_query_graph::{
+    compute_query_term_subset_docids_within_position,
+    compute_query_term_subset_numeric_facet_docids,
+};
 use crate::search::new::SearchContext;
 use crate::Result;
 
@@ -27,7 +30,12 @@ impl RankingRuleGraphTrait for PositionGraph {
         universe: &RoaringBitmap,
     ) -> Result<ComputedCondition> {
         let PositionCondition { term, positions } = condition;
-        let mut docids = RoaringBitmap::new();
+        let mut docids = if positions.is_empty() {
+            // numeric facet matches have no position, they rank with the max cost
+            compute_query_term_subset_numeric_facet_docids(ctx, Some(universe), &term.term_subset)?
+        } else {
+            RoaringBitmap::new()
+        };
         // TODO use MultiOps to do the big union
         for position in positions {
             // maybe compute_query_term_subset_docids_within_position should accept a universe as argument
@@ -108,7 +116,7 @@ impl RankingRuleGraphTrait for PositionGraph {
             ));
         }
 
-        if !max_cost_exists {
+        if !max_cost_exists || ctx.match_numeric_facets {
             // artificial empty condition for computing max cost
             edges.push((
                 max_cost,
@@ -7,7 +7,7 @@ use roaring::{MultiOps, RoaringBitmap};
 
 use super::interner::Interned;
 use super::query_graph::QueryNodeData;
-use super::query_term::{Phrase, QueryTermSubset};
+use super::query_term::{ExactTerm, LocatedQueryTerm, OperatorExpression, Phrase, QueryTermSubset};
 use super::small_bitmap::SmallBitmap;
 use super::{QueryGraph, SearchContext, Word};
 use crate::search::new::query_term::LocatedQueryTermSubset;
@@ -35,7 +35,23 @@ pub fn compute_query_term_subset_docids(
     universe: Option<&RoaringBitmap>,
     term: &QueryTermSubset,
 ) -> Result<RoaringBitmap> {
-    let mut docids = RoaringBitmap::new();
+    // a term written `attribute:term` only matches in its attribute
+    if let Some(fid) = term.scoped_field_id(ctx) {
+        let docids = compute_query_term_subset_docids_within_field_id(ctx, universe, term, fid)?;
+        return match universe {
+            Some(universe) => Ok(docids & universe),
+            None => Ok(docids),
+        };
+    }
+
+    let numeric_docids = compute_query_term_subset_numeric_facet_docids(ctx, universe, term)?;
+
+    if !ctx.prefix_disabled_fids.is_empty() && term.is_prefix(ctx) {
+        let docids = compute_prefix_query_term_subset_docids(ctx, universe, term)?;
+        return Ok(docids | numeric_docids);
+    }
+
+    let mut docids = numeric_docids;
     // TODO use the MultiOps trait to do large intersections
     for word in term.all_single_words_except_prefix_db(ctx)? {
         if let Some(word_docids) = ctx.word_docids(universe, word)? {
@@ -58,14 +74,125 @@ pub fn compute_query_term_subset_docids(
     }
 }
 
+/// Returns the documents of the universe matching a boolean expression over the query terms.
+pub fn compute_operator_expression_docids(
+    ctx: &mut SearchContext<'_>,
+    universe: &RoaringBitmap,
+    terms: &[LocatedQueryTerm],
+    expression: &OperatorExpression,
+) -> Result<RoaringBitmap> {
+    match expression {
+        OperatorExpression::Term(idx) => {
+            let term = QueryTermSubset::full(terms[*idx].value);
+            compute_query_term_subset_docids(ctx, Some(universe), &term)
+        }
+        OperatorExpression::And(operands) => {
+            let mut docids = universe.clone();
+            for operand in operands {
+                docids = compute_operator_expression_docids(ctx, &docids, terms, operand)?;
+            }
+            Ok(docids)
+        }
+        OperatorExpression::Or(operands) => {
+            let mut docids = RoaringBitmap::new();
+            for operand in operands {
+                docids |= compute_operator_expression_docids(ctx, universe, terms, operand)?;
+            }
+            Ok(docids)
+        }
+    }
+}
+
+/// Computes the docids of a prefix term when prefix search is disabled on some attributes.
+///
+/// In these attributes the term only matches its exact word. Its prefix derivations,
+/// and its typo derivations that are computed with a prefix automaton, only match in
+/// the other searched attributes.
+fn compute_prefix_query_term_subset_docids(
+    ctx: &mut SearchContext<'_>,
+    universe: Option<&RoaringBitmap>,
+    term: &QueryTermSubset,
+) -> Result<RoaringBitmap> {
+    let prefix_enabled_fids = ctx.prefix_enabled_fids()?;
+    let exact_word = match term.exact_term(ctx) {
+        Some(ExactTerm::Word(word)) => Some(word),
+        _ => None,
+    };
+
+    let mut docids = RoaringBitmap::new();
+    for word in term.all_single_words_except_prefix_db(ctx)? {
+        if Some(word.interned()) == exact_word {
+            if let Some(word_docids) = ctx.word_docids(universe, word)? {
+                docids |= word_docids;
+            }
+        } else {
+            for &fid in &prefix_enabled_fids {
+                if let Some(word_fid_docids) =
+                    ctx.get_db_word_fid_docids(universe, word.interned(), fid)?
+                {
+                    docids |= word_fid_docids;
+                }
+            }
+        }
+    }
+    for phrase in term.all_phrases(ctx)? {
+        docids |= ctx.get_phrase_docids(phrase)?;
+    }
+
+    if let Some(prefix) = term.use_prefix_db(ctx) {
+        for &fid in &prefix_enabled_fids {
+            if let Some(prefix_fid_docids) =
+                ctx.get_db_word_prefix_fid_docids(universe, prefix.interned(), fid)?
+            {
+                docids |= prefix_fid_docids;
+            }
+        }
+    }
+
+    match universe {
+        Some(universe) => Ok(docids & universe),
+        None => Ok(docids),
+    }
+}
+
+/// Returns the documents with a numeric facet value equal to the term, when `match_numeric_facets` is enabled.
+///
+/// These documents don't match the term in any attribute or at any position, so the fid and position
+/// ranking rules only return them from their artificial max cost condition.
+pub fn compute_query_term_subset_numeric_facet_docids(
+    ctx: &mut SearchContext<'_>,
+    universe: Option<&RoaringBitmap>,
+    term: &QueryTermSubset,
+) -> Result<RoaringBitmap> {
+    match term.original_single_word(ctx) {
+        Some(word) if ctx.match_numeric_facets => ctx.get_number_facet_docids(universe, word),
+        _ => Ok(RoaringBitmap::new()),
+    }
+}
+
 pub fn compute_query_term_subset_docids_within_field_id(
     ctx: &mut SearchContext<'_>,
     universe: Option<&RoaringBitmap>,
     term: &QueryTermSubset,
     fid: u16,
 ) -> Result<RoaringBitmap> {
+    if term.scoped_field_id(ctx).map_or(false, |scoped_fid| scoped_fid != fid) {
+        return Ok(RoaringBitmap::new());
+    }
+
+    // in an attribute without prefix search, a prefix term only matches its exact word
+    let prefix_disabled =
+        term.is_prefix(ctx) && ctx.prefix_disabled_fids.binary_search(&fid).is_ok();
+    let exact_word = match term.exact_term(ctx) {
+        Some(ExactTerm::Word(word)) => Some(word),
+        _ => None,
+    };
+
     let mut docids = RoaringBitmap::new();
     for word in term.all_single_words_except_prefix_db(ctx)? {
+        if prefix_disabled && Some(word.interned()) != exact_word {
+            continue;
+        }
         if let Some(word_fid_docids) = ctx.get_db_word_fid_docids(universe, word.interned(), fid)? {
             docids |= word_fid_docids;
         }
@@ -81,7 +208,7 @@ pub fn compute_query_term_subset_docids_within_field_id(
         }
     }
 
-    if let Some(word_prefix) = term.use_prefix_db(ctx) {
+    if let Some(word_prefix) = term.use_prefix_db(ctx).filter(|_| !prefix_disabled) {
         if let Some(word_fid_docids) =
             ctx.get_db_word_prefix_fid_docids(universe, word_prefix.interned(), fid)?
         {
@@ -126,6 +253,10 @@ pub fn compute_query_term_subset_docids_within_position(
             docids |= word_position_docids;
         }
     }
+
+    if let Some(fid) = term.scoped_field_id(ctx) {
+        docids &= compute_query_term_subset_docids_within_field_id(ctx, universe, term, fid)?;
+    }
     Ok(docids)
 }
 
@@ -184,6 +315,103 @@ pub fn compute_query_graph_docids(
     panic!()
 }
 
+/// Returns the subset of the input universe that satisfies the contraints of the input query graph
+/// through a path whose terms have at most `typo_budget` typos in total.
+pub fn compute_query_graph_docids_within_typo_budget(
+    ctx: &mut SearchContext<'_>,
+    q: &QueryGraph,
+    universe: &RoaringBitmap,
+    typo_budget: u8,
+) -> Result<RoaringBitmap> {
+    let budget = typo_budget as usize;
+    let mut nodes_resolved = SmallBitmap::for_interned_values_in(&q.nodes);
+    // The docids of the paths ending at each node, indexed by their number of typos
+    let mut path_nodes_docids = q.nodes.map(|_| vec![RoaringBitmap::new(); budget + 1]);
+
+    let mut next_nodes_to_visit = VecDeque::new();
+    next_nodes_to_visit.push_back(q.root_node);
+
+    while let Some(node_id) = next_nodes_to_visit.pop_front() {
+        let node = q.nodes.get(node_id);
+        let predecessors = &node.predecessors;
+        if !predecessors.is_subset(&nodes_resolved) {
+            next_nodes_to_visit.push_back(node_id);
+            continue;
+        }
+
+        let node_docids = match &node.data {
+            QueryNodeData::Term(LocatedQueryTermSubset {
+                term_subset,
+                positions: _,
+                term_ids: _,
+            }) => {
+                let mut node_docids = vec![RoaringBitmap::new(); budget + 1];
+                for nbr_typos in 0..=term_subset.max_typo_cost(ctx).min(typo_budget) {
+                    let mut term_subset = term_subset.clone();
+                    match nbr_typos {
+                        0 => {
+                            term_subset.clear_one_typo_subset();
+                            term_subset.clear_two_typo_subset();
+                        }
+                        1 => {
+                            term_subset.clear_zero_typo_subset();
+                            term_subset.clear_two_typo_subset();
+                        }
+                        _ => {
+                            term_subset.clear_zero_typo_subset();
+                            term_subset.clear_one_typo_subset();
+                        }
+                    }
+                    for typos in nbr_typos as usize..=budget {
+                        let predecessors_docids = MultiOps::union(
+                            predecessors
+                                .iter()
+                                .map(|p| &path_nodes_docids.get(p)[typos - nbr_typos as usize]),
+                        );
+                        if predecessors_docids.is_empty() {
+                            continue;
+                        }
+                        node_docids[typos] |= compute_query_term_subset_docids(
+                            ctx,
+                            Some(&predecessors_docids),
+                            &term_subset,
+                        )?;
+                    }
+                }
+                node_docids
+            }
+            QueryNodeData::Deleted => {
+                panic!()
+            }
+            QueryNodeData::Start => {
+                let mut node_docids = vec![RoaringBitmap::new(); budget + 1];
+                node_docids[0] = universe.clone();
+                node_docids
+            }
+            QueryNodeData::End => {
+                return Ok(MultiOps::union(
+                    predecessors.iter().flat_map(|p| path_nodes_docids.get(p).iter()),
+                ));
+            }
+        };
+        nodes_resolved.insert(node_id);
+        *path_nodes_docids.get_mut(node_id) = node_docids;
+
+        for succ in node.successors.iter() {
+            if !next_nodes_to_visit.contains(&succ) && !nodes_resolved.contains(succ) {
+                next_nodes_to_visit.push_back(succ);
+            }
+        }
+
+        for prec in node.predecessors.iter() {
+            if q.nodes.get(prec).successors.is_subset(&nodes_resolved) {
+                path_nodes_docids.get_mut(prec).clear();
+            }
+        }
+    }
+    panic!()
+}
+
 pub fn compute_phrase_docids(
     ctx: &mut SearchContext<'_>,
     phrase: Interned<Phrase>,
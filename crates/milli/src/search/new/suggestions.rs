@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+
+use fst::automaton::Str;
+use fst::{Automaton, IntoStreamer, Streamer};
+use itertools::Itertools;
+
+use super::query_term::{number_of_typos_allowed, LocatedQueryTerm};
+use super::{limits, SearchContext};
+use crate::search::build_dfa;
+use crate::Result;
+
+/// Maximum number of alternative queries suggested for a single search.
+pub const MAX_QUERY_SUGGESTIONS: usize = 3;
+
+/// Returns alternative queries in which the misspelled words of the query
+/// are replaced by their closest words in the index vocabulary.
+///
+/// A word is considered misspelled when the words FST doesn't contain it or, for
+/// the prefix word, when no word of the FST starts with it. Its corrections are the
+/// words one or two typos away from it, ordered by number of typos then by decreasing
+/// document frequency, following the typo tolerance settings of the index.
+/// No suggestion is returned when every word of the query is known.
+pub fn query_suggestions(
+    ctx: &mut SearchContext<'_>,
+    located_query_terms: &[LocatedQueryTerm],
+) -> Result<Vec<String>> {
+    let fst = ctx.get_words_fst()?;
+    let nbr_typos = number_of_typos_allowed(ctx)?;
+
+    // the ngrams are appended after the original terms, we only keep the original ones
+    let mut terms: Vec<_> = located_query_terms
+        .iter()
+        .filter(|term| !ctx.term_interner.get(term.value).is_ngram())
+        .collect();
+    terms.sort_by_key(|term| *term.positions.start());
+
+    let mut words = Vec::with_capacity(terms.len());
+    let mut corrections = Vec::with_capacity(terms.len());
+    for term in terms {
+        let query_term = ctx.term_interner.get(term.value);
+        if let Some(phrase) = query_term.original_phrase() {
+            words.push(format!("\"{}\"", phrase.description(ctx)));
+            corrections.push(Vec::new());
+            continue;
+        }
+
+        let word = query_term.original_word(ctx);
+        let known = if query_term.is_prefix() {
+            fst.search(Str::new(&word).starts_with()).into_stream().next().is_some()
+        } else {
+            fst.contains(&word)
+        };
+
+        let word_corrections =
+            if known { Vec::new() } else { word_corrections(ctx, &fst, &word, nbr_typos(&word))? };
+        words.push(word);
+        corrections.push(word_corrections);
+    }
+
+    if corrections.iter().all(Vec::is_empty) {
+        return Ok(Vec::new());
+    }
+
+    let mut suggestions = Vec::new();
+    for i in 0..MAX_QUERY_SUGGESTIONS {
+        let suggestion = words
+            .iter()
+            .zip(&corrections)
+            .map(|(word, corrections)| match corrections.get(i).or(corrections.last()) {
+                Some(correction) => correction.as_str(),
+                None => word.as_str(),
+            })
+            .join(" ");
+        if !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+    }
+
+    Ok(suggestions)
+}
+
+/// Returns the [`MAX_QUERY_SUGGESTIONS`] words of the FST that are one or two typos away
+/// from the given word, the closest and most frequent ones first.
+///
+/// The derivations are ranked before keeping the best ones, the FST order doesn't
+/// tell anything about the frequency of the words. Only the first
+/// [`limits::MAX_ONE_TYPO_COUNT`] and [`limits::MAX_TWO_TYPOS_COUNT`] derivations
+/// are ranked, like the derivations of the query terms.
+fn word_corrections(
+    ctx: &SearchContext<'_>,
+    fst: &fst::Set<Cow<'_, [u8]>>,
+    word: &str,
+    max_typo: u8,
+) -> Result<Vec<String>> {
+    if max_typo == 0 {
+        return Ok(Vec::new());
+    }
+
+    let dfa = build_dfa(word, max_typo, false);
+    let mut stream = fst.search_with_state(&dfa).into_stream();
+
+    let mut corrections = Vec::new();
+    let mut one_typo_count = 0;
+    let mut two_typos_count = 0;
+    while let Some((derived_word, state)) = stream.next() {
+        if one_typo_count >= limits::MAX_ONE_TYPO_COUNT
+            && (max_typo == 1 || two_typos_count >= limits::MAX_TWO_TYPOS_COUNT)
+        {
+            break;
+        }
+        // every derivation costs a lookup of its frequency, bound them like the query terms do
+        let distance = dfa.distance(state).to_u8();
+        match distance {
+            1 if one_typo_count < limits::MAX_ONE_TYPO_COUNT => one_typo_count += 1,
+            2 if two_typos_count < limits::MAX_TWO_TYPOS_COUNT => two_typos_count += 1,
+            _ => continue,
+        }
+        let derived_word = std::str::from_utf8(derived_word)?.to_owned();
+        let frequency = ctx.index.word_documents_count(ctx.txn, &derived_word)?.unwrap_or(0);
+        corrections.push((distance, Reverse(frequency), derived_word));
+    }
+
+    corrections.sort_unstable();
+    corrections.truncate(MAX_QUERY_SUGGESTIONS);
+    Ok(corrections.into_iter().map(|(_, _, word)| word).collect())
+}
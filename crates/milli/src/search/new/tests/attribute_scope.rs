@@ -0,0 +1,157 @@
+/*!
+This module tests the terms scoped to an attribute, written `attribute:term`:
+- the scoped term only matches in its attribute, the other terms match anywhere
+- the scoped term keeps its typo tolerance
+- the scoped term is never removed by the `last` terms matching strategy
+- an unknown attribute is searched as a regular word
+- a non-searchable attribute, or one that is not searched on, is searched as a regular word
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "body".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "title": "cheap shoes",
+            "body": "",
+            "price": 10,
+        },
+        {
+            "id": 1,
+            "title": "shoes",
+            "body": "cheap leather",
+            "price": 20,
+        },
+        {
+            "id": 2,
+            "title": "cheap hat",
+            "body": "red shoes",
+            "price": 30,
+        },
+        {
+            "id": 3,
+            "title": "cheap boots",
+            "body": "",
+            "price": 40,
+        },
+        {
+            "id": 4,
+            "title": "boots",
+            "body": "boots of size 42",
+            "price": 50,
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(
+    index: &TempIndex,
+    query: &str,
+    strategy: TermsMatchingStrategy,
+) -> crate::Result<Vec<u32>> {
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, index);
+    s.query(query);
+    s.terms_matching_strategy(strategy);
+    let SearchResult { mut documents_ids, .. } = s.execute()?;
+    documents_ids.sort_unstable();
+    Ok(documents_ids)
+}
+
+#[test]
+fn test_scoped_term() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "cheap shoes", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![0, 1, 2]);
+
+    // `cheap` only appears in the body of the document 1
+    let documents_ids = search(&index, "title:cheap shoes", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![0, 2]);
+
+    let documents_ids = search(&index, "body:cheap shoes", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![1]);
+
+    // the scoped term is also restricted when it is the prefix at the end of the query
+    let documents_ids = search(&index, "shoes title:chea", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![0, 2]);
+}
+
+#[test]
+fn test_scoped_term_typo() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "title:cheep", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![0, 2, 3]);
+}
+
+#[test]
+fn test_scoped_term_is_mandatory() {
+    let index = create_index();
+
+    // `shoes` can be removed but `title:cheap` can't
+    let documents_ids = search(&index, "title:cheap shoes", TermsMatchingStrategy::Last).unwrap();
+    assert_eq!(documents_ids, vec![0, 2, 3]);
+}
+
+#[test]
+fn test_unknown_attribute() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "size:42", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, vec![4]);
+}
+
+#[test]
+fn test_non_searchable_attribute() {
+    let index = create_index();
+
+    // `price` is not searchable, `price:10` is made of the words `price` and `10`
+    let documents_ids = search(&index, "price:10", TermsMatchingStrategy::All).unwrap();
+    assert_eq!(documents_ids, Vec::<u32>::new());
+
+    let documents_ids = search(&index, "boots price:10", TermsMatchingStrategy::Last).unwrap();
+    assert_eq!(documents_ids, vec![3, 4]);
+
+    // the search doesn't fail, it behaves exactly like the plain words
+    let plain_documents_ids =
+        search(&index, "boots price 10", TermsMatchingStrategy::Last).unwrap();
+    assert_eq!(documents_ids, plain_documents_ids);
+}
+
+#[test]
+fn test_attribute_not_searched_on() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+    let searchable_attributes = vec!["body".to_owned()];
+
+    // the scope is kept for an attribute that is searched on
+    let mut s = Search::new(&txn, &index);
+    s.query("body:cheap");
+    s.searchable_attributes(&searchable_attributes);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1]);
+
+    // `title` is searchable but not searched on, `title:shoes` is made of regular words
+    // that the `last` strategy can remove
+    let mut s = Search::new(&txn, &index);
+    s.query("cheap title:shoes");
+    s.searchable_attributes(&searchable_attributes);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1]);
+}
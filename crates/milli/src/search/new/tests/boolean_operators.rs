@@ -0,0 +1,131 @@
+/*!
+This module tests the boolean operators written in the search query:
+- the terms of a parenthesized `OR` are alternatives of each other
+- the terms bound by an operator are never removed by the `last` terms matching strategy
+- the operators can be mixed from left to right, an `OR` between groups of terms filters the documents
+- operators in lowercase, or without an operand on both sides, are regular words
+- the operators are regular words unless they are enabled
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "text": "red shoes",
+        },
+        {
+            "id": 1,
+            "text": "blue shoes",
+        },
+        {
+            "id": 2,
+            "text": "green shoes",
+        },
+        {
+            "id": 3,
+            "text": "red hat",
+        },
+        {
+            "id": 4,
+            "text": "shoes or boots",
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, query: &str, strategy: TermsMatchingStrategy) -> Vec<u32> {
+    search_with_operators(index, query, strategy, true)
+}
+
+fn search_with_operators(
+    index: &TempIndex,
+    query: &str,
+    strategy: TermsMatchingStrategy,
+    boolean_operators: bool,
+) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, index);
+    s.query(query);
+    s.terms_matching_strategy(strategy);
+    s.boolean_operators(boolean_operators);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    documents_ids
+}
+
+#[test]
+fn test_parenthesized_or() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "(red OR blue) shoes", TermsMatchingStrategy::All);
+    assert_eq!(documents_ids, vec![0, 1]);
+
+    // `shoes` can be removed, but one of the alternatives must match
+    let documents_ids = search(&index, "(red OR blue) shoes", TermsMatchingStrategy::Last);
+    assert_eq!(documents_ids, vec![0, 1, 3]);
+
+    // the alternatives can also be written without parentheses
+    let documents_ids = search(&index, "red OR blue", TermsMatchingStrategy::All);
+    assert_eq!(documents_ids, vec![0, 1, 3]);
+}
+
+#[test]
+fn test_mixed_operators() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "(red OR blue) AND shoes", TermsMatchingStrategy::Last);
+    assert_eq!(documents_ids, vec![0, 1]);
+
+    let documents_ids =
+        search(&index, "(red OR green) AND shoes OR hat", TermsMatchingStrategy::Last);
+    assert_eq!(documents_ids, vec![0, 2, 3]);
+
+    // `(shoes AND red) OR blue`
+    let documents_ids = search(&index, "shoes AND red OR blue", TermsMatchingStrategy::Last);
+    assert_eq!(documents_ids, vec![0, 1]);
+
+    let documents_ids = search(&index, "red AND hat shoes", TermsMatchingStrategy::Last);
+    assert_eq!(documents_ids, vec![3]);
+}
+
+#[test]
+fn test_operators_as_words() {
+    let index = create_index();
+
+    // lowercase operators are regular words
+    let documents_ids = search(&index, "shoes or boots", TermsMatchingStrategy::All);
+    assert_eq!(documents_ids, vec![4]);
+
+    // an operator without a right operand is a regular word
+    let documents_ids = search(&index, "(shoes OR) boots", TermsMatchingStrategy::All);
+    assert_eq!(documents_ids, vec![4]);
+}
+
+#[test]
+fn test_operators_disabled() {
+    let index = create_index();
+
+    // `OR` is a regular word and the parentheses are ignored
+    let documents_ids =
+        search_with_operators(&index, "(shoes OR boots)", TermsMatchingStrategy::All, false);
+    assert_eq!(documents_ids, vec![4]);
+
+    let documents_ids =
+        search_with_operators(&index, "(red OR blue) shoes", TermsMatchingStrategy::All, false);
+    assert_eq!(documents_ids, Vec::<u32>::new());
+}
@@ -0,0 +1,76 @@
+/*!
+This module tests the `distinct_content` search parameter:
+- the documents identical in the compared fields are collapsed to the best ranked one
+- the fields that are not compared can differ between the collapsed documents
+- a document without any of the compared fields is never collapsed
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "body".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "kefir the dog", "body": "a good boy", "source": "a.com" },
+            { "id": 1, "title": "kefir the dog", "body": "a good boy", "source": "b.com" },
+            { "id": 2, "title": "kefir the dog", "body": "a very good boy", "source": "a.com" },
+            { "id": 3, "source": "kefir.com" },
+            { "id": 4, "source": "kefir.com" },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_distinct_content_collapses_identical_documents() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("kefir");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 1, 2]);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("kefir");
+    s.distinct_content(vec!["title".to_owned(), "body".to_owned()]);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 2]);
+
+    // only the title is compared, the three documents are identical
+    let mut s = Search::new(&txn, &index);
+    s.query("kefir");
+    s.distinct_content(vec!["title".to_owned()]);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0]);
+}
+
+#[test]
+fn test_distinct_content_placeholder_search() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    // the documents 3 and 4 have none of the compared fields and are kept
+    let mut s = Search::new(&txn, &index);
+    s.distinct_content(vec!["title".to_owned(), "body".to_owned()]);
+    s.exhaustive_number_hits(true);
+    let SearchResult { documents_ids, candidates, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 2, 3, 4]);
+    assert_eq!(candidates.len(), 4);
+
+    let mut s = Search::new(&txn, &index);
+    s.distinct_content(vec!["title".to_owned(), "body".to_owned()]);
+    s.offset(1);
+    s.limit(2);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![2, 3]);
+}
@@ -0,0 +1,84 @@
+/*!
+This module tests the `distinct_representative` search parameter:
+- among the equally ranked documents sharing a distinct value, the one with the highest
+  value for the representative field is kept, `true` being higher than `false`
+- a better ranked document is kept whatever its value for the representative field
+- the representatives are also elected in a placeholder search
+*/
+
+use big_s::S;
+use maplit::hashset;
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+            s.set_filterable_fields(hashset! { S("sku"), S("inStock"), S("price") });
+            s.set_distinct_field("sku".to_owned());
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "red shoe", "sku": "A", "inStock": false, "price": 10 },
+            { "id": 1, "title": "red shoe", "sku": "A", "inStock": true, "price": 20 },
+            { "id": 2, "title": "red shoe", "sku": "B", "inStock": true, "price": 30 },
+            { "id": 3, "title": "red shoe", "sku": "B", "inStock": false, "price": 5 },
+            { "id": 4, "title": "red", "sku": "A", "inStock": true, "price": 25 },
+            { "id": 5, "title": "red shoe", "sku": "C" },
+            { "id": 6, "title": "red shoe", "sku": "D", "inStock": false, "price": 40 },
+            { "id": 7, "title": "red", "sku": "D", "inStock": true, "price": 50 },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_distinct_representative_prefers_highest_value() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("red shoe");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 2, 5, 6]);
+
+    // the document 7 is in stock but is ranked below the document 6
+    let mut s = Search::new(&txn, &index);
+    s.query("red shoe");
+    s.distinct_representative(S("inStock"));
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1, 2, 5, 6]);
+}
+
+#[test]
+fn test_distinct_representative_placeholder_search() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let s = Search::new(&txn, &index);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 2, 5, 6]);
+
+    let mut s = Search::new(&txn, &index);
+    s.distinct_representative(S("price"));
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![2, 4, 5, 7]);
+}
+
+#[test]
+fn test_distinct_representative_must_be_filterable() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.distinct_representative(S("title"));
+    let error = s.execute().unwrap_err();
+    assert!(error.to_string().starts_with("Attribute `title` is not filterable"), "{error}");
+}
@@ -0,0 +1,158 @@
+/*!
+This module tests the `compute_facets` search parameter:
+- the facet values are counted over the documents matching the query and the filter
+- the facet distribution is only computed when requested
+- the distribution is not exhaustive when a facet has too many values
+- the facets must be filterable
+- hybrid searches count the facet values once, over the candidates of both searches
+*/
+
+use std::sync::Arc;
+
+use big_s::S;
+use maplit::{btreemap, hashset};
+use serde_json::json;
+
+use crate::documents::mmap_from_objects;
+use crate::index::tests::TempIndex;
+use crate::update::Setting;
+use crate::vector::settings::{EmbedderSource, EmbeddingSettings};
+use crate::vector::Embedder;
+use crate::{Filter, Search, SearchResult, DEFAULT_VALUES_PER_FACET};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(hashset! { S("color"), S("size"), S("rank") });
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "red apple", "color": "red", "size": 2 },
+            { "id": 1, "text": "green apple", "color": "green", "size": 2 },
+            { "id": 2, "text": "red cherry", "color": "red", "size": 1 },
+            { "id": 3, "text": "yellow banana", "color": "yellow" },
+            { "id": 4, "text": "green pear", "color": "green", "size": 3 },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_facet_distribution_of_candidates() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("apple");
+    s.compute_facets(vec![S("color"), S("size")]);
+    let SearchResult { facet_distribution, facet_distribution_exhaustive, .. } =
+        s.execute().unwrap();
+    assert_eq!(
+        facet_distribution,
+        Some(btreemap! {
+            S("color") => btreemap! { S("green") => 1, S("red") => 1 },
+            S("size") => btreemap! { S("2") => 2 },
+        })
+    );
+    assert!(facet_distribution_exhaustive);
+
+    let mut s = Search::new(&txn, &index);
+    s.filter(Filter::from_str("color != yellow").unwrap().unwrap());
+    s.compute_facets(vec![S("color")]);
+    let SearchResult { facet_distribution, .. } = s.execute().unwrap();
+    assert_eq!(
+        facet_distribution,
+        Some(btreemap! { S("color") => btreemap! { S("green") => 2, S("red") => 2 } })
+    );
+
+    let s = Search::new(&txn, &index);
+    let SearchResult { facet_distribution, .. } = s.execute().unwrap();
+    assert_eq!(facet_distribution, None);
+}
+
+#[test]
+fn test_facet_distribution_not_exhaustive() {
+    let index = create_index();
+
+    let documents: Vec<_> = (0..=DEFAULT_VALUES_PER_FACET)
+        .map(|i| json!({ "id": 10 + i, "text": "plum", "rank": i }).as_object().unwrap().clone())
+        .collect();
+    index.add_documents(mmap_from_objects(documents)).unwrap();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("plum");
+    s.compute_facets(vec![S("rank")]);
+    let SearchResult { facet_distribution, facet_distribution_exhaustive, .. } =
+        s.execute().unwrap();
+    assert_eq!(facet_distribution.unwrap()["rank"].len(), DEFAULT_VALUES_PER_FACET);
+    assert!(!facet_distribution_exhaustive);
+
+    let mut s = Search::new(&txn, &index);
+    s.compute_facets(vec![S("text")]);
+    let error = s.execute().unwrap_err();
+    assert!(error.to_string().contains("attribute `text` is not filterable"), "{error}");
+}
+
+#[test]
+fn test_facet_distribution_of_hybrid_search() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(hashset! { S("color") });
+            let embedder = EmbeddingSettings {
+                source: Setting::Set(EmbedderSource::UserProvided),
+                dimensions: Setting::Set(2),
+                ..Default::default()
+            };
+            s.set_embedder_settings(btreemap! { S("manual") => Setting::Set(embedder) });
+        })
+        .unwrap();
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "red apple", "color": "red", "_vectors": { "manual": [1.0, 0.0] } },
+            { "id": 1, "text": "green apple", "color": "green", "_vectors": { "manual": [0.0, 1.0] } },
+            { "id": 2, "text": "yellow banana", "color": "yellow", "_vectors": { "manual": [1.0, 1.0] } },
+        ]))
+        .unwrap();
+
+    let txn = index.read_txn().unwrap();
+    let config = index.embedding_configs(&txn).unwrap().pop().unwrap();
+    let embedder = Arc::new(Embedder::new(config.config.embedder_options).unwrap());
+
+    // the semantic search matches every document, even the ones without the keyword
+    let mut s = Search::new(&txn, &index);
+    s.query("apple");
+    s.semantic(config.name.clone(), embedder.clone(), false, Some(vec![1.0, 0.0]));
+    s.compute_facets(vec![S("color")]);
+    let (SearchResult { candidates, facet_distribution, facet_distribution_exhaustive, .. }, _) =
+        s.execute_hybrid(1.0).unwrap();
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(
+        facet_distribution,
+        Some(btreemap! {
+            S("color") => btreemap! { S("green") => 1, S("red") => 1, S("yellow") => 1 },
+        })
+    );
+    assert!(facet_distribution_exhaustive);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("apple");
+    s.semantic(config.name, embedder, false, Some(vec![1.0, 0.0]));
+    s.filter(Filter::from_str("color != yellow").unwrap().unwrap());
+    s.compute_facets(vec![S("color")]);
+    let (SearchResult { facet_distribution, .. }, _) = s.execute_hybrid(1.0).unwrap();
+    assert_eq!(
+        facet_distribution,
+        Some(btreemap! { S("color") => btreemap! { S("green") => 1, S("red") => 1 } })
+    );
+}
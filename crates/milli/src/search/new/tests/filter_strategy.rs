@@ -0,0 +1,78 @@
+/*!
+This module tests the order of evaluation of the filter and the query:
+- the returned documents, their scores and the candidates are the same whatever the strategy
+- the strategy is irrelevant for a placeholder search
+*/
+
+use serde_json::{json, Value};
+
+use crate::index::tests::TempIndex;
+use crate::score_details::ScoringStrategy;
+use crate::{Filter, FilterStrategy, Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(["category".to_owned()].into());
+        })
+        .unwrap();
+
+    // only the first document contains `unicorn`, making it selective
+    let documents: Vec<Value> = (0..30)
+        .map(|id| {
+            let text = if id == 0 { "the story of a rare unicorn" } else { "the story of a horse" };
+            json!({ "id": id, "text": text, "category": id % 3 })
+        })
+        .collect();
+    index.add_documents(documents!(documents)).unwrap();
+    index
+}
+
+fn search(
+    index: &TempIndex,
+    query: Option<&str>,
+    filter: &str,
+    strategy: FilterStrategy,
+) -> (Vec<u32>, String, Vec<u32>) {
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, index);
+    if let Some(query) = query {
+        s.query(query);
+    }
+    s.filter(Filter::from_str(filter).unwrap().unwrap());
+    s.filter_strategy(strategy);
+    s.scoring_strategy(ScoringStrategy::Detailed);
+    s.limit(100);
+    let SearchResult { documents_ids, document_scores, candidates, .. } = s.execute().unwrap();
+    (documents_ids, format!("{document_scores:?}"), candidates.into_iter().collect())
+}
+
+#[test]
+fn test_filter_strategies_return_the_same_results() {
+    let index = create_index();
+
+    for query in
+        [Some("story unicorn"), Some("unicorn story"), Some("horse sto"), Some("dragon"), None]
+    {
+        for filter in ["category = 0", "category != 1", "NOT category = 2 AND category > 0"] {
+            let expected = search(&index, query, filter, FilterStrategy::FilterFirst);
+            for strategy in [FilterStrategy::QueryFirst, FilterStrategy::Auto] {
+                let results = search(&index, query, filter, strategy);
+                assert_eq!(
+                    results, expected,
+                    "{strategy:?} differs for query {query:?} and filter {filter:?}"
+                );
+            }
+        }
+    }
+
+    let (documents_ids, _, candidates) =
+        search(&index, Some("story unicorn"), "category = 0", FilterStrategy::Auto);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 3, 6, 9, 12, 15, 18, 21, 24, 27]");
+    assert_eq!(candidates.len(), 10);
+}
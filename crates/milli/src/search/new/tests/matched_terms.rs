@@ -0,0 +1,106 @@
+/*!
+This module tests the matched terms returned for each document:
+- they are disabled by default
+- each document is returned with the subset of the query words it contains
+- phrases are returned as a whole, between double quotes
+- a placeholder search doesn't match any term
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "text": "the quick brown fox",
+        },
+        {
+            "id": 1,
+            "text": "a quick dog",
+        },
+        {
+            "id": 2,
+            "text": "the lazy fox",
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_matched_terms_disabled_by_default() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("fox quick");
+    let SearchResult { matched_terms, .. } = s.execute().unwrap();
+    assert_eq!(matched_terms, None);
+}
+
+#[test]
+fn test_matched_terms_subsets() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("fox quick cat");
+    s.matched_terms(true);
+    let SearchResult { documents_ids, matched_terms, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 2]);
+    insta::assert_debug_snapshot!(matched_terms.unwrap(), @r###"
+    [
+        [
+            "fox",
+            "quick",
+        ],
+        [
+            "fox",
+        ],
+    ]
+    "###);
+}
+
+#[test]
+fn test_matched_terms_phrase() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    s.query("\"quick brown\" dog");
+    s.matched_terms(true);
+    let SearchResult { documents_ids, matched_terms, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0]);
+    insta::assert_debug_snapshot!(matched_terms.unwrap(), @r###"
+    [
+        [
+            "\"quick brown\"",
+        ],
+    ]
+    "###);
+}
+
+#[test]
+fn test_matched_terms_placeholder() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.matched_terms(true);
+    let SearchResult { documents_ids, matched_terms, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 1, 2]);
+    assert_eq!(matched_terms, Some(vec![Vec::<String>::new(); 3]));
+}
@@ -0,0 +1,119 @@
+/*!
+This module tests the `max_total_hits` search parameter:
+- the total hits are bounded by the maximum
+- the candidates are not truncated, the facet distribution is computed on all of them
+- the documents past the maximum can still be retrieved with the offset
+- the exhaustive count of the distinct documents is bounded, not the distinct candidates
+*/
+
+use serde_json::{json, Value};
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_filterable_fields(["group".to_owned()].into());
+        })
+        .unwrap();
+
+    let documents: Vec<Value> =
+        (0..50).map(|id| json!({ "id": id, "text": "hello world", "group": id % 10 })).collect();
+    index.add_documents(documents!(documents)).unwrap();
+    index
+}
+
+#[test]
+fn test_max_total_hits_bounds_the_total_hits() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.max_total_hits(20);
+    let SearchResult { candidates, total_hits, documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 20);
+    assert_eq!(candidates.len(), 50);
+    assert_eq!(documents_ids.len(), 20);
+
+    // the pagination goes past the maximum
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.max_total_hits(20);
+    s.offset(40);
+    s.limit(20);
+    let SearchResult { total_hits, documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 20);
+    assert_eq!(documents_ids.len(), 10);
+
+    // a maximum above the number of candidates changes nothing
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.max_total_hits(1000);
+    let SearchResult { total_hits, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 50);
+}
+
+#[test]
+fn test_max_total_hits_keeps_the_facet_distribution_of_all_the_candidates() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.max_total_hits(5);
+    s.compute_facets(vec!["group".to_owned()]);
+    let SearchResult {
+        candidates,
+        total_hits,
+        facet_distribution,
+        facet_distribution_exhaustive,
+        ..
+    } = s.execute().unwrap();
+    assert_eq!(total_hits, 5);
+    assert_eq!(candidates.len(), 50);
+    // the documents past the maximum are counted in every group
+    let groups = &facet_distribution.unwrap()["group"];
+    assert_eq!(groups.len(), 10);
+    assert!(groups.values().all(|&count| count == 5));
+    assert!(facet_distribution_exhaustive);
+}
+
+#[test]
+fn test_max_total_hits_bounds_the_exhaustive_distinct_count() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.distinct("group".to_owned());
+    s.exhaustive_number_hits(true);
+    let SearchResult { total_hits, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 10);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.distinct("group".to_owned());
+    s.exhaustive_number_hits(true);
+    s.max_total_hits(4);
+    let SearchResult { candidates, total_hits, documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 4);
+    // a single document per group
+    assert_eq!(candidates.len(), 10);
+    assert_eq!(documents_ids.len(), 10);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("hello");
+    s.distinct_content(vec!["group".to_owned()]);
+    s.exhaustive_number_hits(true);
+    s.max_total_hits(4);
+    let SearchResult { candidates, total_hits, documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(total_hits, 4);
+    assert_eq!(candidates.len(), 10);
+    assert_eq!(documents_ids.len(), 10);
+}
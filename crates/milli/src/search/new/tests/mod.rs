@@ -1,18 +1,32 @@
 pub mod attribute_fid;
 pub mod attribute_position;
+pub mod attribute_scope;
+pub mod boolean_operators;
 pub mod cutoff;
 pub mod distinct;
+pub mod distinct_content;
+pub mod distinct_representative;
 pub mod exactness;
+pub mod facet_distribution;
+pub mod filter_strategy;
 pub mod geo_sort;
 pub mod integration;
 #[cfg(feature = "all-tokenizations")]
 #[cfg(not(feature = "chinese-pinyin"))]
 pub mod language;
+pub mod matched_terms;
+pub mod max_total_hits;
+pub mod negative_terms;
 pub mod ngram_split_words;
+pub mod numeric_terms;
+pub mod placeholder;
+pub mod prefix_disabled_attributes;
 pub mod proximity;
 pub mod proximity_typo;
+pub mod search_settings;
 pub mod sort;
 pub mod stop_words;
+pub mod suggestions;
 pub mod typo;
 pub mod typo_proximity;
 pub mod words_tms;
@@ -0,0 +1,76 @@
+/*!
+This module tests the negative words of the query, i.e. the words preceded by a `-`:
+- the documents containing a negative word are excluded from the results
+- a negative word is never a term of the query, whatever the terms matching strategy
+- a `-` that is not directly followed by a word is ignored
+- a `-` at the start of the query is literal, the first word is not negative
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "harry potter and the philosopher stone" },
+            { "id": 1, "title": "harry and sally" },
+            { "id": 2, "title": "dirty harry" },
+            { "id": 3, "title": "the potter wheel" },
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, query: &str, strategy: TermsMatchingStrategy) -> (Vec<u32>, bool) {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    s.query(query);
+    s.terms_matching_strategy(strategy);
+    let SearchResult { mut documents_ids, used_negative_operator, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    (documents_ids, used_negative_operator)
+}
+
+#[test]
+fn test_negative_words() {
+    let index = create_index();
+
+    for strategy in [
+        TermsMatchingStrategy::Last,
+        TermsMatchingStrategy::First,
+        TermsMatchingStrategy::Size,
+        TermsMatchingStrategy::All,
+        TermsMatchingStrategy::Frequency,
+        TermsMatchingStrategy::FrequencyWeighted,
+    ] {
+        // the negative word is not mandatory, even with the `all` strategy
+        assert_eq!(search(&index, "harry -potter", strategy), (vec![1, 2], true));
+    }
+}
+
+#[test]
+fn test_leading_dash() {
+    let index = create_index();
+
+    // the first word is searched as if there were no dash
+    assert_eq!(search(&index, "-potter", TermsMatchingStrategy::Last), (vec![0, 3], false));
+    assert_eq!(search(&index, "-potter harry", TermsMatchingStrategy::All), (vec![0], false));
+    assert_eq!(search(&index, "  -potter harry", TermsMatchingStrategy::All), (vec![0], false));
+}
+
+#[test]
+fn test_lone_dash() {
+    let index = create_index();
+
+    let (documents_ids, _) = search(&index, "harry - potter", TermsMatchingStrategy::All);
+    assert_eq!(documents_ids, vec![0]);
+}
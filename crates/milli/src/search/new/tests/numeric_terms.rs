@@ -0,0 +1,145 @@
+/*!
+This module tests the `match_numeric_facets` search option:
+- an all-digit query word matches the documents with an equal numeric facet value
+- the documents matching the word itself are still returned
+- nothing changes when the option is disabled
+- the attribute ranking rule keeps the numeric facet matches, after the textual matches
+- a number field both searchable and filterable is indexed as text and as a numeric facet
+*/
+
+use std::collections::HashSet;
+
+use crate::index::tests::TempIndex;
+use crate::{Criterion, Filter, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+            s.set_filterable_fields(HashSet::from_iter(["year".to_owned()]));
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "title": "the best movie",
+            "year": 2023,
+        },
+        {
+            "id": 1,
+            "title": "the best movie of 2023",
+            "year": 2022,
+        },
+        {
+            "id": 2,
+            "title": "another movie",
+            "year": 20230,
+        },
+        {
+            "id": 3,
+            "title": "the worst movie",
+            "year": 2021,
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_numeric_terms_disabled() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("2023");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1]);
+}
+
+#[test]
+fn test_numeric_terms_enabled() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("2023");
+    s.match_numeric_facets(true);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![0, 1]);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("best 2023");
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.match_numeric_facets(true);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![0, 1]);
+}
+
+#[test]
+fn test_numeric_terms_attribute_ranking_rule() {
+    let index = create_index();
+    index
+        .update_settings(|s| {
+            s.set_criteria(vec![Criterion::Attribute]);
+        })
+        .unwrap();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("2023");
+    s.match_numeric_facets(true);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1, 0]);
+
+    let mut s = Search::new(&txn, &index);
+    s.query("movie 2023");
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.match_numeric_facets(true);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1, 0]);
+}
+
+#[test]
+fn test_numeric_field_searchable_and_filterable() {
+    let index = create_index();
+    index
+        .update_settings(|s| {
+            s.set_searchable_fields(vec!["title".to_owned(), "year".to_owned()]);
+        })
+        .unwrap();
+
+    let search = |query: &str, filter: Option<&str>| {
+        let txn = index.read_txn().unwrap();
+        let mut s = Search::new(&txn, &index);
+        s.query(query);
+        if let Some(filter) = filter {
+            s.filter(Filter::from_str(filter).unwrap().unwrap());
+        }
+        let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+        documents_ids.sort_unstable();
+        documents_ids
+    };
+
+    // the same value is found by its string form and by a numeric range
+    assert_eq!(search("2021", None), vec![3]);
+    assert_eq!(search("", Some("year 2021 TO 2022")), vec![1, 3]);
+    assert_eq!(search("2021", Some("year 2021 TO 2022")), vec![3]);
+
+    // both representations are updated together
+    index
+        .add_documents(documents!([
+            { "id": 3, "title": "the worst movie", "year": 2024 },
+        ]))
+        .unwrap();
+    assert_eq!(search("2021", None), Vec::<u32>::new());
+    assert_eq!(search("2024", None), vec![3]);
+    assert_eq!(search("", Some("year 2021 TO 2022")), vec![1]);
+    assert_eq!(search("", Some("year > 2023 AND year < 2025")), vec![3]);
+}
@@ -0,0 +1,74 @@
+/*!
+This module tests the placeholder search, i.e. a search without query:
+- the relevancy ranking rules are not initialised
+- the documents are ordered by the sort ranking rules only
+- without any sort ranking rule, the documents are ordered by their internal ids
+*/
+
+use big_s::S;
+use maplit::hashset;
+
+use super::super::{get_ranking_rules_for_placeholder_search, GeoSortStrategy, SearchContext};
+use crate::index::tests::TempIndex;
+use crate::{AscDesc, Criterion, Member, Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_sortable_fields(hashset! { S("price") });
+            s.set_criteria(vec![
+                Criterion::Words,
+                Criterion::Typo,
+                Criterion::Proximity,
+                Criterion::Attribute,
+                Criterion::Sort,
+                Criterion::Exactness,
+            ]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox", "price": 30 },
+            { "id": 1, "text": "the lazy dog", "price": 10 },
+            { "id": 2, "text": "the quick dog", "price": 20 },
+            { "id": 3, "text": "a fox", "price": 40 },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_placeholder_search_skips_relevancy_rules() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+    let ctx = SearchContext::new(&index, &txn).unwrap();
+
+    let rules =
+        get_ranking_rules_for_placeholder_search(&ctx, &None, GeoSortStrategy::default()).unwrap();
+    assert!(rules.is_empty());
+
+    let sort = Some(vec![AscDesc::Desc(Member::Field(S("price")))]);
+    let rules =
+        get_ranking_rules_for_placeholder_search(&ctx, &sort, GeoSortStrategy::default()).unwrap();
+    let ids: Vec<_> = rules.iter().map(|rule| rule.id()).collect();
+    assert_eq!(ids, vec![S("price:desc")]);
+}
+
+#[test]
+fn test_placeholder_search_order() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0, 1, 2, 3]);
+
+    s.sort_criteria(vec![AscDesc::Asc(Member::Field(S("price")))]);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![1, 2, 0, 3]);
+}
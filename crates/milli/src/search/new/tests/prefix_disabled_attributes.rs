@@ -0,0 +1,106 @@
+/*!
+This module tests the `prefix_disabled_attributes` setting:
+- the last query word is not matched as a prefix in the prefix disabled attributes
+- it is still matched as a prefix in the other attributes
+- its exact word is matched in all the attributes
+- a term scoped to a prefix disabled attribute is not matched as a prefix either
+*/
+
+use std::collections::HashSet;
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "code".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "title": "hello",
+            "code": "abc",
+        },
+        {
+            "id": 1,
+            "title": "world",
+            "code": "abcdef",
+        },
+        {
+            "id": 2,
+            "title": "abcdef",
+            "code": "xyz",
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_prefix_enabled_everywhere() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("abc");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_prefix_disabled_attribute() {
+    let index = create_index();
+    index
+        .update_settings(|s| {
+            s.set_prefix_disabled_attributes(HashSet::from(["code".to_owned()]));
+        })
+        .unwrap();
+    let txn = index.read_txn().unwrap();
+
+    // "abc" is matched as a prefix in the title but only exactly in the code
+    let mut s = Search::new(&txn, &index);
+    s.query("abc");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![0, 2]);
+
+    // the words that are not the last one are never prefixes
+    let mut s = Search::new(&txn, &index);
+    s.query("abcdef ");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![1, 2]);
+}
+
+#[test]
+fn test_prefix_disabled_scoped_term() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("code:abc");
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    assert_eq!(documents_ids, vec![0, 1]);
+    drop(txn);
+
+    index
+        .update_settings(|s| {
+            s.set_prefix_disabled_attributes(HashSet::from(["code".to_owned()]));
+        })
+        .unwrap();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("code:abc");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0]);
+}
@@ -12,11 +12,15 @@ This module tests the Proximity ranking rule:
 
 4. The prefix databases can be used to find the sprximity between two words, but
    they store fewer sprximities than the regular word sprximity DB.
+
+5. With the `byAttribute` proximity precision, all the words of the same attribute
+   are considered close to each other.
 */
 
 use std::collections::BTreeMap;
 
 use crate::index::tests::TempIndex;
+use crate::proximity::ProximityPrecision;
 use crate::search::new::tests::collect_field_values;
 use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
 
@@ -487,3 +491,47 @@ fn test_proximity_prefix_db() {
     ]
     "###);
 }
+
+#[test]
+fn test_proximity_precision() {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words, Criterion::Proximity]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            {
+                "id": 0,
+                "text": "the red house has a big garden where I park my car"
+            },
+            {
+                "id": 1,
+                "text": "a red car"
+            },
+        ]))
+        .unwrap();
+
+    let search = |index: &TempIndex| {
+        let txn = index.read_txn().unwrap();
+        let mut s = Search::new(&txn, index);
+        s.terms_matching_strategy(TermsMatchingStrategy::All);
+        s.query("red car");
+        let SearchResult { documents_ids, .. } = s.execute().unwrap();
+        documents_ids
+    };
+
+    // by word, the consecutive words are ranked first
+    insta::assert_snapshot!(format!("{:?}", search(&index)), @"[1, 0]");
+
+    index.update_settings(|s| s.set_proximity_precision(ProximityPrecision::ByAttribute)).unwrap();
+
+    // by attribute, both documents have the words in the same attribute
+    // and are thus ranked by their document id
+    insta::assert_snapshot!(format!("{:?}", search(&index)), @"[0, 1]");
+}
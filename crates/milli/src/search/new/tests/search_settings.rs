@@ -0,0 +1,61 @@
+/*!
+This module tests the `settings` search parameter:
+- the searches sharing settings read beforehand return the same results as the others
+- the sort criteria are validated against the sortable fields of the shared settings
+*/
+
+use std::collections::BTreeSet;
+
+use big_s::S;
+use maplit::hashset;
+
+use crate::index::tests::TempIndex;
+use crate::{AscDesc, Criterion, Member, Search, SearchResult, SearchSettings};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_sortable_fields(hashset! { S("rank") });
+            s.set_stop_words(BTreeSet::from_iter([S("the")]));
+            s.set_criteria(vec![Criterion::Words, Criterion::Sort]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick fox", "rank": 2 },
+            { "id": 1, "text": "the quick brown fox", "rank": 0 },
+            { "id": 2, "text": "the lazy dog", "rank": 1 },
+            { "id": 3, "text": "the brown dog", "rank": 3 },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_shared_settings_same_results() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+    let settings = SearchSettings::new(&index, &txn).unwrap();
+
+    for query in ["the quick fox", "brown dog", "the"] {
+        let mut s = Search::new(&txn, &index);
+        s.query(query);
+        s.sort_criteria(vec![AscDesc::Desc(Member::Field(S("rank")))]);
+        let SearchResult { documents_ids: expected, .. } = s.execute().unwrap();
+
+        s.settings(&settings);
+        let SearchResult { documents_ids, .. } = s.execute().unwrap();
+        assert_eq!(documents_ids, expected, "{query}");
+    }
+
+    let mut s = Search::new(&txn, &index);
+    s.settings(&settings);
+    s.sort_criteria(vec![AscDesc::Desc(Member::Field(S("id")))]);
+    let error = s.execute().unwrap_err();
+    assert!(error.to_string().starts_with("Attribute `id` is not sortable"), "{error}");
+}
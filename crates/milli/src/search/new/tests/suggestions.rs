@@ -0,0 +1,158 @@
+/*!
+This module tests the "did you mean" query suggestions:
+- they are disabled by default
+- they are only computed when less documents than the threshold match the query
+- the misspelled words are replaced by their closest word in the index vocabulary
+- a query where all the words are known doesn't produce any suggestion
+- the most frequent corrections are kept, whatever their position in the vocabulary
+- the words too short to have typos are not corrected
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+        {
+            "id": 0,
+            "title": "Captain Marvel",
+        },
+        {
+            "id": 1,
+            "title": "Captain America",
+        },
+        {
+            "id": 2,
+            "title": "How to Train Your Dragon",
+        },
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_suggestions_disabled_by_default() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("captian marvl");
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn test_suggestions_correct_typos() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("captian marvl");
+    s.suggestions(10);
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    assert_eq!(suggestions, vec!["captain marvel".to_owned()]);
+
+    // the known words are kept as is
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("train your dragom");
+    s.suggestions(10);
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    assert_eq!(suggestions, vec!["train your dragon".to_owned()]);
+}
+
+#[test]
+fn test_suggestions_threshold() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    // one document matches the query thanks to typo tolerance,
+    // it is not less than the threshold
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("captian marvl");
+    s.suggestions(1);
+    let SearchResult { documents_ids, suggestions, .. } = s.execute().unwrap();
+    assert_eq!(documents_ids, vec![0]);
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn test_no_suggestions_for_known_words() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("captain dragon");
+    s.suggestions(10);
+    let SearchResult { documents_ids, suggestions, .. } = s.execute().unwrap();
+    assert!(documents_ids.is_empty());
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn test_suggestions_most_frequent_corrections() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+        })
+        .unwrap();
+
+    // more than a hundred words one typo away from "abcdef", the most
+    // frequent one is the last one of the vocabulary
+    let mut words = Vec::new();
+    for position in 2..6 {
+        for letter in 'a'..='z' {
+            let mut word: Vec<char> = "abcdef".chars().collect();
+            if word[position] != letter {
+                word[position] = letter;
+                words.push(word.into_iter().collect::<String>());
+            }
+        }
+    }
+    words.extend(["abzdef".to_owned(), "abzdef".to_owned()]);
+    let documents: Vec<_> = words
+        .into_iter()
+        .enumerate()
+        .map(|(id, title)| {
+            serde_json::json!({ "id": id, "title": title }).as_object().unwrap().clone()
+        })
+        .collect();
+    index.add_documents(crate::documents::mmap_from_objects(documents)).unwrap();
+
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, &index);
+    s.query("abcdef ");
+    s.suggestions(10);
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    assert_eq!(suggestions.first().map(String::as_str), Some("abzdef"));
+}
+
+#[test]
+fn test_suggestions_short_words() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("captian marvl");
+    s.min_word_len_for_typo(6, 8);
+    s.suggestions(10);
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    assert_eq!(suggestions, vec!["captain marvl".to_owned()]);
+}
@@ -230,6 +230,88 @@ fn test_default_typo() {
     "###);
 }
 
+#[test]
+fn test_min_word_len_for_typo_search_parameter() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    // `quack` is long enough for one typo by default
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quack brown fox jumps over the lazy dog");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+
+    // words shorter than 6 bytes get no typo
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.min_word_len_for_typo(6, 9);
+    s.query("the quack brown fox jumps over the lazy dog");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+
+    // `quack` gets two typos and also matches `quivk`
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.min_word_len_for_typo(2, 5);
+    s.query("the quack brown fox jumps over the lazy dog");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 23]");
+    let texts = collect_field_values(&index, &txn, "text", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"the quick brown fox jumps over the lazy dog\"",
+        "\"the quivk brown fox jumps over the lazy dog\"",
+    ]
+    "###);
+
+    // the lengths must be ordered
+    let mut s = Search::new(&txn, &index);
+    s.min_word_len_for_typo(9, 5);
+    s.query("the quack brown fox");
+    let error = s.execute().unwrap_err();
+    assert!(matches!(
+        error,
+        crate::Error::UserError(crate::UserError::InvalidMinTypoWordLenSetting(9, 5))
+    ));
+}
+
+#[test]
+fn test_typo_budget() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    // two typos on a single word are still allowed
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.typo_budget(true);
+    s.query("sunflawar");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert!(documents_ids.contains(&16), "{documents_ids:?}");
+
+    // a single typo in a query of four words is allowed
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.typo_budget(true);
+    s.query("the quack brown fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert!(documents_ids.contains(&0), "{documents_ids:?}");
+
+    // but two typos are not
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quack browm fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    assert!(documents_ids.contains(&0), "{documents_ids:?}");
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.typo_budget(true);
+    s.query("the quack browm fox");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+}
+
 #[test]
 fn test_phrase_no_typo_allowed() {
     let index = create_index();
@@ -635,3 +717,45 @@ fn test_typo_synonyms() {
     ]
     "###);
 }
+
+#[test]
+fn test_had_typo_correction() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    // 0 typo
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quick brown fox jumps over the lazy dog");
+    s.typo_correction(true);
+    s.limit(1);
+    let SearchResult { documents_ids, had_typo_correction, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+    assert!(!had_typo_correction);
+
+    // the second document only matches "quick" with a typo
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quick brown fox jumps over the lazy dog");
+    s.typo_correction(true);
+    let SearchResult { documents_ids, had_typo_correction, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 23]");
+    assert!(had_typo_correction);
+
+    // it is not computed by default
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quick brown fox jumps over the lazy dog");
+    let SearchResult { documents_ids, had_typo_correction, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 23]");
+    assert!(!had_typo_correction);
+
+    // 1 typo on one word, replaced letter
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("the quack brown fox jumps over the lazy dog");
+    s.typo_correction(true);
+    let SearchResult { documents_ids, had_typo_correction, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+    assert!(had_typo_correction);
+}
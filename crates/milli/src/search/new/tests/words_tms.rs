@@ -10,8 +10,16 @@ This module tests the following properties:
 5. Unclosed double quotes still make a phrase
 6. The `all` term matching strategy does not remove any term from the query
 7. The search is capable of returning no results if no documents match the query
+8. The `frequencyWeighted` term matching strategy removes a short word before a longer
+   and more frequent one, unlike the `frequency` strategy
+9. The `requireRare` term matching strategy only returns the documents containing at least one
+   rare word of the query
+10. The `first` and `size` term matching strategies remove the terms in a different order
+    than the `last` and `frequency` ones
 */
 
+use ordered_float::OrderedFloat;
+
 use crate::index::tests::TempIndex;
 use crate::search::new::tests::collect_field_values;
 use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
@@ -458,3 +466,136 @@ fn test_words_tms_all() {
     let texts = collect_field_values(&index, &txn, "text", &documents_ids);
     insta::assert_debug_snapshot!(texts, @"[]");
 }
+
+#[test]
+fn test_words_tms_frequency_weighted() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+
+    // `photography` is in more documents than `of` but is far longer
+    let documents: Vec<_> = (0..9)
+        .map(|id| {
+            let text = if id < 6 { "photography lessons" } else { "history of art" };
+            serde_json::json!({ "id": id, "text": text })
+        })
+        .collect();
+    index.add_documents(documents!(documents)).unwrap();
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("photography of");
+    s.terms_matching_strategy(TermsMatchingStrategy::Frequency);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[6, 7, 8]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("photography of");
+    s.terms_matching_strategy(TermsMatchingStrategy::FrequencyWeighted);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 3, 4, 5]");
+}
+
+#[test]
+fn test_words_tms_require_rare() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the cat" },
+            { "id": 1, "text": "the dog" },
+            { "id": 2, "text": "the zebra" },
+            { "id": 3, "text": "a zebra" },
+            { "id": 4, "text": "the bird" },
+        ]))
+        .unwrap();
+
+    let txn = index.read_txn().unwrap();
+
+    // the `last` strategy ends up matching the documents that only contain `the`
+    let mut s = Search::new(&txn, &index);
+    s.query("the zebra");
+    s.terms_matching_strategy(TermsMatchingStrategy::Last);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 0, 1, 4]");
+
+    // with 5 documents, the idf of `the` is ln(5/4) ≈ 0.22 and the one of `zebra` ln(5/2) ≈ 0.92,
+    // `zebra` is rare and required, `the` is not
+    let mut s = Search::new(&txn, &index);
+    s.query("the zebra");
+    s.terms_matching_strategy(TermsMatchingStrategy::RequireRare { min_idf: OrderedFloat(0.5) });
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+
+    // without rare word, the strategy removes the most frequent words first
+    let mut s = Search::new(&txn, &index);
+    s.query("the zebra");
+    s.terms_matching_strategy(TermsMatchingStrategy::RequireRare { min_idf: OrderedFloat(1.0) });
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+
+    // `cat` and `zebra` are both rare, at least one of them is required: the documents
+    // that contain `cat` or `zebra` are returned, not the ones that only contain `the`
+    let mut s = Search::new(&txn, &index);
+    s.query("the cat zebra");
+    s.terms_matching_strategy(TermsMatchingStrategy::RequireRare { min_idf: OrderedFloat(0.5) });
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 2, 3]");
+}
+
+#[test]
+fn test_words_tms_removal_order() {
+    let index = TempIndex::new();
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+
+    // `sea` is the first and shortest word, `world` the last one, and `wonderful` the most frequent
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "wonderful world" },
+            { "id": 1, "text": "sea world" },
+            { "id": 2, "text": "sea wonderful" },
+            { "id": 3, "text": "sea" },
+            { "id": 4, "text": "wonderful" },
+            { "id": 5, "text": "world" },
+            { "id": 6, "text": "wonderful" },
+            { "id": 7, "text": "wonderful" },
+            { "id": 8, "text": "world" },
+        ]))
+        .unwrap();
+
+    let txn = index.read_txn().unwrap();
+    let search = |strategy| {
+        let mut s = Search::new(&txn, &index);
+        s.query("sea wonderful world");
+        s.terms_matching_strategy(strategy);
+        let SearchResult { documents_ids, .. } = s.execute().unwrap();
+        format!("{documents_ids:?}")
+    };
+
+    // removes `world`, then `wonderful`
+    insta::assert_snapshot!(search(TermsMatchingStrategy::Last), @"[2, 1, 3]");
+    // removes `sea`, then `wonderful`
+    insta::assert_snapshot!(search(TermsMatchingStrategy::First), @"[0, 1, 5, 8]");
+    // removes `sea`, then `world`
+    insta::assert_snapshot!(search(TermsMatchingStrategy::Size), @"[0, 2, 4, 6, 7]");
+    // removes `wonderful`, then `world`
+    insta::assert_snapshot!(search(TermsMatchingStrategy::Frequency), @"[1, 2, 3]");
+}
@@ -123,11 +123,19 @@ impl<'a> Similar<'a> {
 
         Ok(SearchResult {
             matching_words: Default::default(),
+            total_hits: candidates.len(),
             candidates,
             documents_ids,
             document_scores,
             degraded: false,
             used_negative_operator: false,
+            suggestions: Vec::new(),
+            had_typo_correction: false,
+            matched_terms: None,
+            total_documents: self.index.number_of_documents(self.rtxn)?,
+            matches: None,
+            facet_distribution: None,
+            facet_distribution_exhaustive: true,
         })
     }
 }
@@ -9,7 +9,7 @@ use super::vector_document::VectorDocument;
 use super::{KvReaderFieldId, KvWriterFieldId};
 use crate::constants::{RESERVED_GEO_FIELD_NAME, RESERVED_VECTORS_FIELD_NAME};
 use crate::documents::FieldIdMapper;
-use crate::{DocumentId, GlobalFieldsIdsMap, Index, InternalError, Result, UserError};
+use crate::{DocumentId, FieldId, GlobalFieldsIdsMap, Index, InternalError, Result, UserError};
 
 /// A view into a document that can represent either the current version from the DB,
 /// the update data from payload or other means, or the merged updated version.
@@ -141,6 +141,34 @@ impl<'t, Mapper: FieldIdMapper> DocumentFromDb<'t, Mapper> {
         let Some(value) = self.content.get(fid) else { return Ok(None) };
         Ok(Some(serde_json::from_slice(value).map_err(InternalError::SerdeJson)?))
     }
+
+    /// Returns the name and raw JSON value of the fields with the given ids.
+    ///
+    /// The fields are returned in the order of the given ids, the ones that are missing from
+    /// the document are skipped. Unlike [`Document::iter_top_level_fields`], the other fields
+    /// of the document are never deserialized.
+    pub fn fields<'a>(
+        &'a self,
+        ids: &'a [FieldId],
+    ) -> impl Iterator<Item = Result<(&'t str, &'t RawValue)>> + 'a {
+        ids.iter().filter_map(move |&fid| {
+            let value = self.content.get(fid)?;
+            let res = (|| {
+                let name = self.fields_ids_map.name(fid).ok_or(
+                    InternalError::FieldIdMapMissingEntry(crate::FieldIdMapMissingEntry::FieldId {
+                        field_id: fid,
+                        process: "getting fields of the current document",
+                    }),
+                )?;
+                let value =
+                    serde_json::from_slice(value).map_err(crate::InternalError::SerdeJson)?;
+
+                Ok((name, value))
+            })();
+
+            Some(res)
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -439,3 +467,36 @@ impl<'doc> Versions<'doc> {
         self.data.get(k)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::DocumentFromDb;
+    use crate::index::tests::TempIndex;
+
+    #[test]
+    fn test_fields_projection() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "kefir", "age": 4, "breed": "labrador", "colour": "brown" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let fields_ids_map = index.fields_ids_map(&rtxn).unwrap();
+        let document = DocumentFromDb::new(0, &rtxn, &index, &fields_ids_map).unwrap().unwrap();
+
+        // the fields are returned in the requested order and the unknown ones are skipped
+        let ids = [
+            fields_ids_map.id("colour").unwrap(),
+            fields_ids_map.id("name").unwrap(),
+            fields_ids_map.len() as u16,
+        ];
+        let fields: Vec<_> = document
+            .fields(&ids)
+            .map(|result| result.map(|(name, value)| (name, value.get())))
+            .collect::<crate::Result<_>>()
+            .unwrap();
+        assert_eq!(fields, vec![("colour", "\"brown\""), ("name", "\"kefir\"")]);
+    }
+}
@@ -66,6 +66,8 @@ use std::collections::BinaryHeap;
 use std::fs::File;
 use std::hash::BuildHasher;
 use std::io::BufReader;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
 use std::{io, iter, mem};
 
 use bumpalo::Bump;
@@ -94,6 +96,17 @@ pub struct BalancedCaches<'extractor> {
     caches: InnerCaches<'extractor>,
 }
 
+/// A snapshot of the memory used by a [`BalancedCaches`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheMemoryStats {
+    /// The number of bytes allocated in the bump allocator of the cache.
+    pub allocated_bytes: usize,
+    /// Whether the cache started writing the new entries to disk.
+    pub spilling: bool,
+    /// The number of entries written to disk since the cache started spilling.
+    pub spilled_entries: usize,
+}
+
 enum InnerCaches<'extractor> {
     Normal(NormalCaches<'extractor>),
     Spilling(SpillingCaches<'extractor>),
@@ -113,6 +126,20 @@ impl<'extractor> BalancedCaches<'extractor> {
         }
     }
 
+    /// Creates a cache with one bucket per thread of the current rayon thread pool,
+    /// multiplied by the `over_partition` factor when there is one.
+    ///
+    /// Over-partitioning spreads the keys on more buckets, which reduces the impact of a
+    /// skewed distribution of the keys when the buckets are merged.
+    pub fn new_in_thread_buckets(
+        over_partition: Option<NonZeroUsize>,
+        max_memory: Option<usize>,
+        alloc: &'extractor Bump,
+    ) -> Self {
+        let buckets = rayon::current_num_threads() * over_partition.map_or(1, NonZeroUsize::get);
+        Self::new_in(buckets, max_memory, alloc)
+    }
+
     fn buckets(&self) -> usize {
         match &self.caches {
             InnerCaches::Normal(caches) => caches.caches.len(),
@@ -120,6 +147,26 @@ impl<'extractor> BalancedCaches<'extractor> {
         }
     }
 
+    /// Returns the memory used by the cache, without allocating in the bump allocator.
+    pub fn memory_stats(&self) -> CacheMemoryStats {
+        let (spilling, spilled_entries) = match &self.caches {
+            InnerCaches::Normal(_) => (false, 0),
+            InnerCaches::Spilling(caches) => (true, caches.spilled_count),
+        };
+        let allocated_bytes = self.alloc.allocated_bytes();
+        CacheMemoryStats { allocated_bytes, spilling, spilled_entries }
+    }
+
+    /// Returns the number of in-memory entries of each bucket, the entries
+    /// already spilled to disk are not counted.
+    pub fn bucket_fill(&self) -> Vec<usize> {
+        let caches = match &self.caches {
+            InnerCaches::Normal(caches) => &caches.caches,
+            InnerCaches::Spilling(caches) => &caches.caches,
+        };
+        caches.iter().map(HashMap::len).collect()
+    }
+
     pub fn insert_del_u32(&mut self, key: &[u8], n: u32) -> Result<()> {
         if self.max_memory.map_or(false, |mm| self.alloc.allocated_bytes() >= mm) {
             self.start_spilling()?;
@@ -154,6 +201,68 @@ impl<'extractor> BalancedCaches<'extractor> {
         }
     }
 
+    /// Inserts every integer of the range as deleted, like calling
+    /// [`Self::insert_del_u32`] for each of them.
+    pub fn insert_del_u32_range(&mut self, key: &[u8], range: RangeInclusive<u32>) -> Result<()> {
+        self.insert_u32_range(DelAdd::Deletion, key, range)
+    }
+
+    /// Inserts every integer of the range as added, like calling
+    /// [`Self::insert_add_u32`] for each of them.
+    pub fn insert_add_u32_range(&mut self, key: &[u8], range: RangeInclusive<u32>) -> Result<()> {
+        self.insert_u32_range(DelAdd::Addition, key, range)
+    }
+
+    fn insert_u32_range(
+        &mut self,
+        deladd: DelAdd,
+        key: &[u8],
+        range: RangeInclusive<u32>,
+    ) -> Result<()> {
+        if range.is_empty() {
+            return Ok(());
+        }
+
+        let (mut start, end) = range.into_inner();
+        loop {
+            if self.max_memory.map_or(false, |mm| self.alloc.allocated_bytes() >= mm) {
+                self.start_spilling()?;
+            }
+
+            let buckets = self.buckets();
+            match &mut self.caches {
+                // A range can be large, we insert it by chunks to check
+                // the memory usage as often as the scalar inserts do.
+                InnerCaches::Normal(normal) => {
+                    let chunk_end = start.saturating_add(RANGE_CHUNK_LEN - 1).min(end);
+                    normal.insert_u32_range(
+                        &self.hasher,
+                        self.alloc,
+                        buckets,
+                        deladd,
+                        key,
+                        start..=chunk_end,
+                    );
+                    if chunk_end == end {
+                        return Ok(());
+                    }
+                    start = chunk_end + 1;
+                }
+                // The entries unknown to the cache are directly spilled as a bitmap.
+                InnerCaches::Spilling(spilling) => {
+                    return spilling.insert_u32_range(
+                        &self.hasher,
+                        self.alloc,
+                        buckets,
+                        deladd,
+                        key,
+                        start..=end,
+                    )
+                }
+            }
+        }
+    }
+
     /// Make sure the cache is no longer allocating data
     /// and writes every new and unknow entry to disk.
     fn start_spilling(&mut self) -> Result<()> {
@@ -309,6 +418,36 @@ impl<'extractor> NormalCaches<'extractor> {
             }
         }
     }
+
+    pub fn insert_u32_range(
+        &mut self,
+        hasher: &FxBuildHasher,
+        alloc: &'extractor Bump,
+        buckets: usize,
+        deladd: DelAdd,
+        key: &[u8],
+        range: RangeInclusive<u32>,
+    ) {
+        let hash = hasher.hash_one(key);
+        let bucket = compute_bucket_from_hash(buckets, hash);
+        match self.caches[bucket].raw_entry_mut().from_hash(hash, |&k| k == key) {
+            RawEntryMut::Occupied(mut entry) => {
+                let DelAddBbbul { del, add } = entry.get_mut();
+                let bbbul = match deladd {
+                    DelAdd::Deletion => del,
+                    DelAdd::Addition => add,
+                };
+                insert_u32_range_in(bbbul.get_or_insert_with(|| Bbbul::new_in(alloc)), range);
+            }
+            RawEntryMut::Vacant(entry) => {
+                entry.insert_hashed_nocheck(
+                    hash,
+                    alloc.alloc_slice_copy(key),
+                    DelAddBbbul::new_u32_range_in(deladd, range, alloc),
+                );
+            }
+        }
+    }
 }
 
 struct SpillingCaches<'extractor> {
@@ -321,6 +460,8 @@ struct SpillingCaches<'extractor> {
         >,
     >,
     spilled_entries: Vec<grenad::Sorter<MergeDeladdCboRoaringBitmaps>>,
+    /// The number of entries inserted in the sorters.
+    spilled_count: usize,
     deladd_buffer: Vec<u8>,
     cbo_buffer: Vec<u8>,
 }
@@ -346,6 +487,7 @@ impl<'extractor> SpillingCaches<'extractor> {
             .take(caches.len())
             .collect(),
             caches,
+            spilled_count: 0,
             deladd_buffer: Vec::new(),
             cbo_buffer: Vec::new(),
         }
@@ -366,13 +508,16 @@ impl<'extractor> SpillingCaches<'extractor> {
                 entry.get_mut().del.get_or_insert_with(|| Bbbul::new_in(alloc)).insert(n);
                 Ok(())
             }
-            RawEntryMut::Vacant(_entry) => spill_entry_to_sorter(
-                &mut self.spilled_entries[bucket],
-                &mut self.deladd_buffer,
-                &mut self.cbo_buffer,
-                key,
-                DelAddRoaringBitmap::new_del_u32(n),
-            ),
+            RawEntryMut::Vacant(_entry) => {
+                self.spilled_count += 1;
+                spill_entry_to_sorter(
+                    &mut self.spilled_entries[bucket],
+                    &mut self.deladd_buffer,
+                    &mut self.cbo_buffer,
+                    key,
+                    DelAddRoaringBitmap::new_del_u32(n),
+                )
+            }
         }
     }
 
@@ -391,22 +536,69 @@ impl<'extractor> SpillingCaches<'extractor> {
                 entry.get_mut().add.get_or_insert_with(|| Bbbul::new_in(alloc)).insert(n);
                 Ok(())
             }
-            RawEntryMut::Vacant(_entry) => spill_entry_to_sorter(
-                &mut self.spilled_entries[bucket],
-                &mut self.deladd_buffer,
-                &mut self.cbo_buffer,
-                key,
-                DelAddRoaringBitmap::new_add_u32(n),
-            ),
+            RawEntryMut::Vacant(_entry) => {
+                self.spilled_count += 1;
+                spill_entry_to_sorter(
+                    &mut self.spilled_entries[bucket],
+                    &mut self.deladd_buffer,
+                    &mut self.cbo_buffer,
+                    key,
+                    DelAddRoaringBitmap::new_add_u32(n),
+                )
+            }
+        }
+    }
+
+    pub fn insert_u32_range(
+        &mut self,
+        hasher: &FxBuildHasher,
+        alloc: &'extractor Bump,
+        buckets: usize,
+        deladd: DelAdd,
+        key: &[u8],
+        range: RangeInclusive<u32>,
+    ) -> Result<()> {
+        let hash = hasher.hash_one(key);
+        let bucket = compute_bucket_from_hash(buckets, hash);
+        match self.caches[bucket].raw_entry_mut().from_hash(hash, |&k| k == key) {
+            RawEntryMut::Occupied(mut entry) => {
+                let DelAddBbbul { del, add } = entry.get_mut();
+                let bbbul = match deladd {
+                    DelAdd::Deletion => del,
+                    DelAdd::Addition => add,
+                };
+                insert_u32_range_in(bbbul.get_or_insert_with(|| Bbbul::new_in(alloc)), range);
+                Ok(())
+            }
+            RawEntryMut::Vacant(_entry) => {
+                self.spilled_count += 1;
+                spill_entry_to_sorter(
+                    &mut self.spilled_entries[bucket],
+                    &mut self.deladd_buffer,
+                    &mut self.cbo_buffer,
+                    key,
+                    DelAddRoaringBitmap::new_u32_range(deladd, range),
+                )
+            }
         }
     }
 }
 
+/// The number of integers of a range inserted between two checks of the memory usage.
+const RANGE_CHUNK_LEN: u32 = 1024;
+
 #[inline]
 fn compute_bucket_from_hash(buckets: usize, hash: u64) -> usize {
     hash as usize % buckets
 }
 
+/// Inserts the integers of the range one by one, exactly like the scalar inserts do.
+fn insert_u32_range_in<B: BitPacker>(bbbul: &mut Bbbul<'_, B>, range: RangeInclusive<u32>) {
+    for n in range {
+        bbbul.insert(n);
+    }
+}
+
 fn spill_entry_to_sorter(
     spilled_entries: &mut grenad::Sorter<MergeDeladdCboRoaringBitmaps>,
     deladd_buffer: &mut Vec<u8>,
@@ -464,6 +656,14 @@ pub fn transpose_and_freeze_caches<'a, 'extractor>(
     let mut bucket_caches: Vec<_> = iter::repeat_with(Vec::new).take(width).collect();
 
     for (thread_index, thread_cache) in caches.iter_mut().enumerate() {
+        tracing::trace!(
+            "The buckets of thread #{thread_index} contain {:?} in-memory entries",
+            thread_cache.bucket_fill()
+        );
+        tracing::trace!(
+            "The cache of thread #{thread_index} ends with {:?}",
+            thread_cache.memory_stats()
+        );
         for frozen in thread_cache.freeze(thread_index)? {
             bucket_caches[frozen.bucket_id].push(frozen);
         }
@@ -616,6 +816,15 @@ impl<'bump, B: BitPacker> DelAddBbbul<'bump, B> {
         bbbul.insert(n);
         DelAddBbbul { del: None, add: Some(bbbul) }
     }
+
+    pub fn new_u32_range_in(deladd: DelAdd, range: RangeInclusive<u32>, bump: &'bump Bump) -> Self {
+        let mut bbbul = Bbbul::new_in(bump);
+        insert_u32_range_in(&mut bbbul, range);
+        match deladd {
+            DelAdd::Deletion => DelAddBbbul { del: Some(bbbul), add: None },
+            DelAdd::Addition => DelAddBbbul { del: None, add: Some(bbbul) },
+        }
+    }
 }
 
 pub struct FrozenDelAddBbbul<'bump, B> {
@@ -672,6 +881,15 @@ impl DelAddRoaringBitmap {
         DelAddRoaringBitmap { del: None, add: Some(RoaringBitmap::from([n])) }
     }
 
+    pub fn new_u32_range(deladd: DelAdd, range: RangeInclusive<u32>) -> Self {
+        let mut bitmap = RoaringBitmap::new();
+        bitmap.insert_range(range);
+        match deladd {
+            DelAdd::Deletion => DelAddRoaringBitmap { del: Some(bitmap), add: None },
+            DelAdd::Addition => DelAddRoaringBitmap { del: None, add: Some(bitmap) },
+        }
+    }
+
     pub fn union_and_clear_bbbul<B: BitPacker>(&mut self, bbbul: &mut FrozenDelAddBbbul<'_, B>) {
         let FrozenDelAddBbbul { del, add } = bbbul;
 
@@ -723,3 +941,148 @@ impl DelAddRoaringBitmap {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::num::NonZeroUsize;
+    use std::ops::RangeInclusive;
+
+    use bumpalo::Bump;
+    use roaring::RoaringBitmap;
+
+    use super::{
+        merge_caches_sorted, transpose_and_freeze_caches, BalancedCaches, DelAddRoaringBitmap,
+    };
+
+    type Content = BTreeMap<Vec<u8>, (Option<RoaringBitmap>, Option<RoaringBitmap>)>;
+
+    /// Returns the del and add bitmaps of every key of the cache.
+    fn content(caches: &mut BalancedCaches) -> Content {
+        let mut content = Content::new();
+        for frozen in transpose_and_freeze_caches(std::slice::from_mut(caches)).unwrap() {
+            merge_caches_sorted(frozen, |key, DelAddRoaringBitmap { del, add }| {
+                content.insert(key.to_vec(), (del, add));
+                Ok(())
+            })
+            .unwrap();
+        }
+        content
+    }
+
+    #[test]
+    fn thread_buckets_and_fill() {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+        pool.install(|| {
+            let alloc = Bump::new();
+            let caches = BalancedCaches::new_in_thread_buckets(None, None, &alloc);
+            assert_eq!(caches.bucket_fill(), vec![0; 3]);
+
+            let mut caches =
+                BalancedCaches::new_in_thread_buckets(NonZeroUsize::new(2), None, &alloc);
+            for n in 0..100u32 {
+                caches.insert_add_u32(&n.to_be_bytes(), n).unwrap();
+            }
+            // Inserting in a known key doesn't create a new entry.
+            caches.insert_del_u32(&0u32.to_be_bytes(), 1).unwrap();
+            let fill = caches.bucket_fill();
+            assert_eq!(fill.len(), 6);
+            assert_eq!(fill.iter().sum::<usize>(), 100);
+
+            // The entries spilled to disk are not counted.
+            let mut caches = BalancedCaches::new_in_thread_buckets(None, Some(0), &alloc);
+            for n in 0..100u32 {
+                caches.insert_add_u32(&n.to_be_bytes(), n).unwrap();
+            }
+            assert_eq!(caches.bucket_fill(), vec![0; 3]);
+        });
+    }
+
+    #[test]
+    fn memory_stats() {
+        let alloc = Bump::new();
+        let mut caches = BalancedCaches::new_in(2, None, &alloc);
+        for n in 0..10u32 {
+            caches.insert_add_u32(&n.to_be_bytes(), n).unwrap();
+        }
+        let stats = caches.memory_stats();
+        assert!(!stats.spilling);
+        assert_eq!(stats.spilled_entries, 0);
+        assert_eq!(stats.allocated_bytes, alloc.allocated_bytes());
+
+        // The cache spills as soon as anything is allocated.
+        let alloc = Bump::new();
+        let mut caches = BalancedCaches::new_in(2, Some(0), &alloc);
+        for n in 0..10u32 {
+            caches.insert_add_u32(&n.to_be_bytes(), n).unwrap();
+        }
+        // Inserting the same key twice spills it twice.
+        caches.insert_del_u32(&0u32.to_be_bytes(), 0).unwrap();
+        let allocated_bytes = alloc.allocated_bytes();
+        let stats = caches.memory_stats();
+        assert!(stats.spilling);
+        assert_eq!(stats.spilled_entries, 11);
+        assert_eq!(stats.allocated_bytes, allocated_bytes);
+        assert_eq!(alloc.allocated_bytes(), allocated_bytes);
+    }
+
+    #[test]
+    fn u32_range_matches_scalar_inserts() {
+        let operations: [(&[u8], bool, RangeInclusive<u32>); 5] = [
+            (b"kefir", false, 0..=9),
+            (b"kefir", true, 2..=3),
+            (b"intel", true, 5..=7),
+            (b"intel", false, 3..=4),
+            (b"empty", false, 5..=4),
+        ];
+
+        // Without a memory limit everything stays in memory, with a limit of zero
+        // every new key is spilled to disk.
+        for max_memory in [None, Some(0)] {
+            let scalar_alloc = Bump::new();
+            let mut scalar = BalancedCaches::new_in(2, max_memory, &scalar_alloc);
+            let range_alloc = Bump::new();
+            let mut range = BalancedCaches::new_in(2, max_memory, &range_alloc);
+
+            for (key, deletion, numbers) in operations.iter().cloned() {
+                for n in numbers.clone() {
+                    if deletion {
+                        scalar.insert_del_u32(key, n).unwrap();
+                    } else {
+                        scalar.insert_add_u32(key, n).unwrap();
+                    }
+                }
+                if deletion {
+                    range.insert_del_u32_range(key, numbers).unwrap();
+                } else {
+                    range.insert_add_u32_range(key, numbers).unwrap();
+                }
+            }
+
+            let expected = content(&mut scalar);
+            assert_eq!(expected.len(), 2);
+            assert_eq!(content(&mut range), expected);
+        }
+    }
+
+    #[test]
+    fn large_u32_range_checks_the_memory() {
+        // The memory limit is reached while inserting the range, the cache
+        // must start spilling before inserting the following keys.
+        let alloc = Bump::new();
+        let mut caches = BalancedCaches::new_in(2, Some(1), &alloc);
+        caches.insert_add_u32_range(b"kefir", 0..=100_000).unwrap();
+        assert!(caches.memory_stats().spilling);
+
+        caches.insert_add_u32_range(b"intel", 10..=20).unwrap();
+        assert_eq!(caches.memory_stats().spilled_entries, 1);
+
+        let content = content(&mut caches);
+        let (del, add) = &content[&b"kefir"[..]];
+        assert_eq!(*del, None);
+        assert_eq!(*add, Some(RoaringBitmap::from_iter(0..=100_000)));
+        let (del, add) = &content[&b"intel"[..]];
+        assert_eq!(*del, None);
+        assert_eq!(*add, Some(RoaringBitmap::from_iter(10..=20)));
+    }
+}
@@ -35,13 +35,13 @@ pub struct WordDocidsBalancedCaches<'extractor> {
 unsafe impl<'extractor> MostlySend for WordDocidsBalancedCaches<'extractor> {}
 
 impl<'extractor> WordDocidsBalancedCaches<'extractor> {
-    pub fn new_in(buckets: usize, max_memory: Option<usize>, alloc: &'extractor Bump) -> Self {
+    pub fn new_in(max_memory: Option<usize>, alloc: &'extractor Bump) -> Self {
         Self {
-            word_fid_docids: BalancedCaches::new_in(buckets, max_memory, alloc),
-            word_docids: BalancedCaches::new_in(buckets, max_memory, alloc),
-            exact_word_docids: BalancedCaches::new_in(buckets, max_memory, alloc),
-            word_position_docids: BalancedCaches::new_in(buckets, max_memory, alloc),
-            fid_word_count_docids: BalancedCaches::new_in(buckets, max_memory, alloc),
+            word_fid_docids: BalancedCaches::new_in_thread_buckets(None, max_memory, alloc),
+            word_docids: BalancedCaches::new_in_thread_buckets(None, max_memory, alloc),
+            exact_word_docids: BalancedCaches::new_in_thread_buckets(None, max_memory, alloc),
+            word_position_docids: BalancedCaches::new_in_thread_buckets(None, max_memory, alloc),
+            fid_word_count_docids: BalancedCaches::new_in_thread_buckets(None, max_memory, alloc),
             fid_word_count: HashMap::new(),
             current_docid: None,
         }
@@ -209,7 +209,6 @@ impl<'extractor> WordDocidsCaches<'extractor> {
 pub struct WordDocidsExtractorData<'a> {
     tokenizer: &'a DocumentTokenizer<'a>,
     grenad_parameters: &'a GrenadParameters,
-    buckets: usize,
 }
 
 impl<'a, 'extractor> Extractor<'extractor> for WordDocidsExtractorData<'a> {
@@ -217,7 +216,6 @@ impl<'a, 'extractor> Extractor<'extractor> for WordDocidsExtractorData<'a> {
 
     fn init_data(&self, extractor_alloc: &'extractor Bump) -> Result<Self::Data> {
         Ok(RefCell::new(Some(WordDocidsBalancedCaches::new_in(
-            self.buckets,
             self.grenad_parameters.max_memory_by_thread(),
             extractor_alloc,
         ))))
@@ -288,7 +286,6 @@ impl WordDocidsExtractors {
             let extractor = WordDocidsExtractorData {
                 tokenizer: &document_tokenizer,
                 grenad_parameters: indexing_context.grenad_parameters,
-                buckets: rayon::current_num_threads(),
             };
 
             extract(
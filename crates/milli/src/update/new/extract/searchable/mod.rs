@@ -25,7 +25,6 @@ use crate::{Index, Result, MAX_POSITION_PER_ATTRIBUTE};
 pub struct SearchableExtractorData<'a, EX: SearchableExtractor> {
     tokenizer: &'a DocumentTokenizer<'a>,
     grenad_parameters: &'a GrenadParameters,
-    buckets: usize,
     _ex: PhantomData<EX>,
 }
 
@@ -35,8 +34,8 @@ impl<'a, 'extractor, EX: SearchableExtractor + Sync> Extractor<'extractor>
     type Data = RefCell<BalancedCaches<'extractor>>;
 
     fn init_data(&self, extractor_alloc: &'extractor Bump) -> Result<Self::Data> {
-        Ok(RefCell::new(BalancedCaches::new_in(
-            self.buckets,
+        Ok(RefCell::new(BalancedCaches::new_in_thread_buckets(
+            None,
             self.grenad_parameters.max_memory_by_thread(),
             extractor_alloc,
         )))
@@ -96,7 +95,6 @@ pub trait SearchableExtractor: Sized + Sync {
         let extractor_data: SearchableExtractorData<Self> = SearchableExtractorData {
             tokenizer: &document_tokenizer,
             grenad_parameters: indexing_context.grenad_parameters,
-            buckets: rayon::current_num_threads(),
             _ex: PhantomData,
         };
 
@@ -38,7 +38,16 @@ impl<'pl> DocumentOperation<'pl> {
     pub fn add_documents(&mut self, payload: &'pl Mmap) -> Result<()> {
         #[cfg(unix)]
         payload.advise(memmap2::Advice::Sequential)?;
-        self.operations.push(Payload::Addition(&payload[..]));
+        self.operations.push(Payload::Addition { payload: &payload[..], skip_malformed: false });
+        Ok(())
+    }
+
+    /// Same as [`Self::add_documents`] but the documents with a missing or invalid identifier
+    /// are skipped and reported in the [`PayloadStats`] instead of rejecting the whole payload.
+    pub fn add_documents_skipping_malformed(&mut self, payload: &'pl Mmap) -> Result<()> {
+        #[cfg(unix)]
+        payload.advise(memmap2::Advice::Sequential)?;
+        self.operations.push(Payload::Addition { payload: &payload[..], skip_malformed: true });
         Ok(())
     }
 
@@ -81,8 +90,9 @@ impl<'pl> DocumentOperation<'pl> {
             step.store(payload_index as u32, Ordering::Relaxed);
 
             let mut bytes = 0;
+            let mut skipped_documents = Vec::new();
             let result = match operation {
-                Payload::Addition(payload) => extract_addition_payload_changes(
+                Payload::Addition { payload, skip_malformed } => extract_addition_payload_changes(
                     indexer,
                     index,
                     rtxn,
@@ -94,6 +104,7 @@ impl<'pl> DocumentOperation<'pl> {
                     &docids_version_offsets,
                     method,
                     payload,
+                    skip_malformed.then_some(&mut skipped_documents),
                 ),
                 Payload::Deletion(to_delete) => extract_deletion_payload_changes(
                     index,
@@ -117,7 +128,7 @@ impl<'pl> DocumentOperation<'pl> {
                 Err(Error::UserError(user_error)) => Some(user_error),
                 Err(e) => return Err(e),
             };
-            operations_stats.push(PayloadStats { document_count, bytes, error });
+            operations_stats.push(PayloadStats { document_count, bytes, error, skipped_documents });
         }
         step.store(payload_count as u32, Ordering::Relaxed);
 
@@ -153,12 +164,14 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
     main_docids_version_offsets: &hashbrown::HashMap<&'pl str, PayloadOperations<'pl>>,
     method: MergeMethod,
     payload: &'pl [u8],
+    mut skipped_documents: Option<&mut Vec<SkippedDocument>>,
 ) -> Result<hashbrown::HashMap<&'pl str, PayloadOperations<'pl>>> {
     let mut new_docids_version_offsets = hashbrown::HashMap::<&str, PayloadOperations<'pl>>::new();
 
     let mut previous_offset = 0;
     let mut iter = Deserializer::from_slice(payload).into_iter::<&RawValue>();
-    while let Some(doc) = iter.next().transpose().map_err(InternalError::SerdeJson)? {
+    for document_index in 0.. {
+        let Some(doc) = iter.next().transpose().map_err(InternalError::SerdeJson)? else { break };
         *bytes = previous_offset as u64;
 
         // Only guess the primary key if it is the first document
@@ -187,13 +200,24 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
             primary_key.as_ref().unwrap()
         };
 
-        let external_id = match retrieved_primary_key.extract_fields_and_docid(
-            doc,
-            new_fields_ids_map,
-            indexer,
-        ) {
-            Ok(edi) => edi,
-            Err(e) => return Err(e),
+        let result =
+            retrieved_primary_key.extract_fields_and_docid(doc, new_fields_ids_map, indexer);
+        let external_id = match (result, skipped_documents.as_deref_mut()) {
+            (Ok(edi), _) => edi,
+            (
+                Err(Error::UserError(
+                    error @ (UserError::MissingDocumentId { .. }
+                    | UserError::InvalidDocumentId { .. }
+                    | UserError::TooManyDocumentIds { .. }
+                    | UserError::SerdeJson(_)),
+                )),
+                Some(skipped_documents),
+            ) => {
+                skipped_documents.push(SkippedDocument { index: document_index, error });
+                previous_offset = iter.byte_offset();
+                continue;
+            }
+            (Err(e), _) => return Err(e),
         };
 
         let external_id = external_id.to_de();
@@ -388,7 +412,7 @@ pub struct DocumentOperationChanges<'pl> {
 }
 
 pub enum Payload<'pl> {
-    Addition(&'pl [u8]),
+    Addition { payload: &'pl [u8], skip_malformed: bool },
     Deletion(&'pl [&'pl str]),
 }
 
@@ -396,6 +420,16 @@ pub struct PayloadStats {
     pub bytes: u64,
     pub document_count: u64,
     pub error: Option<UserError>,
+    /// The malformed documents that were skipped instead of rejecting the payload.
+    pub skipped_documents: Vec<SkippedDocument>,
+}
+
+/// A document of a payload that was skipped because it could not be processed.
+#[derive(Debug)]
+pub struct SkippedDocument {
+    /// The position of the document in its payload, starting at zero.
+    pub index: usize,
+    pub error: UserError,
 }
 
 pub struct PayloadOperations<'pl> {
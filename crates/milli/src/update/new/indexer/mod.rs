@@ -5,7 +5,7 @@ use std::thread::{self, Builder};
 use big_s::S;
 use document_changes::{DocumentChanges, IndexingContext};
 pub use document_deletion::DocumentDeletion;
-pub use document_operation::{DocumentOperation, PayloadStats};
+pub use document_operation::{DocumentOperation, PayloadStats, SkippedDocument};
 use hashbrown::HashMap;
 use heed::RwTxn;
 pub use partial_dump::PartialDump;
@@ -18,7 +18,7 @@ use crate::constants::{RESERVED_GEO_FIELD_NAME, RESERVED_VECTORS_FIELD_NAME};
 use crate::criterion::Criterion;
 use crate::error::UserError;
 use crate::index::{
-    IndexEmbeddingConfig, PrefixSearch, DEFAULT_MIN_WORD_LEN_ONE_TYPO,
+    DocumentTtl, IndexEmbeddingConfig, PrefixSearch, DEFAULT_MIN_WORD_LEN_ONE_TYPO,
     DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
 };
 use crate::order_by_map::OrderByMap;
@@ -171,12 +171,16 @@ pub struct Settings<'a, 't, 'i> {
     exact_words: Setting<BTreeSet<String>>,
     /// Attributes on which typo tolerance is disabled.
     exact_attributes: Setting<HashSet<String>>,
+    /// Attributes on which the last query word is not matched as a prefix.
+    prefix_disabled_attributes: Setting<HashSet<String>>,
     max_values_per_facet: Setting<usize>,
     sort_facet_values_by: Setting<OrderByMap>,
     pagination_max_total_hits: Setting<usize>,
     proximity_precision: Setting<ProximityPrecision>,
     embedder_settings: Setting<BTreeMap<String, Setting<EmbeddingSettings>>>,
     search_cutoff: Setting<u64>,
+    /// The field and duration after which the documents are deleted.
+    document_ttl: Setting<DocumentTtl>,
     localized_attributes_rules: Setting<Vec<LocalizedAttributesRule>>,
     prefix_search: Setting<PrefixSearch>,
     facet_search: Setting<bool>,
@@ -208,12 +212,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             min_word_len_two_typos: Setting::NotSet,
             min_word_len_one_typo: Setting::NotSet,
             exact_attributes: Setting::NotSet,
+            prefix_disabled_attributes: Setting::NotSet,
             max_values_per_facet: Setting::NotSet,
             sort_facet_values_by: Setting::NotSet,
             pagination_max_total_hits: Setting::NotSet,
             proximity_precision: Setting::NotSet,
             embedder_settings: Setting::NotSet,
             search_cutoff: Setting::NotSet,
+            document_ttl: Setting::NotSet,
             localized_attributes_rules: Setting::NotSet,
             prefix_search: Setting::NotSet,
             facet_search: Setting::NotSet,
@@ -367,6 +373,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.exact_attributes = Setting::Reset;
     }
 
+    pub fn set_prefix_disabled_attributes(&mut self, attrs: HashSet<String>) {
+        self.prefix_disabled_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_prefix_disabled_attributes(&mut self) {
+        self.prefix_disabled_attributes = Setting::Reset;
+    }
+
     pub fn set_max_values_per_facet(&mut self, value: usize) {
         self.max_values_per_facet = Setting::Set(value);
     }
@@ -415,6 +429,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.search_cutoff = Setting::Reset;
     }
 
+    pub fn set_document_ttl(&mut self, value: DocumentTtl) {
+        self.document_ttl = Setting::Set(value);
+    }
+
+    pub fn reset_document_ttl(&mut self) {
+        self.document_ttl = Setting::Reset;
+    }
+
     pub fn set_localized_attributes_rules(&mut self, value: Vec<LocalizedAttributesRule>) {
         self.localized_attributes_rules = Setting::Set(value);
     }
@@ -757,6 +779,20 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
+    fn update_prefix_disabled_attributes(&mut self) -> Result<()> {
+        match self.prefix_disabled_attributes {
+            Setting::Set(ref attrs) => {
+                let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                self.index.put_prefix_disabled_attributes(self.wtxn, &attrs)?;
+            }
+            Setting::Reset => {
+                self.index.delete_prefix_disabled_attributes(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
     fn update_filterable(&mut self) -> Result<()> {
         match self.filterable_fields {
             Setting::Set(ref fields) => {
@@ -1210,6 +1246,39 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(changed)
     }
 
+    /// The expired documents are found in the numeric facet database,
+    /// so the time-to-live field must stay filterable or sortable.
+    fn validate_document_ttl(&mut self) -> Result<()> {
+        let Some(DocumentTtl { field, .. }) = self.index.document_ttl(self.wtxn)? else {
+            return Ok(());
+        };
+        if self.index.filterable_fields(self.wtxn)?.contains(&field)
+            || self.index.sortable_fields(self.wtxn)?.contains(&field)
+        {
+            Ok(())
+        } else {
+            Err(UserError::InvalidDocumentTtlField { field }.into())
+        }
+    }
+
+    fn update_document_ttl(&mut self) -> Result<bool> {
+        let changed = match self.document_ttl {
+            Setting::Set(ref new) => {
+                let old = self.index.document_ttl(self.wtxn)?;
+                if old.as_ref() == Some(new) {
+                    false
+                } else {
+                    self.index.put_document_ttl(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_document_ttl(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
     fn update_localized_attributes_rules(&mut self) -> Result<()> {
         match &self.localized_attributes_rules {
             Setting::Set(new) => {
@@ -1244,10 +1313,12 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_authorize_typos()?;
         self.update_min_typo_word_len()?;
         self.update_exact_words()?;
+        self.update_prefix_disabled_attributes()?;
         self.update_max_values_per_facet()?;
         self.update_sort_facet_values_by()?;
         self.update_pagination_max_total_hits()?;
         self.update_search_cutoff()?;
+        self.update_document_ttl()?;
 
         // could trigger re-indexing
         self.update_filterable()?;
@@ -1263,6 +1334,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_prefix_search()?;
         self.update_facet_search()?;
         self.update_localized_attributes_rules()?;
+        self.validate_document_ttl()?;
 
         let embedding_config_updates = self.update_embedding_configs()?;
 
@@ -2788,12 +2860,14 @@ mod tests {
                     min_word_len_one_typo,
                     exact_words,
                     exact_attributes,
+                    prefix_disabled_attributes,
                     max_values_per_facet,
                     sort_facet_values_by,
                     pagination_max_total_hits,
                     proximity_precision,
                     embedder_settings,
                     search_cutoff,
+                    document_ttl,
                     localized_attributes_rules,
                     prefix_search,
                     facet_search,
@@ -2815,12 +2889,14 @@ mod tests {
                 assert!(matches!(min_word_len_one_typo, Setting::NotSet));
                 assert!(matches!(exact_words, Setting::NotSet));
                 assert!(matches!(exact_attributes, Setting::NotSet));
+                assert!(matches!(prefix_disabled_attributes, Setting::NotSet));
                 assert!(matches!(max_values_per_facet, Setting::NotSet));
                 assert!(matches!(sort_facet_values_by, Setting::NotSet));
                 assert!(matches!(pagination_max_total_hits, Setting::NotSet));
                 assert!(matches!(proximity_precision, Setting::NotSet));
                 assert!(matches!(embedder_settings, Setting::NotSet));
                 assert!(matches!(search_cutoff, Setting::NotSet));
+                assert!(matches!(document_ttl, Setting::NotSet));
                 assert!(matches!(localized_attributes_rules, Setting::NotSet));
                 assert!(matches!(prefix_search, Setting::NotSet));
                 assert!(matches!(facet_search, Setting::NotSet));
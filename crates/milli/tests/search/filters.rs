@@ -99,6 +99,9 @@ test_filter!(empty_filter_1_not, vec![Right("opt1 IS NOT EMPTY")]);
 test_filter!(empty_filter_1_not_alt, vec![Right("NOT opt1 IS EMPTY")]);
 test_filter!(empty_filter_1_double_not, vec![Right("NOT opt1 IS NOT EMPTY")]);
 
+test_filter!(not_empty_filter_1, vec![Right("opt1 NOT EMPTY")]);
+test_filter!(not_empty_filter_1_not, vec![Right("NOT opt1 NOT EMPTY")]);
+
 test_filter!(in_filter, vec![Right("tag_in IN[1, 2, 3, four, five]")]);
 test_filter!(not_in_filter, vec![Right("tag_in NOT IN[1, 2, 3, four, five]")]);
 test_filter!(not_not_in_filter, vec![Right("NOT tag_in NOT IN[1, 2, 3, four, five]")]);
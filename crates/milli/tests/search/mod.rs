@@ -193,7 +193,11 @@ pub fn expected_order(
 
     match optional_words {
         TermsMatchingStrategy::Last => groups.into_iter().flatten().collect(),
+        TermsMatchingStrategy::First => groups.into_iter().flatten().collect(),
+        TermsMatchingStrategy::Size => groups.into_iter().flatten().collect(),
         TermsMatchingStrategy::Frequency => groups.into_iter().flatten().collect(),
+        TermsMatchingStrategy::FrequencyWeighted => groups.into_iter().flatten().collect(),
+        TermsMatchingStrategy::RequireRare { .. } => groups.into_iter().flatten().collect(),
         TermsMatchingStrategy::All => {
             groups.into_iter().flatten().filter(|d| d.word_rank == 0).collect()
         }
@@ -258,6 +262,18 @@ fn execute_filter(filter: &str, document: &TestDocument) -> Option<String> {
             .as_ref()
             .map_or(true, |v| !is_empty_value(v))
             .then(|| document.id.clone());
+    } else if matches!(filter, "opt1 NOT EMPTY") {
+        id = document
+            .opt1
+            .as_ref()
+            .map_or(false, |v| !v.is_null() && !is_empty_value(v))
+            .then(|| document.id.clone());
+    } else if matches!(filter, "NOT opt1 NOT EMPTY") {
+        id = document
+            .opt1
+            .as_ref()
+            .map_or(true, |v| v.is_null() || is_empty_value(v))
+            .then(|| document.id.clone());
     } else if matches!(filter, "opt1.opt2 IS EMPTY") {
         if document.opt1opt2.as_ref().map_or(false, is_empty_value) {
             id = Some(document.id.clone());
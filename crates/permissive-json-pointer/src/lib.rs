@@ -244,6 +244,73 @@ fn create_array(array: &[Value], selectors: &HashSet<&str>) -> Vec<Value> {
     res
 }
 
+/// Permissively removes values from a json with a list of selectors.
+/// It is the opposite of [`select_values`] and modifies the json in place.
+/// ```
+/// use serde_json::*;
+/// use permissive_json_pointer::remove_values;
+///
+/// let mut value: Value = json!({
+///     "name": "peanut",
+///     "age": 8,
+///     "race": {
+///         "name": "bernese mountain",
+///         "avg_age": 12,
+///     },
+/// });
+/// let map: &mut Map<String, Value> = value.as_object_mut().unwrap();
+///
+/// remove_values(map, vec!["age", "race.avg_age"]);
+/// assert_eq!(
+///     value,
+///     json!({
+///         "name": "peanut",
+///         "race": {
+///             "name": "bernese mountain",
+///         },
+///     })
+/// );
+/// ```
+pub fn remove_values<'a>(
+    value: &mut Map<String, Value>,
+    selectors: impl IntoIterator<Item = &'a str>,
+) {
+    let selectors = selectors.into_iter().collect();
+    remove_from_value(value, &selectors);
+}
+
+fn remove_from_value(value: &mut Document, selectors: &HashSet<&str>) {
+    value.retain(|key, _| !selectors.contains(key as &str));
+
+    for (key, value) in value.iter_mut() {
+        // same as in `create_value`, if there was [person.name, person.age] and
+        // we are on the field `person` we generate the sub selectors: [name, age].
+        let sub_selectors: HashSet<&str> = selectors
+            .iter()
+            .filter(|s| contained_in(s, key))
+            .filter_map(|s| s.trim_start_matches(key.as_str()).get(SPLIT_SYMBOL.len_utf8()..))
+            .collect();
+
+        if !sub_selectors.is_empty() {
+            match value {
+                Value::Array(array) => remove_from_array(array, &sub_selectors),
+                Value::Object(object) => remove_from_value(object, &sub_selectors),
+                _ => (),
+            }
+        }
+    }
+}
+
+fn remove_from_array(array: &mut [Value], selectors: &HashSet<&str>) {
+    for value in array {
+        match value {
+            Value::Array(array) => remove_from_array(array, selectors),
+            Value::Object(object) => remove_from_value(object, selectors),
+            _ => (),
+        }
+    }
+}
+
 fn is_simple(key: impl AsRef<str>) -> bool {
     !key.as_ref().contains(SPLIT_SYMBOL)
 }
@@ -655,6 +722,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remove_nested_values() {
+        let mut value: Value = json!({
+            "title": "Hello",
+            "author": {
+                "name": "Jane",
+                "email": "jane@example.com",
+            },
+            "author.email": "flattened@example.com",
+            "comments": [
+                { "author": { "name": "John", "email": "john@example.com" } },
+                { "text": "no author" },
+                "plain",
+            ],
+        });
+
+        remove_values(
+            value.as_object_mut().unwrap(),
+            vec!["author.email", "comments.author.email", "missing.field"],
+        );
+        assert_eq!(
+            value,
+            json!({
+                "title": "Hello",
+                "author": {
+                    "name": "Jane",
+                },
+                "comments": [
+                    { "author": { "name": "John" } },
+                    { "text": "no author" },
+                    "plain",
+                ],
+            })
+        );
+    }
+
     #[test]
     fn empty_array_object_return_empty() {
         let value: Value = json!({
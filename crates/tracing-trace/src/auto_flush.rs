@@ -0,0 +1,115 @@
+use std::io::Write;
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// A writer that is flushed by a background thread on a fixed interval.
+///
+/// The thread is stopped, and the writer flushed one last time, when it is dropped.
+pub struct AutoFlush<W: Write + Send + 'static> {
+    writer: Arc<Mutex<W>>,
+    /// Disconnecting this channel stops the flushing thread.
+    stop: Option<mpsc::Sender<()>>,
+    flusher: Option<JoinHandle<()>>,
+}
+
+impl<W: Write + Send + 'static> AutoFlush<W> {
+    pub fn new(writer: W, interval: Duration) -> Self {
+        let writer = Arc::new(Mutex::new(writer));
+        let (stop, stopped) = mpsc::channel::<()>();
+
+        let flusher = std::thread::Builder::new()
+            .name(String::from("trace-flusher"))
+            .spawn({
+                let writer = writer.clone();
+                move || {
+                    while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval)
+                    {
+                        // there is no one to report the error to, the next flush will retry
+                        let _ = lock(&writer).flush();
+                    }
+                }
+            })
+            .expect("failed to spawn the trace flushing thread");
+
+        Self { writer, stop: Some(stop), flusher: Some(flusher) }
+    }
+}
+
+impl<W: Write + Send + 'static> Write for AutoFlush<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        lock(&self.writer).write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        lock(&self.writer).write_all(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        lock(&self.writer).flush()
+    }
+}
+
+impl<W: Write + Send + 'static> Drop for AutoFlush<W> {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(flusher) = self.flusher.take() {
+            let _ = flusher.join();
+        }
+        let _ = self.flush();
+    }
+}
+
+/// A panic while writing leaves the writer as usable as an I/O error would.
+fn lock<W>(writer: &Mutex<W>) -> MutexGuard<'_, W> {
+    writer.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufWriter;
+    use std::time::Instant;
+
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+    use crate::{TraceReader, TraceWriter};
+
+    /// A writer whose content can be read while it is owned by the trace writer.
+    #[derive(Clone, Default)]
+    struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            lock(&self.0).write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn flushed_without_an_explicit_flush() {
+        let output = SharedOutput::default();
+        let (writer, layer) = TraceWriter::new(BufWriter::new(output.clone()), false);
+        let mut writer = writer.with_auto_flush(Duration::from_millis(10));
+        tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+            let _span = tracing::info_span!("span").entered();
+        });
+        while writer.try_receive().unwrap().is_continue() {}
+
+        // The entries stay in the buffer of the `BufWriter` until the flushing thread runs.
+        let start = Instant::now();
+        loop {
+            let written = lock(&output.0).clone();
+            let entries = TraceReader::new(written.as_slice()).filter(Result::is_ok).count();
+            // the callsite, the thread, and the creation, enter, exit and close of the span
+            if entries == 6 {
+                break;
+            }
+            assert!(start.elapsed() < Duration::from_secs(10), "the trace was not flushed");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
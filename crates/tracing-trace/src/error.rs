@@ -1,6 +1,12 @@
 #[derive(Debug)]
 pub enum Error {
     Json(serde_json::Error),
+    Bincode(bincode::Error),
+    Io(std::io::Error),
+    /// The trace starts like a header but is not one.
+    InvalidHeader,
+    /// The header describes a format unknown to this version.
+    UnknownFormat(u8),
 }
 
 impl std::error::Error for Error {}
@@ -10,6 +16,10 @@ impl std::fmt::Display for Error {
         f.write_str("error de/serializing trace entry:")?;
         match self {
             Error::Json(error) => std::fmt::Display::fmt(&error, f),
+            Error::Bincode(error) => std::fmt::Display::fmt(&error, f),
+            Error::Io(error) => std::fmt::Display::fmt(&error, f),
+            Error::InvalidHeader => f.write_str("invalid trace header"),
+            Error::UnknownFormat(byte) => write!(f, "unknown trace format `{byte}`"),
         }
     }
 }
@@ -19,3 +29,15 @@ impl From<serde_json::Error> for Error {
         Self::Json(value)
     }
 }
+
+impl From<bincode::Error> for Error {
+    fn from(value: bincode::Error) -> Self {
+        Self::Bincode(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
@@ -1,19 +1,24 @@
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::RwLock;
+use std::time::Duration;
 
 use tracing::span::{Attributes, Id as TracingId};
 use tracing::{Metadata, Subscriber};
 use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::Layer;
 
 use crate::entry::{
     Entry, Event, MemoryStats, NewCallsite, NewSpan, NewThread, ResourceId, SpanClose, SpanEnter,
     SpanExit, SpanId,
 };
-use crate::{Error, Trace, TraceWriter};
+use crate::{AutoFlush, Error, Trace, TraceFormat, TraceWriter};
 
 /// Layer that measures the time spent in spans.
 pub struct TraceLayer {
@@ -21,10 +26,20 @@ pub struct TraceLayer {
     callsites: RwLock<HashMap<OpaqueIdentifier, ResourceId>>,
     start_time: std::time::Instant,
     profile_memory: bool,
+    filter: Option<SpanFilter>,
 }
 
 impl Trace {
     pub fn new(profile_memory: bool) -> (Self, TraceLayer) {
+        Self::new_sampled(profile_memory, 1.0)
+    }
+
+    /// Only records a `ratio` of the spans, between `0.0` and `1.0`.
+    ///
+    /// The decision is made once per span when it is created, so the enters, exits and close
+    /// of a dropped span are never recorded. The kept spans are attached to their closest kept
+    /// ancestor.
+    pub fn new_sampled(profile_memory: bool, ratio: f64) -> (Self, TraceLayer) {
         let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
         let trace = Trace { receiver };
         let layer = TraceLayer {
@@ -32,6 +47,7 @@ impl Trace {
             callsites: Default::default(),
             start_time: std::time::Instant::now(),
             profile_memory,
+            filter: SpanFilter::sampled(ratio),
         };
         (trace, layer)
     }
@@ -43,6 +59,22 @@ impl<W: Write> TraceWriter<W> {
         (trace.into_writer(writer), layer)
     }
 
+    /// See [`Trace::new_sampled`].
+    pub fn new_sampled(writer: W, profile_memory: bool, ratio: f64) -> (Self, TraceLayer) {
+        let (trace, layer) = Trace::new_sampled(profile_memory, ratio);
+        (trace.into_writer(writer), layer)
+    }
+
+    /// Writes the entries in the given format, preceded by a header describing it.
+    pub fn with_format(
+        writer: W,
+        format: TraceFormat,
+        profile_memory: bool,
+    ) -> Result<(Self, TraceLayer), Error> {
+        let (trace, layer) = Trace::new(profile_memory);
+        Ok((trace.into_writer_with_format(writer, format)?, layer))
+    }
+
     pub async fn receive(&mut self) -> Result<ControlFlow<(), ()>, Error> {
         let Some(entry) = self.receiver.recv().await else {
             return Ok(ControlFlow::Break(()));
@@ -61,7 +93,10 @@ impl<W: Write> TraceWriter<W> {
     }
 
     pub fn write(&mut self, entry: Entry) -> Result<(), Error> {
-        Ok(serde_json::ser::to_writer(&mut self.writer, &entry)?)
+        match self.format {
+            TraceFormat::Json => Ok(serde_json::ser::to_writer(&mut self.writer, &entry)?),
+            TraceFormat::Bincode => Ok(bincode::serialize_into(&mut self.writer, &entry)?),
+        }
     }
 
     pub fn try_receive(&mut self) -> Result<ControlFlow<(), ()>, Error> {
@@ -77,6 +112,144 @@ impl<W: Write> TraceWriter<W> {
     }
 }
 
+impl<W: Write + Send + 'static> TraceWriter<W> {
+    /// Flushes the writer every `interval` from a background thread, so that the end of the
+    /// trace is not lost when the process is killed.
+    ///
+    /// The thread is stopped when the returned writer is dropped.
+    pub fn with_auto_flush(self, interval: Duration) -> TraceWriter<AutoFlush<W>> {
+        let TraceWriter { writer, format, receiver } = self;
+        TraceWriter { writer: AutoFlush::new(writer, interval), format, receiver }
+    }
+}
+
+/// The targets recorded by a [`TraceLayer`].
+///
+/// A target matches an entry if it is equal to it or is one of its submodules,
+/// e.g. `milli::update` matches `milli::update::index_documents`.
+#[derive(Debug, Clone, Default)]
+pub struct TargetFilter {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl TargetFilter {
+    /// Only records the given targets, all the targets are recorded when none is allowed.
+    pub fn allow<I: IntoIterator<Item = S>, S: Into<String>>(mut self, targets: I) -> Self {
+        self.allow.extend(targets.into_iter().map(Into::into));
+        self
+    }
+
+    /// Never records the given targets, even when they are allowed.
+    pub fn deny<I: IntoIterator<Item = S>, S: Into<String>>(mut self, targets: I) -> Self {
+        self.deny.extend(targets.into_iter().map(Into::into));
+        self
+    }
+
+    fn records(&self, target: &str) -> bool {
+        let matches = |prefix: &String| match target.strip_prefix(prefix.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with("::"),
+            None => false,
+        };
+        (self.allow.is_empty() || self.allow.iter().any(matches)) && !self.deny.iter().any(matches)
+    }
+}
+
+/// Gives a different id to the filter of every layer, several layers can record the same spans.
+static NEXT_FILTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Decides which spans are recorded by a sampled or target-filtered [`TraceLayer`].
+struct SpanFilter {
+    /// Identifies the decisions of this filter among the ones stored in the span extensions.
+    id: usize,
+    /// The spans whose hash is below this threshold are kept, all of them when `None`.
+    threshold: Option<u64>,
+    targets: Option<TargetFilter>,
+    /// Whether the target of a callsite is recorded, to only check it once per callsite.
+    callsites: RwLock<HashMap<tracing::callsite::Identifier, bool>>,
+}
+
+/// The decisions made for a span by the filter of each layer, stored in the extensions of the span.
+struct SampledSpans(Vec<(usize, SampledSpan)>);
+
+#[derive(Clone, Copy)]
+enum SampledSpan {
+    Kept,
+    /// The children of a dropped span are attached to its closest kept ancestor instead.
+    Dropped {
+        kept_ancestor: Option<SpanId>,
+    },
+}
+
+impl Default for SpanFilter {
+    fn default() -> Self {
+        SpanFilter {
+            id: NEXT_FILTER_ID.fetch_add(1, Ordering::Relaxed),
+            threshold: None,
+            targets: None,
+            callsites: Default::default(),
+        }
+    }
+}
+
+impl SpanFilter {
+    /// Returns `None` when every span must be kept.
+    fn sampled(ratio: f64) -> Option<Self> {
+        if ratio >= 1.0 {
+            return None;
+        }
+        let threshold = (ratio.max(0.0) * u64::MAX as f64) as u64;
+        Some(SpanFilter { threshold: Some(threshold), ..Default::default() })
+    }
+
+    /// Whether the spans and events of this callsite can be recorded, according to its target.
+    fn records_callsite(&self, metadata: &'static Metadata<'static>) -> bool {
+        let Some(targets) = &self.targets else { return true };
+        let callsite = metadata.callsite();
+        if let Some(recorded) = self.callsites.read().unwrap().get(&callsite) {
+            return *recorded;
+        }
+        let recorded = targets.records(metadata.target());
+        self.callsites.write().unwrap().insert(callsite, recorded);
+        recorded
+    }
+
+    /// Deterministically decides whether to keep a span from its id and callsite.
+    fn keeps(&self, id: &TracingId, metadata: &'static Metadata<'static>) -> bool {
+        if !self.records_callsite(metadata) {
+            return false;
+        }
+        let Some(threshold) = self.threshold else { return true };
+        let mut hasher = DefaultHasher::new();
+        metadata.callsite().hash(&mut hasher);
+        id.hash(&mut hasher);
+        hasher.finish() < threshold
+    }
+
+    /// Stores the decision with the span, no lock shared by all the spans is taken.
+    fn store_decision<S>(&self, id: &TracingId, sampled: SampledSpan, ctx: &Context<'_, S>)
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        match extensions.get_mut::<SampledSpans>() {
+            Some(SampledSpans(decisions)) => decisions.push((self.id, sampled)),
+            None => extensions.insert(SampledSpans(vec![(self.id, sampled)])),
+        }
+    }
+
+    fn decision<S>(&self, id: &TracingId, ctx: &Context<'_, S>) -> Option<SampledSpan>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let span = ctx.span(id)?;
+        let extensions = span.extensions();
+        let SampledSpans(decisions) = extensions.get::<SampledSpans>()?;
+        decisions.iter().find(|(filter, _)| *filter == self.id).map(|(_, sampled)| *sampled)
+    }
+}
+
 #[derive(PartialEq, Eq, Hash)]
 enum OpaqueIdentifier {
     Thread(std::thread::ThreadId),
@@ -84,6 +257,14 @@ enum OpaqueIdentifier {
 }
 
 impl TraceLayer {
+    /// Only records the spans and events of the targets accepted by the filter.
+    ///
+    /// The children of a filtered out span are attached to its closest recorded ancestor.
+    pub fn with_targets(mut self, targets: TargetFilter) -> Self {
+        self.filter.get_or_insert_with(Default::default).targets = Some(targets);
+        self
+    }
+
     fn resource_id(&self, opaque: OpaqueIdentifier) -> Option<ResourceId> {
         self.callsites.read().unwrap().get(&opaque).copied()
     }
@@ -138,13 +319,52 @@ impl TraceLayer {
         self.send(Entry::NewThread(NewThread { thread_id, name }));
         thread_id
     }
+
+    /// Returns the span that must be recorded as the parent of a new span or event,
+    /// its closest kept ancestor when it has been dropped.
+    fn recorded_parent<S>(&self, parent: Option<TracingId>, ctx: &Context<'_, S>) -> Option<SpanId>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let parent = parent?;
+        let Some(filter) = &self.filter else { return Some(SpanId::from(&parent)) };
+        match filter.decision(&parent, ctx)? {
+            SampledSpan::Kept => Some(SpanId::from(&parent)),
+            SampledSpan::Dropped { kept_ancestor } => *kept_ancestor,
+        }
+    }
+
+    /// Returns `true` if the events of this span must be recorded.
+    fn is_recorded<S>(&self, id: &TracingId, ctx: &Context<'_, S>) -> bool
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        self.filter
+            .as_ref()
+            .map_or(true, |filter| matches!(filter.decision(id, ctx), Some(SampledSpan::Kept)))
+    }
 }
 
 impl<S> Layer<S> for TraceLayer
 where
-    S: Subscriber,
+    S: Subscriber + for<'a> LookupSpan<'a>,
 {
-    fn on_new_span(&self, attrs: &Attributes<'_>, id: &TracingId, _ctx: Context<'_, S>) {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &TracingId, ctx: Context<'_, S>) {
+        let parent = attrs.parent().cloned().or_else(|| tracing::Span::current().id());
+        let parent_id = self.recorded_parent(parent, &ctx);
+
+        if let Some(filter) = &self.filter {
+            let sampled = if filter.keeps(id, attrs.metadata()) {
+                SampledSpan::Kept
+            } else {
+                SampledSpan::Dropped { kept_ancestor: parent_id }
+            };
+            filter.store_decision(id, sampled, &ctx);
+            if let SampledSpan::Dropped { .. } = sampled {
+                return;
+            }
+        }
+
         let call_id = self
             .resource_id(OpaqueIdentifier::Call(attrs.metadata().callsite()))
             .unwrap_or_else(|| self.register_callsite(attrs.metadata()));
@@ -153,16 +373,14 @@ where
             .resource_id(OpaqueIdentifier::Thread(std::thread::current().id()))
             .unwrap_or_else(|| self.register_thread());
 
-        let parent_id = attrs
-            .parent()
-            .cloned()
-            .or_else(|| tracing::Span::current().id())
-            .map(|id| SpanId::from(&id));
-
         self.send(Entry::NewSpan(NewSpan { id: id.into(), call_id, parent_id, thread_id }));
     }
 
-    fn on_enter(&self, id: &TracingId, _ctx: Context<'_, S>) {
+    fn on_enter(&self, id: &TracingId, ctx: Context<'_, S>) {
+        if !self.is_recorded(id, &ctx) {
+            return;
+        }
+
         self.send(Entry::SpanEnter(SpanEnter {
             id: id.into(),
             time: self.elapsed(),
@@ -170,7 +388,11 @@ where
         }))
     }
 
-    fn on_exit(&self, id: &TracingId, _ctx: Context<'_, S>) {
+    fn on_exit(&self, id: &TracingId, ctx: Context<'_, S>) {
+        if !self.is_recorded(id, &ctx) {
+            return;
+        }
+
         self.send(Entry::SpanExit(SpanExit {
             id: id.into(),
             time: self.elapsed(),
@@ -178,7 +400,13 @@ where
         }))
     }
 
-    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        if let Some(filter) = &self.filter {
+            if !filter.records_callsite(event.metadata()) {
+                return;
+            }
+        }
+
         let call_id = self
             .resource_id(OpaqueIdentifier::Call(event.metadata().callsite()))
             .unwrap_or_else(|| self.register_callsite(event.metadata()));
@@ -187,11 +415,8 @@ where
             .resource_id(OpaqueIdentifier::Thread(std::thread::current().id()))
             .unwrap_or_else(|| self.register_thread());
 
-        let parent_id = event
-            .parent()
-            .cloned()
-            .or_else(|| tracing::Span::current().id())
-            .map(|id| SpanId::from(&id));
+        let parent = event.parent().cloned().or_else(|| tracing::Span::current().id());
+        let parent_id = self.recorded_parent(parent, &ctx);
 
         self.send(Entry::Event(Event {
             call_id,
@@ -202,7 +427,171 @@ where
         }))
     }
 
-    fn on_close(&self, id: TracingId, _ctx: Context<'_, S>) {
+    fn on_close(&self, id: TracingId, ctx: Context<'_, S>) {
+        if !self.is_recorded(&id, &ctx) {
+            return;
+        }
+
         self.send(Entry::SpanClose(SpanClose { id: Into::into(&id), time: self.elapsed() }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+
+    /// Runs `f` with the layer as the default subscriber and returns the recorded entries.
+    fn record(trace: Trace, layer: TraceLayer, f: impl FnOnce()) -> Vec<Entry> {
+        tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), f);
+        received(trace)
+    }
+
+    fn received(trace: Trace) -> Vec<Entry> {
+        let mut receiver = trace.into_receiver();
+        let mut entries = Vec::new();
+        while let Ok(entry) = receiver.try_recv() {
+            entries.push(entry);
+        }
+        entries
+    }
+
+    /// Opens 100 spans at the same time, each of them with three short-lived children.
+    fn spans() {
+        let outers: Vec<_> = (0..100).map(|i| tracing::info_span!("outer", i)).collect();
+        for outer in &outers {
+            let _outer = outer.enter();
+            for _ in 0..3 {
+                let _inner = tracing::info_span!("inner").entered();
+                tracing::info!("event");
+            }
+        }
+    }
+
+    /// Checks that every recorded span is entered, exited and closed once it has been
+    /// created and that the parents are recorded spans, returns the number of spans.
+    fn check_balanced(entries: &[Entry]) -> usize {
+        let mut open = HashSet::new();
+        let mut depths: HashMap<SpanId, usize> = HashMap::new();
+        let mut count = 0;
+        for entry in entries {
+            match entry {
+                Entry::NewSpan(span) => {
+                    if let Some(parent) = span.parent_id {
+                        assert!(open.contains(&parent));
+                    }
+                    assert!(open.insert(span.id));
+                    count += 1;
+                }
+                Entry::SpanEnter(enter) => {
+                    assert!(open.contains(&enter.id));
+                    *depths.entry(enter.id).or_default() += 1;
+                }
+                Entry::SpanExit(exit) => {
+                    let depth = depths.get_mut(&exit.id).expect("exit without an enter");
+                    *depth = depth.checked_sub(1).expect("more exits than enters");
+                }
+                Entry::SpanClose(close) => {
+                    assert!(open.remove(&close.id));
+                    assert_eq!(depths.remove(&close.id).unwrap_or(0), 0);
+                }
+                Entry::Event(event) => {
+                    if let Some(parent) = event.parent_id {
+                        assert!(open.contains(&parent));
+                    }
+                }
+                Entry::NewCallsite(_) | Entry::NewThread(_) => (),
+            }
+        }
+        assert!(open.is_empty());
+        count
+    }
+
+    fn count_events(entries: &[Entry]) -> usize {
+        entries.iter().filter(|entry| matches!(entry, Entry::Event(_))).count()
+    }
+
+    #[test]
+    fn sampled_spans_stay_balanced() {
+        let (trace, layer) = Trace::new(false);
+        let entries = record(trace, layer, spans);
+        assert_eq!(check_balanced(&entries), 400);
+        assert_eq!(count_events(&entries), 300);
+
+        let (trace, layer) = Trace::new_sampled(false, 0.5);
+        let entries = record(trace, layer, spans);
+        let count = check_balanced(&entries);
+        assert!(count > 0 && count < 400, "{count} spans were kept");
+        // the events are not sampled, they are attached to the closest kept span
+        assert_eq!(count_events(&entries), 300);
+
+        let (trace, layer) = Trace::new_sampled(false, 0.0);
+        let entries = record(trace, layer, spans);
+        assert_eq!(check_balanced(&entries), 0);
+        assert_eq!(count_events(&entries), 300);
+    }
+
+    #[test]
+    fn filtered_targets_are_not_recorded() {
+        let (trace, layer) = Trace::new(false);
+        let targets = TargetFilter::default().allow(["milli"]).deny(["milli::update"]);
+        let entries = record(trace, layer.with_targets(targets), || {
+            let _search = tracing::info_span!(target: "milli::search", "search").entered();
+            let _update =
+                tracing::info_span!(target: "milli::update::index_documents", "update").entered();
+            let _query = tracing::info_span!(target: "milli::search::query", "query").entered();
+            tracing::info!(target: "milli::update", "filtered");
+            tracing::info!(target: "meilisearch", "not allowed");
+            tracing::info!(target: "milli::updates", "not a submodule");
+        });
+        assert_eq!(check_balanced(&entries), 2);
+
+        let targets: Vec<_> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::NewCallsite(callsite) => Some(callsite.target.as_ref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(targets, ["milli::search", "milli::search::query", "milli::updates"]);
+
+        // the span of the filtered target is skipped, its child is attached to the search span
+        let spans: Vec<_> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::NewSpan(span) => Some(span),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(spans[1].parent_id, Some(spans[0].id));
+        let events: Vec<_> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Event(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].parent_id, Some(spans[1].id));
+    }
+
+    #[test]
+    fn layers_keep_their_own_decisions() {
+        // Two layers recording the same spans with different targets.
+        let (search_trace, search_layer) = Trace::new(false);
+        let search_layer = search_layer.with_targets(TargetFilter::default().allow(["search"]));
+        let (update_trace, update_layer) = Trace::new(false);
+        let update_layer = update_layer.with_targets(TargetFilter::default().allow(["update"]));
+        let subscriber = tracing_subscriber::registry().with(search_layer).with(update_layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let _search = tracing::info_span!(target: "search", "search").entered();
+            let _update = tracing::info_span!(target: "update", "update").entered();
+        });
+
+        assert_eq!(check_balanced(&received(search_trace)), 1);
+        assert_eq!(check_balanced(&received(update_trace)), 1);
+    }
+}
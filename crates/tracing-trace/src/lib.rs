@@ -1,16 +1,51 @@
-use std::io::{Read, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 
 use entry::Entry;
 
+mod auto_flush;
 pub mod entry;
 mod error;
 pub mod layer;
 pub mod processor;
 
+pub use auto_flush::AutoFlush;
 pub use error::Error;
 
+/// The bytes starting the header of the traces that are not in the JSON format.
+///
+/// JSON traces have no header to stay readable by any JSON tool, they are recognized
+/// by their first byte that can never be the first byte of this magic.
+const HEADER_MAGIC: &[u8; 8] = b"MEILITRC";
+
+/// How the entries of a trace are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// One JSON object per entry.
+    Json,
+    /// A compact binary encoding using bincode, about half the size of JSON and faster to parse.
+    Bincode,
+}
+
+impl TraceFormat {
+    fn to_byte(self) -> u8 {
+        match self {
+            TraceFormat::Json => 0,
+            TraceFormat::Bincode => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, Error> {
+        match byte {
+            0 => Ok(TraceFormat::Json),
+            1 => Ok(TraceFormat::Bincode),
+            byte => Err(Error::UnknownFormat(byte)),
+        }
+    }
+}
+
 pub struct TraceWriter<W: Write> {
     writer: W,
+    format: TraceFormat,
     receiver: tokio::sync::mpsc::UnboundedReceiver<Entry>,
 }
 
@@ -24,24 +59,67 @@ impl Trace {
     }
 
     pub fn into_writer<W: Write>(self, writer: W) -> TraceWriter<W> {
-        TraceWriter { writer, receiver: self.receiver }
+        TraceWriter { writer, format: TraceFormat::Json, receiver: self.receiver }
+    }
+
+    /// Writes the header describing the format, then the entries in the given format.
+    pub fn into_writer_with_format<W: Write>(
+        self,
+        mut writer: W,
+        format: TraceFormat,
+    ) -> Result<TraceWriter<W>, Error> {
+        writer.write_all(HEADER_MAGIC)?;
+        writer.write_all(&[format.to_byte()])?;
+        Ok(TraceWriter { writer, format, receiver: self.receiver })
     }
 }
 
+/// Reads the entries of a trace in any [`TraceFormat`], with or without a header.
 pub struct TraceReader<R: Read> {
-    reader: R,
+    reader: BufReader<R>,
+    /// `None` until the header, if any, has been read.
+    format: Option<TraceFormat>,
 }
 
 impl<R: Read> TraceReader<R> {
     pub fn new(reader: R) -> Self {
-        Self { reader }
+        Self { reader: BufReader::new(reader), format: None }
+    }
+
+    fn read_format(&mut self) -> Result<TraceFormat, Error> {
+        if self.reader.fill_buf()?.first() != Some(&HEADER_MAGIC[0]) {
+            return Ok(TraceFormat::Json);
+        }
+
+        let mut header = [0; HEADER_MAGIC.len() + 1];
+        self.reader.read_exact(&mut header)?;
+        let (magic, format) = header.split_at(HEADER_MAGIC.len());
+        if magic != HEADER_MAGIC {
+            return Err(Error::InvalidHeader);
+        }
+        TraceFormat::from_byte(format[0])
     }
 
     fn read(&mut self) -> Option<Result<Entry, Error>> {
-        serde_json::Deserializer::from_reader(&mut self.reader)
-            .into_iter()
-            .next()
-            .map(|res| res.map_err(Into::into))
+        let format = match self.format {
+            Some(format) => format,
+            None => match self.read_format() {
+                Ok(format) => *self.format.insert(format),
+                Err(error) => return Some(Err(error)),
+            },
+        };
+
+        match format {
+            TraceFormat::Json => serde_json::Deserializer::from_reader(&mut self.reader)
+                .into_iter()
+                .next()
+                .map(|res| res.map_err(Into::into)),
+            TraceFormat::Bincode => match self.reader.fill_buf().map(|buf| buf.is_empty()) {
+                Ok(true) => None,
+                Ok(false) => Some(bincode::deserialize_from(&mut self.reader).map_err(Into::into)),
+                Err(error) => Some(Err(error.into())),
+            },
+        }
     }
 }
 
@@ -52,3 +130,72 @@ impl<R: Read> Iterator for TraceReader<R> {
         self.read()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::time::Duration;
+
+    use tracing::span::Id as TracingId;
+
+    use super::*;
+    use crate::entry::{
+        Event, MemoryStats, NewCallsite, NewSpan, NewThread, ResourceId, SpanClose, SpanEnter,
+        SpanExit, SpanId,
+    };
+
+    fn entries() -> Vec<Entry> {
+        let span_id = SpanId::from(&TracingId::from_u64(1));
+        let call_id = ResourceId(0);
+        let thread_id = ResourceId(1);
+        let memory = Some(MemoryStats { resident: 1024 });
+        vec![
+            Entry::NewCallsite(NewCallsite {
+                call_id,
+                name: Cow::Borrowed("search"),
+                module_path: Some(Cow::Borrowed("milli::search")),
+                file: None,
+                line: Some(42),
+                target: Cow::Borrowed("search::query"),
+            }),
+            Entry::NewThread(NewThread { thread_id, name: Some(String::from("main")) }),
+            Entry::NewSpan(NewSpan { id: span_id, call_id, parent_id: None, thread_id }),
+            Entry::SpanEnter(SpanEnter { id: span_id, time: Duration::from_micros(10), memory }),
+            Entry::Event(Event {
+                call_id,
+                thread_id,
+                parent_id: Some(span_id),
+                time: Duration::from_micros(15),
+                memory: None,
+            }),
+            Entry::SpanExit(SpanExit { id: span_id, time: Duration::from_micros(20), memory }),
+            Entry::SpanClose(SpanClose { id: span_id, time: Duration::from_micros(25) }),
+        ]
+    }
+
+    /// Writes the entries with the given writer and reads them back.
+    fn roundtrip(mut writer: TraceWriter<Vec<u8>>) {
+        for entry in entries() {
+            writer.write(entry).unwrap();
+        }
+        let read = TraceReader::new(writer.writer.as_slice()).collect::<Result<Vec<_>, _>>();
+        // The entries don't implement `PartialEq`, their debug representation is compared instead.
+        assert_eq!(format!("{:?}", read.unwrap()), format!("{:?}", entries()));
+    }
+
+    #[test]
+    fn read_written_entries() {
+        roundtrip(Trace::new(false).0.into_writer(Vec::new()));
+        for format in [TraceFormat::Json, TraceFormat::Bincode] {
+            roundtrip(Trace::new(false).0.into_writer_with_format(Vec::new(), format).unwrap());
+        }
+    }
+
+    #[test]
+    fn read_unknown_format() {
+        let mut trace = HEADER_MAGIC.to_vec();
+        trace.push(7);
+        let mut reader = TraceReader::new(trace.as_slice());
+        assert!(matches!(reader.next(), Some(Err(Error::UnknownFormat(7)))));
+    }
+}